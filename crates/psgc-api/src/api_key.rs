@@ -0,0 +1,319 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use actix_web::{
+    Error, HttpMessage, ResponseError,
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{self, HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+    web::Data,
+};
+use chrono::{Days, Utc};
+use psgc_application::{
+    dto::api_key_dto::ApiKeyDTO,
+    errors::UsecaseError,
+    usecases::api_key_usecases::{AuthenticateApiKeyUsecase, RecordApiKeyUsageUsecase},
+};
+use psgc_infrastructure::repositories::api_key_impl::PgApiKeyRepository;
+
+use crate::{response::APIErr, router::APIState};
+
+pub const API_KEY_HEADER: HeaderName = HeaderName::from_static("x-api-key");
+
+const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+const RATE_LIMIT_DAILY_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-daily-limit");
+const RATE_LIMIT_DAILY_REMAINING: HeaderName =
+    HeaderName::from_static("x-ratelimit-daily-remaining");
+
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Per-key request limits. The per-minute window is counted in this process, like the
+/// per-IP limits; the daily quota is counted in the database so restarts don't reset it.
+#[derive(Clone)]
+pub struct ApiKeyAuth {
+    repository: Arc<PgApiKeyRepository>,
+    windows: MinuteWindows,
+}
+
+/// Fixed one-minute request windows, one per key.
+#[derive(Clone, Default)]
+struct MinuteWindows(Arc<Mutex<HashMap<uuid::Uuid, Window>>>);
+
+struct Window {
+    started: Instant,
+    requests: u32,
+}
+
+/// What's left of a key's limits, as reported in the `X-RateLimit-*` headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Allowance {
+    limit: u32,
+    remaining: u32,
+    reset_secs: u64,
+    daily: Option<(u64, u64)>,
+}
+
+impl ApiKeyAuth {
+    pub fn new(repository: Arc<PgApiKeyRepository>) -> Self {
+        Self {
+            repository,
+            windows: MinuteWindows::default(),
+        }
+    }
+}
+
+impl MinuteWindows {
+    /// Counts a request in the key's current one-minute window; `Err` when it's used up.
+    fn check(&self, id: uuid::Uuid, limit: u32, now: Instant) -> Result<Allowance, Allowance> {
+        let mut windows = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        windows.retain(|_, window| now.duration_since(window.started) < WINDOW);
+
+        let window = windows.entry(id).or_insert(Window {
+            started: now,
+            requests: 0,
+        });
+        let reset_secs = (WINDOW - now.duration_since(window.started))
+            .as_secs()
+            .max(1);
+
+        if window.requests >= limit {
+            return Err(Allowance {
+                limit,
+                remaining: 0,
+                reset_secs,
+                daily: None,
+            });
+        }
+        window.requests += 1;
+
+        Ok(Allowance {
+            limit,
+            remaining: limit - window.requests,
+            reset_secs,
+            daily: None,
+        })
+    }
+}
+
+impl Allowance {
+    fn write_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(self.limit));
+        headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(self.remaining));
+        headers.insert(RATE_LIMIT_RESET, HeaderValue::from(self.reset_secs));
+
+        if let Some((quota, used)) = self.daily {
+            headers.insert(RATE_LIMIT_DAILY_LIMIT, HeaderValue::from(quota));
+            headers.insert(
+                RATE_LIMIT_DAILY_REMAINING,
+                HeaderValue::from(quota.saturating_sub(used)),
+            );
+        }
+    }
+}
+
+/// Reads the key from `X-API-Key`, or from an `Authorization: Bearer` header.
+fn api_key_from_headers(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(&API_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .or_else(|| {
+            headers
+                .get(header::AUTHORIZATION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.strip_prefix("Bearer "))
+        })
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+fn seconds_until_utc_midnight() -> u64 {
+    let now = Utc::now();
+    let midnight = now
+        .date_naive()
+        .checked_add_days(Days::new(1))
+        .and_then(|day| day.and_hms_opt(0, 0, 0))
+        .map(|midnight| midnight.and_utc());
+
+    midnight
+        .map(|midnight| (midnight - now).num_seconds().max(1) as u64)
+        .unwrap_or(1)
+}
+
+/// Requires a valid API key on every `/api/v1` call when key authentication is on, and
+/// enforces the key's per-minute rate limit and daily quota. Does nothing otherwise.
+pub async fn require_api_key(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let auth = req
+        .app_data::<Data<APIState>>()
+        .and_then(|state| state.api_key_auth.clone());
+
+    // The OpenAPI document stays public so `/docs` keeps working without a key.
+    let Some(auth) = auth.filter(|_| req.path().trim_end_matches('/') != "/api/v1") else {
+        return Ok(next.call(req).await?.map_into_left_body());
+    };
+
+    let reject = |req: ServiceRequest, err: APIErr, allowance: Option<Allowance>| {
+        let mut res = req.into_response(err.error_response());
+        if let Some(allowance) = allowance {
+            allowance.write_headers(res.headers_mut());
+            res.headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(allowance.reset_secs));
+        }
+
+        Ok(res.map_into_right_body())
+    };
+
+    let Some(key) = api_key_from_headers(req.headers()) else {
+        return reject(req, UsecaseError::Unauthorized.into(), None);
+    };
+    let origin = req
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok());
+
+    let api_key = match AuthenticateApiKeyUsecase::new(auth.repository.clone())
+        .execute(key, origin)
+        .await
+    {
+        Ok(api_key) => api_key,
+        Err(e) => return reject(req, e.into(), None),
+    };
+
+    let mut allowance =
+        match auth
+            .windows
+            .check(api_key.id, api_key.rate_limit_per_minute, Instant::now())
+        {
+            Ok(allowance) => allowance,
+            Err(allowance) => {
+                let message = format!(
+                    "Rate limit of {} requests per minute exceeded",
+                    api_key.rate_limit_per_minute
+                );
+                return reject(req, APIErr::too_many_requests(message), Some(allowance));
+            }
+        };
+
+    let used_today = match RecordApiKeyUsageUsecase::new(auth.repository.clone())
+        .execute(api_key.id)
+        .await
+    {
+        Ok(used_today) => used_today,
+        Err(e) => return reject(req, e.into(), None),
+    };
+
+    if let Some(quota) = api_key.daily_quota {
+        allowance.daily = Some((quota, used_today));
+
+        if used_today > quota {
+            let message = format!("Daily quota of {quota} requests exceeded");
+            let allowance = Allowance {
+                reset_secs: seconds_until_utc_midnight(),
+                ..allowance
+            };
+            return reject(req, APIErr::too_many_requests(message), Some(allowance));
+        }
+    }
+
+    req.extensions_mut().insert(api_key);
+
+    let mut res = next.call(req).await?;
+    allowance.write_headers(res.headers_mut());
+
+    Ok(res.map_into_left_body())
+}
+
+/// Lets only admin keys through to the `/api/v1/admin` write endpoints. The key is the one
+/// `require_api_key` already checked, or is read from the headers when key authentication
+/// is off for the read endpoints. Without an API key store (the in-memory backend) there
+/// is no one to authorize, so every call is forbidden.
+pub async fn require_admin(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let reject = |req: ServiceRequest, err: UsecaseError| {
+        let err: APIErr = err.into();
+        Ok(req
+            .into_response(err.error_response())
+            .map_into_right_body())
+    };
+
+    let authenticated = req.extensions().get::<ApiKeyDTO>().cloned();
+    let api_key = match authenticated {
+        Some(api_key) => api_key,
+        None => {
+            let repository = req
+                .app_data::<Data<APIState>>()
+                .and_then(|state| state.repositories.api_keys.clone());
+            let Some(repository) = repository else {
+                return reject(req, UsecaseError::Forbidden);
+            };
+            let Some(key) = api_key_from_headers(req.headers()) else {
+                return reject(req, UsecaseError::Unauthorized);
+            };
+            let origin = req
+                .headers()
+                .get(header::ORIGIN)
+                .and_then(|value| value.to_str().ok());
+
+            match AuthenticateApiKeyUsecase::new(repository)
+                .execute(key, origin)
+                .await
+            {
+                Ok(api_key) => api_key,
+                Err(e) => return reject(req, e),
+            }
+        }
+    };
+
+    if !api_key.admin {
+        return reject(req, UsecaseError::Forbidden);
+    }
+
+    req.extensions_mut().insert(api_key);
+
+    Ok(next.call(req).await?.map_into_left_body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_each_key_per_minute_window() {
+        let windows = MinuteWindows::default();
+        let (first, second) = (uuid::Uuid::now_v7(), uuid::Uuid::now_v7());
+        let now = Instant::now();
+
+        assert_eq!(windows.check(first, 2, now).unwrap().remaining, 1);
+        assert_eq!(windows.check(first, 2, now).unwrap().remaining, 0);
+        assert!(windows.check(first, 2, now).is_err());
+        assert!(windows.check(second, 2, now).is_ok());
+
+        let next_window = now + WINDOW;
+        assert_eq!(windows.check(first, 2, next_window).unwrap().remaining, 1);
+    }
+
+    #[test]
+    fn reads_the_key_from_either_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(api_key_from_headers(&headers), None);
+
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_static("Bearer psgc_abc"),
+        );
+        assert_eq!(api_key_from_headers(&headers), Some("psgc_abc"));
+
+        headers.insert(API_KEY_HEADER, HeaderValue::from_static("psgc_def"));
+        assert_eq!(api_key_from_headers(&headers), Some("psgc_def"));
+    }
+}
@@ -0,0 +1,360 @@
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+use actix_web::web::{self, Bytes};
+use chrono::NaiveDate;
+use flate2::{Compression, write::GzEncoder};
+use futures_util::Stream;
+use psgc_application::{
+    dto::dataset_dto::{DatasetDTO, DatasetFileDTO, DatasetManifestDTO},
+    errors::UsecaseError,
+    usecases::{
+        dataset_usecases::{ExportDatasetUsecase, GetSyncTokenUsecase},
+        release_usecases::ResolveReleaseUsecase,
+    },
+};
+use psgc_infrastructure::{
+    database::export::{ExportTable, sqlite_file},
+    repositories::backend::Repositories,
+};
+use sha2::{Digest, Sha256};
+use tokio::io::AsyncReadExt;
+use tracing::{info, warn};
+
+use crate::{errors::APIError, response::APIErr};
+
+/// Formats the dataset can be downloaded in, all gzipped. SQLite files need the `sqlite`
+/// feature.
+#[cfg(feature = "sqlite")]
+pub const FORMATS: &[&str] = &["ndjson", "sqlite"];
+#[cfg(not(feature = "sqlite"))]
+pub const FORMATS: &[&str] = &["ndjson"];
+
+/// The `format` parameter, as documented for this build.
+#[cfg(feature = "sqlite")]
+pub const FORMAT_DESCRIPTION: &str =
+    "`ndjson` (default) or `sqlite`, a database with a table per level and a `manifest` table";
+#[cfg(not(feature = "sqlite"))]
+pub const FORMAT_DESCRIPTION: &str = "`ndjson`, the only format of this build";
+
+/// A built download of a release's dataset, kept in a temporary file.
+pub struct DatasetFile {
+    /// The sync token of the data it was built from, when the backend keeps changes
+    pub version: Option<String>,
+    /// Describes the data in the file, as the file itself does
+    pub manifest: DatasetManifestDTO,
+    /// The file's name, size and checksum
+    pub described: DatasetFileDTO,
+    path: TempPath,
+}
+
+/// Bytes read from a file per chunk of a download.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+impl DatasetFile {
+    /// The manifest served by the manifest endpoint, which also describes the file.
+    pub fn full_manifest(&self) -> DatasetManifestDTO {
+        DatasetManifestDTO {
+            file: Some(self.described.clone()),
+            ..self.manifest.clone()
+        }
+    }
+
+    /// The gzipped file in chunks, read as they're sent rather than all at once.
+    pub async fn chunks(
+        self: Arc<Self>,
+    ) -> std::io::Result<impl Stream<Item = std::io::Result<Bytes>> + use<>> {
+        let reader = tokio::fs::File::open(&self.path.0).await?;
+
+        // The file is carried along so it isn't removed while being sent
+        Ok(futures_util::stream::try_unfold(
+            (reader, self),
+            async |(mut reader, file)| {
+                let mut chunk = vec![0; CHUNK_SIZE];
+                let read = reader.read(&mut chunk).await?;
+                chunk.truncate(read);
+
+                Ok((read > 0).then(|| (Bytes::from(chunk), (reader, file))))
+            },
+        ))
+    }
+}
+
+/// A temporary file, removed when dropped.
+struct TempPath(PathBuf);
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0) {
+            warn!("couldn't remove dataset file {}: {e}", self.0.display());
+        }
+    }
+}
+
+/// Exports done over while writes land in the middle of them, before the last is kept.
+const EXPORT_ATTEMPTS: usize = 3;
+
+/// The most files kept; the one downloaded longest ago makes way for a new one.
+const MAX_FILES: usize = 8;
+
+/// A release, format and the day a file's data is as of.
+type DatasetKey = (String, String, NaiveDate);
+
+/// Files by their key, with when each was last served.
+type DatasetFiles = HashMap<DatasetKey, (Arc<DatasetFile>, Instant)>;
+
+/// The dataset files built so far, shared by the workers.
+///
+/// A file is kept until the release's data changes, which moves its sync token; a backend
+/// without one never changes its data. The current data is as of today, so its file is
+/// built anew each day, when units whose `valid_from` or `valid_to` it is come or go.
+#[derive(Clone, Default)]
+pub struct DatasetCache {
+    files: Arc<Mutex<DatasetFiles>>,
+    /// Held while the file of a key is built, so a request for a file being built waits
+    /// for it rather than building it again, while other files build alongside. A key's
+    /// lock is dropped once no request holds or waits for it.
+    building: Arc<Mutex<HashMap<DatasetKey, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl DatasetCache {
+    fn get(&self, key: &DatasetKey, version: Option<&str>) -> Option<Arc<DatasetFile>> {
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+
+        files
+            .get_mut(key)
+            .filter(|(file, _)| file.version.as_deref() == version)
+            .map(|(file, used)| {
+                *used = Instant::now();
+                file.clone()
+            })
+    }
+
+    fn insert(&self, key: DatasetKey, file: DatasetFile) -> Arc<DatasetFile> {
+        let file = Arc::new(file);
+        let mut files = self.files.lock().unwrap_or_else(|e| e.into_inner());
+        files.insert(key, (file.clone(), Instant::now()));
+
+        while files.len() > MAX_FILES {
+            let Some(oldest) = files
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+            files.remove(&oldest);
+        }
+
+        file
+    }
+
+    /// The file of `release` in `format` as of a day, today by default, built unless the
+    /// one built last is still current.
+    pub async fn file(
+        &self,
+        repositories: &Repositories,
+        release: &str,
+        as_of: Option<NaiveDate>,
+        format: &str,
+    ) -> Result<Arc<DatasetFile>, APIErr> {
+        if !FORMATS.contains(&format) {
+            let message = match format {
+                "sqlite" => "this server was built without SQLite support; use ndjson".to_string(),
+                _ => format!("unknown format {format}; use {}", FORMATS.join(" or ")),
+            };
+            return Err(UsecaseError::ValidationError(message).into());
+        }
+
+        let day = as_of.unwrap_or_else(|| chrono::Utc::now().date_naive());
+        let key = (release.to_string(), format.to_string(), day);
+        let version = sync_token(repositories, release).await?;
+        if let Some(file) = self.get(&key, version.as_deref()) {
+            return Ok(file);
+        }
+
+        let lock = self
+            .building
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        let built = {
+            let _building = lock.lock().await;
+            self.build(repositories, release, as_of, format, &key).await
+        };
+
+        let mut building = self.building.lock().unwrap_or_else(|e| e.into_inner());
+        // Requests clone the lock out of the map while holding this guard, so the map's
+        // and this request's are the only ones left when no other request needs it
+        if Arc::strong_count(&lock) == 2 {
+            building.remove(&key);
+        }
+
+        built
+    }
+
+    /// Builds and keeps the file of `key` while holding its lock, unless a request it
+    /// waited for already did.
+    async fn build(
+        &self,
+        repositories: &Repositories,
+        release: &str,
+        as_of: Option<NaiveDate>,
+        format: &str,
+        key: &DatasetKey,
+    ) -> Result<Arc<DatasetFile>, APIErr> {
+        // Built while this request waited, or the data changed meanwhile
+        let mut version = sync_token(repositories, release).await?;
+        if let Some(file) = self.get(key, version.as_deref()) {
+            return Ok(file);
+        }
+
+        let units = repositories.for_release(release, as_of);
+        let export = ExportDatasetUsecase::builder()
+            .region_repository(units.region)
+            .province_repository(units.province)
+            .district_repository(units.district)
+            .city_repository(units.city)
+            .municipality_repository(units.municipality)
+            .barangay_repository(units.barangay)
+            .build();
+        // The levels are read page by page rather than from one snapshot, so an export a
+        // write landed in is done over. One kept anyway still carries the token from before
+        // it, and a copy made from it replays that write from the feed.
+        let mut dataset = export.execute(release, version.clone()).await?;
+        for _ in 1..EXPORT_ATTEMPTS {
+            let current = sync_token(repositories, release).await?;
+            if current == version {
+                break;
+            }
+            version = current;
+            dataset = export.execute(release, version.clone()).await?;
+        }
+
+        let built = {
+            let format = format.to_string();
+            web::block(move || build_file(dataset, &format, version)).await
+        };
+        let file = built
+            .map_err(|e| e.to_string())
+            .and_then(|file| file.map_err(|e| e.to_string()))
+            .map_err(|e| {
+                APIErr::builder()
+                    .code("psgc-500".to_string())
+                    .error(APIError::InternalError(e))
+                    .build()
+            })?;
+
+        Ok(self.insert(key.clone(), file))
+    }
+
+    /// Builds the files of the latest release ahead of its first download, so that request
+    /// doesn't wait for it.
+    pub async fn prebuild(self, repositories: Repositories) {
+        let release = match ResolveReleaseUsecase::new(repositories.releases.clone())
+            .execute(None)
+            .await
+        {
+            Ok(release) => release.id,
+            Err(e) => {
+                warn!("no release to build dataset files of: {e}");
+                return;
+            }
+        };
+
+        for format in FORMATS {
+            match self.file(&repositories, &release, None, format).await {
+                Ok(file) => info!(
+                    "built the {format} dataset file of {release}, {} bytes",
+                    file.described.bytes
+                ),
+                Err(e) => warn!("couldn't build the {format} dataset file of {release}: {e}"),
+            }
+        }
+    }
+}
+
+/// The sync token of `release`, when the backend keeps changes.
+async fn sync_token(repositories: &Repositories, release: &str) -> Result<Option<String>, APIErr> {
+    let Some(audit_repository) = repositories.audit_log.clone() else {
+        return Ok(None);
+    };
+
+    Ok(Some(
+        GetSyncTokenUsecase::new(audit_repository, repositories.releases.clone())
+            .execute(release)
+            .await?,
+    ))
+}
+
+/// Builds the gzipped file of `dataset` in `format`, one of [`FORMATS`], and its manifest.
+/// Blocks; the file is written to the temporary directory, and the SQLite database inside
+/// it to a temporary file of its own first.
+pub fn build_file(
+    dataset: DatasetDTO,
+    format: &str,
+    version: Option<String>,
+) -> anyhow::Result<DatasetFile> {
+    let manifest = dataset.manifest();
+    let contents = match format {
+        "sqlite" => {
+            let tables: Vec<ExportTable> = dataset
+                .levels
+                .iter()
+                .map(|level| ExportTable {
+                    name: &level.table,
+                    rows: &level.units,
+                })
+                .collect();
+            let manifest = [
+                ("release", manifest.release.clone()),
+                (
+                    "sync_token",
+                    manifest.sync_token.clone().unwrap_or_default(),
+                ),
+                ("levels", serde_json::to_string(&manifest.levels)?),
+            ];
+
+            sqlite_file(&tables, &manifest)?
+        }
+        _ => dataset.to_ndjson(&manifest),
+    };
+
+    // Removed again when dropped, including when the build fails below
+    let path =
+        TempPath(std::env::temp_dir().join(format!("psgc-dataset-{}.gz", uuid::Uuid::now_v7())));
+
+    let mut gzip = GzEncoder::new(std::fs::File::create(&path.0)?, Compression::default());
+    gzip.write_all(&contents)?;
+    gzip.finish()?.sync_all()?;
+
+    let mut sha256 = Sha256::new();
+    let bytes = std::io::copy(&mut std::fs::File::open(&path.0)?, &mut sha256)?;
+    let file_name = match format {
+        "sqlite" => format!("psgc-{}.sqlite.gz", dataset.release),
+        _ => format!("psgc-{}.ndjson.gz", dataset.release),
+    };
+
+    Ok(DatasetFile {
+        version,
+        manifest,
+        described: DatasetFileDTO {
+            format: format.to_string(),
+            file_name,
+            bytes: usize::try_from(bytes)?,
+            sha256: sha256
+                .finalize()
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect(),
+        },
+        path,
+    })
+}
@@ -1,3 +1,4 @@
+use psgc_infrastructure::health::ReadinessReport;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -16,3 +17,131 @@ impl PaginateQueryParam {
         self.limit.unwrap_or(10).clamp(0, 100)
     }
 }
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct OptionsQueryParam {
+    pub parent: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct NameQueryParam {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct IslandGroupQueryParam {
+    pub island_group: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct LevelQueryParam {
+    level: Option<String>,
+}
+
+impl LevelQueryParam {
+    pub fn level(&self) -> &str {
+        self.level.as_deref().unwrap_or("municipality")
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct FormatQueryParam {
+    format: Option<String>,
+}
+
+impl FormatQueryParam {
+    pub fn format(&self) -> &str {
+        self.format.as_deref().unwrap_or("json")
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct HealthCheckDTO {
+    pub name: String,
+    /// `ok` or `fail`
+    pub status: String,
+    pub latency_ms: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ReadinessDTO {
+    /// `ok` when every check passed, `fail` otherwise
+    pub status: String,
+    pub checks: Vec<HealthCheckDTO>,
+}
+
+impl From<ReadinessReport> for ReadinessDTO {
+    fn from(report: ReadinessReport) -> Self {
+        let status = status_label(report.is_ready());
+        let checks = report
+            .checks
+            .into_iter()
+            .map(|check| HealthCheckDTO {
+                name: check.name.to_string(),
+                status: status_label(check.is_ok()),
+                latency_ms: check.latency.as_secs_f64() * 1000.0,
+                error: check.error,
+            })
+            .collect();
+
+        Self { status, checks }
+    }
+}
+
+fn status_label(ok: bool) -> String {
+    if ok { "ok" } else { "fail" }.to_string()
+}
+
+/// Why an admin change is being made; recorded in the audit log.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ReasonQueryParam {
+    pub reason: String,
+}
+
+/// Why and when a unit is abolished; the reason is recorded in the audit log.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AbolishQueryParam {
+    pub reason: String,
+    valid_to: Option<chrono::NaiveDate>,
+}
+
+impl AbolishQueryParam {
+    /// The day the unit was abolished, today unless given.
+    pub fn valid_to(&self) -> chrono::NaiveDate {
+        self.valid_to
+            .unwrap_or_else(|| chrono::Utc::now().date_naive())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AuditQueryParam {
+    pub code: Option<String>,
+    /// A date (`YYYY-MM-DD`) or an RFC 3339 timestamp
+    pub since: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct ChangesQueryParam {
+    /// A token from an earlier response, a date (`YYYY-MM-DD`) or an RFC 3339 timestamp
+    pub since: String,
+    limit: Option<u64>,
+}
+
+impl ChangesQueryParam {
+    pub fn limit(&self) -> u64 {
+        self.limit.unwrap_or(500)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct DatasetQueryParam {
+    format: Option<String>,
+}
+
+impl DatasetQueryParam {
+    pub fn format(&self) -> &str {
+        self.format.as_deref().unwrap_or("ndjson")
+    }
+}
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
-#[serde(tag = "type")]
+#[serde(tag = "type", content = "message")]
 pub enum APIError {
     #[schema(example = "Entity not found")]
     NotFound,
@@ -16,6 +16,8 @@ pub enum APIError {
     Unauthorized,
     #[schema(example = "Resource already exists")]
     Conflict(String),
+    #[schema(example = "Rate limit of 60 requests per minute exceeded")]
+    TooManyRequests(String),
 }
 
 impl From<psgc_application::errors::UsecaseError> for APIError {
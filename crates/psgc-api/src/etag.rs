@@ -0,0 +1,58 @@
+use actix_web::{
+    HttpMessage, HttpRequest, HttpResponse,
+    http::header::{CacheControl, CacheDirective, ETag, EntityTag, IfNoneMatch},
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::{errors::APIError, response::APIErr};
+
+/// Serializes `body` and responds with a strong ETag derived from its SHA-256 digest.
+///
+/// Answers `304 Not Modified` when the request's `If-None-Match` already carries the tag.
+pub fn respond_with_etag<T: Serialize>(
+    req: &HttpRequest,
+    body: &T,
+    max_age: u32,
+) -> Result<HttpResponse, APIErr> {
+    let bytes = serde_json::to_vec(body).map_err(|e| {
+        APIErr::builder()
+            .code("psgc-500".to_string())
+            .error(APIError::InternalError(e.to_string()))
+            .build()
+    })?;
+
+    let etag = EntityTag::new_strong(hex_digest(&bytes));
+    let cache_control = CacheControl(vec![
+        CacheDirective::Public,
+        CacheDirective::MaxAge(max_age),
+    ]);
+
+    if is_fresh(req, &etag) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header(cache_control)
+            .finish());
+    }
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/json")
+        .insert_header(ETag(etag))
+        .insert_header(cache_control)
+        .body(bytes))
+}
+
+pub(crate) fn is_fresh(req: &HttpRequest, etag: &EntityTag) -> bool {
+    match req.get_header::<IfNoneMatch>() {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        None => false,
+    }
+}
+
+fn hex_digest(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
@@ -0,0 +1,1088 @@
+use actix_web::{
+    HttpResponse,
+    web::{self, Json, Query, ReqData},
+};
+use psgc_application::{
+    dto::{
+        PaginateResponseDTO,
+        api_key_dto::ApiKeyDTO,
+        audit_dto::{AuditEntryDTO, ChangeContextDTO},
+        barangay_dto::{BarangayDTO, BarangayInputDTO},
+        city_dto::{CityDTO, CityInputDTO},
+        crosswalk_dto::{CrosswalkEntryDTO, CrosswalkEntryInputDTO},
+        district_dto::{DistrictDTO, DistrictInputDTO},
+        lineage_dto::{LineageEventDTO, LineageEventInputDTO},
+        municipality_dto::{MunicipalityDTO, MunicipalityInputDTO},
+        province_dto::{ProvinceDTO, ProvinceInputDTO},
+        region_dto::{RegionDTO, RegionInputDTO},
+    },
+    errors::UsecaseError,
+    usecases::{
+        audit_usecases::{GetAuditHistoryUsecase, ListAuditLogUsecase},
+        barangay_usecases::{AbolishBarangayUsecase, CreateBarangayUsecase, UpdateBarangayUsecase},
+        city_usecases::{AbolishCityUsecase, CreateCityUsecase, UpdateCityUsecase},
+        crosswalk_usecases::{DeleteCrosswalkEntryUsecase, RecordCrosswalkEntryUsecase},
+        district_usecases::{AbolishDistrictUsecase, CreateDistrictUsecase, UpdateDistrictUsecase},
+        lineage_usecases::{DeleteLineageEventUsecase, RecordLineageEventUsecase},
+        municipality_usecases::{
+            AbolishMunicipalityUsecase, CreateMunicipalityUsecase, UpdateMunicipalityUsecase,
+        },
+        province_usecases::{AbolishProvinceUsecase, CreateProvinceUsecase, UpdateProvinceUsecase},
+        region_usecases::{AbolishRegionUsecase, CreateRegionUsecase, UpdateRegionUsecase},
+    },
+};
+use psgc_infrastructure::repositories::{
+    audit_impl::PgAuditLogRepository, crosswalk_impl::PgCrosswalkRepository,
+    lineage_impl::PgLineageRepository,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::{AbolishQueryParam, AuditQueryParam, PaginateQueryParam, ReasonQueryParam},
+    release::InRelease,
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(
+        list_audit_log, get_audit_history, create_region, update_region, delete_region, create_province, update_province, delete_province, create_district, update_district, delete_district, create_city, update_city, delete_city, create_municipality, update_municipality, delete_municipality, create_barangay, update_barangay, delete_barangay, create_lineage_event, delete_lineage_event, create_crosswalk_entry, delete_crosswalk_entry,
+    ),
+    components(schemas(
+        AuditEntryDTO, PaginateResponseDTO<AuditEntryDTO>, RegionDTO, RegionInputDTO, ProvinceDTO, ProvinceInputDTO, DistrictDTO, DistrictInputDTO, CityDTO, CityInputDTO, MunicipalityDTO, MunicipalityInputDTO, BarangayDTO, BarangayInputDTO, LineageEventDTO, LineageEventInputDTO, CrosswalkEntryDTO, CrosswalkEntryInputDTO,
+        APIErr
+    )),
+    tags((
+        name = "admin",
+        description = "Corrections between PSA releases; needs an admin API key"
+    ))
+)]
+pub struct AdminAPIDoc;
+
+/// Write endpoints for correcting the data in place, and the audit log they write to.
+/// Parent links are given as codes and checked before anything is saved; see the usecases
+/// for the exact rules.
+pub fn build_admin_route() -> actix_web::Scope {
+    web::scope("/admin")
+        .app_data(web::JsonConfig::default().error_handler(|err, _req| {
+            APIErr::from(UsecaseError::ValidationError(err.to_string())).into()
+        }))
+        .app_data(web::QueryConfig::default().error_handler(|err, _req| {
+            APIErr::from(UsecaseError::ValidationError(err.to_string())).into()
+        }))
+        .service(web::resource("/audit").route(web::get().to(list_audit_log)))
+        .service(web::resource("/{level}/{code}/history").route(web::get().to(get_audit_history)))
+        .service(web::resource("/regions").route(web::post().to(create_region)))
+        .service(
+            web::resource("/regions/{code}")
+                .route(web::put().to(update_region))
+                .route(web::delete().to(delete_region)),
+        )
+        .service(web::resource("/provinces").route(web::post().to(create_province)))
+        .service(
+            web::resource("/provinces/{code}")
+                .route(web::put().to(update_province))
+                .route(web::delete().to(delete_province)),
+        )
+        .service(web::resource("/districts").route(web::post().to(create_district)))
+        .service(
+            web::resource("/districts/{code}")
+                .route(web::put().to(update_district))
+                .route(web::delete().to(delete_district)),
+        )
+        .service(web::resource("/cities").route(web::post().to(create_city)))
+        .service(
+            web::resource("/cities/{code}")
+                .route(web::put().to(update_city))
+                .route(web::delete().to(delete_city)),
+        )
+        .service(web::resource("/municipalities").route(web::post().to(create_municipality)))
+        .service(
+            web::resource("/municipalities/{code}")
+                .route(web::put().to(update_municipality))
+                .route(web::delete().to(delete_municipality)),
+        )
+        .service(web::resource("/barangays").route(web::post().to(create_barangay)))
+        .service(
+            web::resource("/barangays/{code}")
+                .route(web::put().to(update_barangay))
+                .route(web::delete().to(delete_barangay)),
+        )
+        .service(web::resource("/lineage").route(web::post().to(create_lineage_event)))
+        .service(web::resource("/lineage/{id}").route(web::delete().to(delete_lineage_event)))
+        .service(web::resource("/crosswalk").route(web::post().to(create_crosswalk_entry)))
+        .service(web::resource("/crosswalk/{id}").route(web::delete().to(delete_crosswalk_entry)))
+}
+
+fn change_context(api_key: &ApiKeyDTO, reason: String) -> ChangeContextDTO {
+    ChangeContextDTO {
+        actor: api_key.name.clone(),
+        reason,
+    }
+}
+
+/// The audit log is kept by the SQL backends, the only ones admin keys can exist on.
+fn audit_repository(state: &APIState) -> Result<std::sync::Arc<PgAuditLogRepository>, APIErr> {
+    state
+        .repositories
+        .audit_log
+        .clone()
+        .ok_or_else(|| UsecaseError::Forbidden.into())
+}
+
+/// Likewise the lineage of codes.
+fn lineage_repository(state: &APIState) -> Result<std::sync::Arc<PgLineageRepository>, APIErr> {
+    state
+        .repositories
+        .lineage
+        .clone()
+        .ok_or_else(|| UsecaseError::Forbidden.into())
+}
+
+/// Likewise the crosswalk of historical codes.
+fn crosswalk_repository(state: &APIState) -> Result<std::sync::Arc<PgCrosswalkRepository>, APIErr> {
+    state
+        .repositories
+        .crosswalk
+        .clone()
+        .ok_or_else(|| UsecaseError::Forbidden.into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit",
+    params(
+        ("code" = Option<String>, Query, description = "Only changes to the unit with this code"),
+        ("since" = Option<String>, Query, description = "Only changes from this date (YYYY-MM-DD) or RFC 3339 timestamp on"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Audit log entries, newest first", body = PaginateResponseDTO<AuditEntryDTO>),
+        (status = 400, description = "Invalid since", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Browse the audit log of every change to the data"
+)]
+async fn list_audit_log(
+    state: web::Data<APIState>,
+    Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<AuditQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<AuditEntryDTO>>>, APIErr> {
+    let entries = ListAuditLogUsecase::new(audit_repository(&state)?)
+        .execute(
+            filter.code.as_deref(),
+            filter.since.as_deref(),
+            param.page(),
+            param.limit(),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Audit log".to_string(),
+        entries,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/{level}/{code}/history",
+    params(
+        ("level" = String, Path, description = "regions, provinces, districts, cities, municipalities or barangays"),
+        ("code" = String, Path, description = "Current or former code of the unit")
+    ),
+    responses(
+        (status = 200, description = "Every recorded change to the unit, oldest first", body = Vec<AuditEntryDTO>),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "No recorded changes", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "History of one unit, including changes made under a former code and its deletion"
+)]
+async fn get_audit_history(
+    state: web::Data<APIState>,
+    path: web::Path<(String, String)>,
+) -> Result<Json<APIOk<Vec<AuditEntryDTO>>>, APIErr> {
+    let (level, code) = path.into_inner();
+    let entity = match level.as_str() {
+        "regions" => "region",
+        "provinces" => "province",
+        "districts" => "district",
+        "cities" => "city",
+        "municipalities" => "municipality",
+        "barangays" => "barangay",
+        _ => return Err(UsecaseError::NotFound.into()),
+    };
+
+    let history = GetAuditHistoryUsecase::new(audit_repository(&state)?)
+        .execute(entity, &code)
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        format!("History of {entity} {code}"),
+        history,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/regions",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = RegionInputDTO,
+    responses(
+        (status = 201, description = "Region created", body = RegionDTO),
+        (status = 400, description = "Invalid region or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A region with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a region"
+)]
+async fn create_region(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<RegionInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let region = CreateRegionUsecase::new(repositories.region.clone())
+        .execute(input, &change_context(&api_key, param.reason))
+        .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Region created".to_string(),
+        region,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/regions/{code}",
+    params(
+        ("code" = String, Path, description = "Current region code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = RegionInputDTO,
+    responses(
+        (status = 200, description = "Region updated", body = RegionDTO),
+        (status = 400, description = "Invalid region or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Region not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a region; the body may also change its code"
+)]
+async fn update_region(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<RegionInputDTO>,
+) -> Result<Json<APIOk<RegionDTO>>, APIErr> {
+    let region = UpdateRegionUsecase::new(repositories.region.clone())
+        .execute(
+            &path.into_inner(),
+            input,
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region updated".to_string(),
+        region,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/regions/{code}",
+    params(
+        ("code" = String, Path, description = "Region code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the region was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Region abolished; returns it with its end date", body = RegionDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Region not found", body = APIErr),
+        (status = 409, description = "Region still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a region that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_region(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<RegionDTO>>, APIErr> {
+    let region =
+        AbolishRegionUsecase::new(repositories.region.clone(), repositories.district.clone())
+            .execute(
+                &path.into_inner(),
+                param.valid_to(),
+                &change_context(&api_key, param.reason),
+            )
+            .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region abolished".to_string(),
+        region,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/provinces",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = ProvinceInputDTO,
+    responses(
+        (status = 201, description = "Province created", body = ProvinceDTO),
+        (status = 400, description = "Invalid province or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A province with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a province"
+)]
+async fn create_province(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<ProvinceInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let province =
+        CreateProvinceUsecase::new(repositories.province.clone(), repositories.region.clone())
+            .execute(input, &change_context(&api_key, param.reason))
+            .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Province created".to_string(),
+        province,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/provinces/{code}",
+    params(
+        ("code" = String, Path, description = "Current province code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = ProvinceInputDTO,
+    responses(
+        (status = 200, description = "Province updated", body = ProvinceDTO),
+        (status = 400, description = "Invalid province or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Province not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a province; the body may also change its code"
+)]
+async fn update_province(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<ProvinceInputDTO>,
+) -> Result<Json<APIOk<ProvinceDTO>>, APIErr> {
+    let province =
+        UpdateProvinceUsecase::new(repositories.province.clone(), repositories.region.clone())
+            .execute(
+                &path.into_inner(),
+                input,
+                &change_context(&api_key, param.reason),
+            )
+            .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province updated".to_string(),
+        province,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/provinces/{code}",
+    params(
+        ("code" = String, Path, description = "Province code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the province was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Province abolished; returns it with its end date", body = ProvinceDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Province not found", body = APIErr),
+        (status = 409, description = "Province still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a province that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_province(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<ProvinceDTO>>, APIErr> {
+    let province = AbolishProvinceUsecase::new(repositories.province.clone())
+        .execute(
+            &path.into_inner(),
+            param.valid_to(),
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province abolished".to_string(),
+        province,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/districts",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = DistrictInputDTO,
+    responses(
+        (status = 201, description = "District created", body = DistrictDTO),
+        (status = 400, description = "Invalid district or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A district with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a district"
+)]
+async fn create_district(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<DistrictInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let district =
+        CreateDistrictUsecase::new(repositories.district.clone(), repositories.region.clone())
+            .execute(input, &change_context(&api_key, param.reason))
+            .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "District created".to_string(),
+        district,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/districts/{code}",
+    params(
+        ("code" = String, Path, description = "Current district code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = DistrictInputDTO,
+    responses(
+        (status = 200, description = "District updated", body = DistrictDTO),
+        (status = 400, description = "Invalid district or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "District not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a district; the body may also change its code"
+)]
+async fn update_district(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<DistrictInputDTO>,
+) -> Result<Json<APIOk<DistrictDTO>>, APIErr> {
+    let district =
+        UpdateDistrictUsecase::new(repositories.district.clone(), repositories.region.clone())
+            .execute(
+                &path.into_inner(),
+                input,
+                &change_context(&api_key, param.reason),
+            )
+            .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "District updated".to_string(),
+        district,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/districts/{code}",
+    params(
+        ("code" = String, Path, description = "District code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the district was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "District abolished; returns it with its end date", body = DistrictDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "District not found", body = APIErr),
+        (status = 409, description = "District still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a district that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_district(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<DistrictDTO>>, APIErr> {
+    let district =
+        AbolishDistrictUsecase::new(repositories.district.clone(), repositories.barangay.clone())
+            .execute(
+                &path.into_inner(),
+                param.valid_to(),
+                &change_context(&api_key, param.reason),
+            )
+            .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "District abolished".to_string(),
+        district,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/cities",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = CityInputDTO,
+    responses(
+        (status = 201, description = "City created", body = CityDTO),
+        (status = 400, description = "Invalid city or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A city with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a city"
+)]
+async fn create_city(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<CityInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let city = CreateCityUsecase::new(
+        repositories.city.clone(),
+        repositories.region.clone(),
+        repositories.province.clone(),
+    )
+    .execute(input, &change_context(&api_key, param.reason))
+    .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "City created".to_string(),
+        city,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/cities/{code}",
+    params(
+        ("code" = String, Path, description = "Current city code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = CityInputDTO,
+    responses(
+        (status = 200, description = "City updated", body = CityDTO),
+        (status = 400, description = "Invalid city or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "City not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a city; the body may also change its code"
+)]
+async fn update_city(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<CityInputDTO>,
+) -> Result<Json<APIOk<CityDTO>>, APIErr> {
+    let city = UpdateCityUsecase::new(
+        repositories.city.clone(),
+        repositories.region.clone(),
+        repositories.province.clone(),
+    )
+    .execute(
+        &path.into_inner(),
+        input,
+        &change_context(&api_key, param.reason),
+    )
+    .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "City updated".to_string(),
+        city,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/cities/{code}",
+    params(
+        ("code" = String, Path, description = "City code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the city was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "City abolished; returns it with its end date", body = CityDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "City not found", body = APIErr),
+        (status = 409, description = "City still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a city that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_city(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<CityDTO>>, APIErr> {
+    let city = AbolishCityUsecase::new(repositories.city.clone())
+        .execute(
+            &path.into_inner(),
+            param.valid_to(),
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "City abolished".to_string(),
+        city,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/municipalities",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = MunicipalityInputDTO,
+    responses(
+        (status = 201, description = "Municipality created", body = MunicipalityDTO),
+        (status = 400, description = "Invalid municipality or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A municipality with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a municipality"
+)]
+async fn create_municipality(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<MunicipalityInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let municipality = CreateMunicipalityUsecase::new(
+        repositories.municipality.clone(),
+        repositories.region.clone(),
+        repositories.province.clone(),
+        repositories.district.clone(),
+    )
+    .execute(input, &change_context(&api_key, param.reason))
+    .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Municipality created".to_string(),
+        municipality,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/municipalities/{code}",
+    params(
+        ("code" = String, Path, description = "Current municipality code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = MunicipalityInputDTO,
+    responses(
+        (status = 200, description = "Municipality updated", body = MunicipalityDTO),
+        (status = 400, description = "Invalid municipality or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Municipality not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a municipality; the body may also change its code"
+)]
+async fn update_municipality(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<MunicipalityInputDTO>,
+) -> Result<Json<APIOk<MunicipalityDTO>>, APIErr> {
+    let municipality = UpdateMunicipalityUsecase::new(
+        repositories.municipality.clone(),
+        repositories.region.clone(),
+        repositories.province.clone(),
+        repositories.district.clone(),
+    )
+    .execute(
+        &path.into_inner(),
+        input,
+        &change_context(&api_key, param.reason),
+    )
+    .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Municipality updated".to_string(),
+        municipality,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/municipalities/{code}",
+    params(
+        ("code" = String, Path, description = "Municipality code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the municipality was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Municipality abolished; returns it with its end date", body = MunicipalityDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Municipality not found", body = APIErr),
+        (status = 409, description = "Municipality still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a municipality that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_municipality(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<MunicipalityDTO>>, APIErr> {
+    let municipality = AbolishMunicipalityUsecase::new(repositories.municipality.clone())
+        .execute(
+            &path.into_inner(),
+            param.valid_to(),
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Municipality abolished".to_string(),
+        municipality,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/barangays",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = BarangayInputDTO,
+    responses(
+        (status = 201, description = "Barangay created", body = BarangayDTO),
+        (status = 400, description = "Invalid barangay or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "A barangay with this code already exists", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Create a barangay"
+)]
+async fn create_barangay(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<BarangayInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let barangay = CreateBarangayUsecase::new(
+        repositories.barangay.clone(),
+        repositories.city.clone(),
+        repositories.municipality.clone(),
+        repositories.district.clone(),
+    )
+    .execute(input, &change_context(&api_key, param.reason))
+    .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Barangay created".to_string(),
+        barangay,
+    )))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/barangays/{code}",
+    params(
+        ("code" = String, Path, description = "Current barangay code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = BarangayInputDTO,
+    responses(
+        (status = 200, description = "Barangay updated", body = BarangayDTO),
+        (status = 400, description = "Invalid barangay or unknown parent code", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Barangay not found", body = APIErr),
+        (status = 409, description = "The new code is taken or the change would orphan children", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Replace a barangay; the body may also change its code"
+)]
+async fn update_barangay(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<BarangayInputDTO>,
+) -> Result<Json<APIOk<BarangayDTO>>, APIErr> {
+    let barangay = UpdateBarangayUsecase::new(
+        repositories.barangay.clone(),
+        repositories.city.clone(),
+        repositories.municipality.clone(),
+        repositories.district.clone(),
+    )
+    .execute(
+        &path.into_inner(),
+        input,
+        &change_context(&api_key, param.reason),
+    )
+    .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Barangay updated".to_string(),
+        barangay,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/barangays/{code}",
+    params(
+        ("code" = String, Path, description = "Barangay code"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log"),
+        ("valid_to" = Option<String>, Query, description = "The day the barangay was abolished, `YYYY-MM-DD`; defaults to today")
+    ),
+    responses(
+        (status = 200, description = "Barangay abolished; returns it with its end date", body = BarangayDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Barangay not found", body = APIErr),
+        (status = 409, description = "Barangay still has children, or was already abolished", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Abolish a barangay that no longer has children. It is kept, with its end date, for lookups by code and `as_of` queries"
+)]
+async fn delete_barangay(
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<String>,
+    Query(param): Query<AbolishQueryParam>,
+) -> Result<Json<APIOk<BarangayDTO>>, APIErr> {
+    let barangay = AbolishBarangayUsecase::new(repositories.barangay.clone())
+        .execute(
+            &path.into_inner(),
+            param.valid_to(),
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Barangay abolished".to_string(),
+        barangay,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/lineage",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = LineageEventInputDTO,
+    responses(
+        (status = 201, description = "Lineage event recorded", body = LineageEventDTO),
+        (status = 400, description = "Invalid code or event type", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "The event is already recorded", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Record that a unit was replaced: one predecessor and successor of a conversion, split, merge, rename or code change. Codes aren't checked against a release, so units from any release can be linked"
+)]
+async fn create_lineage_event(
+    state: web::Data<APIState>,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<LineageEventInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let event = RecordLineageEventUsecase::new(lineage_repository(&state)?)
+        .execute(input, &change_context(&api_key, param.reason))
+        .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Lineage event recorded".to_string(),
+        event,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/lineage/{id}",
+    params(
+        ("id" = String, Path, description = "Lineage event id"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    responses(
+        (status = 200, description = "Lineage event deleted; returns it", body = LineageEventDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Lineage event not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Delete a lineage event recorded by mistake"
+)]
+async fn delete_lineage_event(
+    state: web::Data<APIState>,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<uuid::Uuid>,
+    Query(param): Query<ReasonQueryParam>,
+) -> Result<Json<APIOk<LineageEventDTO>>, APIErr> {
+    let event = DeleteLineageEventUsecase::new(lineage_repository(&state)?)
+        .execute(path.into_inner(), &change_context(&api_key, param.reason))
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Lineage event deleted".to_string(),
+        event,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/crosswalk",
+    params(
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    request_body = CrosswalkEntryInputDTO,
+    responses(
+        (status = 201, description = "Mapping recorded", body = CrosswalkEntryDTO),
+        (status = 400, description = "Invalid code or mapping type, or the current code isn't in use", body = APIErr),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 409, description = "The historical code is already mapped to this code", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Map a code of an earlier PSGC edition to a code in use now, for codes the lineage doesn't resolve. A code that maps to several needs a mapping for each"
+)]
+async fn create_crosswalk_entry(
+    state: web::Data<APIState>,
+    repositories: InRelease,
+    api_key: ReqData<ApiKeyDTO>,
+    Query(param): Query<ReasonQueryParam>,
+    Json(input): Json<CrosswalkEntryInputDTO>,
+) -> Result<HttpResponse, APIErr> {
+    let entry = RecordCrosswalkEntryUsecase::new(crosswalk_repository(&state)?)
+        .execute(
+            &repositories.release,
+            input,
+            &change_context(&api_key, param.reason),
+        )
+        .await?;
+
+    Ok(HttpResponse::Created().json(APIOk::success_with_message(
+        "Mapping recorded".to_string(),
+        entry,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/crosswalk/{id}",
+    params(
+        ("id" = String, Path, description = "Mapping id"),
+        ("reason" = String, Query, description = "Why the change is made; recorded in the audit log")
+    ),
+    responses(
+        (status = 200, description = "Mapping deleted; returns it", body = CrosswalkEntryDTO),
+        (status = 401, description = "Missing or invalid API key", body = APIErr),
+        (status = 403, description = "API key is not an admin key", body = APIErr),
+        (status = 404, description = "Mapping not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "admin",
+    description = "Delete a crosswalk mapping"
+)]
+async fn delete_crosswalk_entry(
+    state: web::Data<APIState>,
+    api_key: ReqData<ApiKeyDTO>,
+    path: web::Path<uuid::Uuid>,
+    Query(param): Query<ReasonQueryParam>,
+) -> Result<Json<APIOk<CrosswalkEntryDTO>>, APIErr> {
+    let entry = DeleteCrosswalkEntryUsecase::new(crosswalk_repository(&state)?)
+        .execute(path.into_inner(), &change_context(&api_key, param.reason))
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Mapping deleted".to_string(),
+        entry,
+    )))
+}
@@ -1,19 +1,20 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO},
-    usecases::barangay_usecases::{GetBarangayByCodeUsecase, ListBarangaysUsecase},
-};
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
+    dto::{NameLookupDTO, PaginateResponseDTO, barangay_dto::BarangayDTO},
+    usecases::{
+        barangay_usecases::{
+            FindBarangayByNameUsecase, GetBarangayByCodeUsecase, GetBarangayBySlugUsecase,
+            ListBarangaysUsecase,
+        },
+        island_group_usecases::ListBarangaysByIslandGroupUsecase,
+    },
 };
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -21,10 +22,13 @@ use crate::{
     paths(
         list_barangays,
         get_barangay_by_code,
+        get_barangay_by_slug,
+        find_barangay_by_name,
     ),
     components(schemas(
         BarangayDTO,
         PaginateResponseDTO<BarangayDTO>,
+        NameLookupDTO<BarangayDTO>,
         APIErr
     )),
     tags((
@@ -34,19 +38,12 @@ use crate::{
 )]
 pub struct BarangayAPIDoc;
 
-pub fn build_barangay_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_barangay_route() -> actix_web::Scope {
     web::scope("/barangays")
-        .service(web::resource("").route(web::get().to(list_barangays::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_barangay_by_code::<R, P, M, D, C, B>)),
-        )
+        .service(web::resource("").route(web::get().to(list_barangays)))
+        .service(web::resource("/by-name").route(web::get().to(find_barangay_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_barangay_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_barangay_by_code)))
 }
 
 #[utoipa::path(
@@ -54,7 +51,8 @@ pub fn build_barangay_route<
     path = "/api/v1/barangays",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only barangays in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved barangays", body = PaginateResponseDTO<BarangayDTO>),
@@ -64,23 +62,25 @@ pub fn build_barangay_route<
     tag = "barangays",
     description = "Get all barangays"
 )]
-async fn list_barangays<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_barangays(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<BarangayDTO>>>, APIErr> {
-    let barangay_repository = state.barangay_repository.clone();
-    let list_barangays_usecase = ListBarangaysUsecase::new(barangay_repository);
+    let barangay_repository = repositories.barangay.clone();
 
-    let barangays = list_barangays_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let barangays = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListBarangaysByIslandGroupUsecase::new(barangay_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListBarangaysUsecase::new(barangay_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Barangays".to_string(),
@@ -102,18 +102,11 @@ async fn list_barangays<
     tag = "barangays",
     description = "Get barangay by code"
 )]
-async fn get_barangay_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_barangay_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<BarangayDTO>>, APIErr> {
-    let barangay_repository = state.barangay_repository.clone();
+    let barangay_repository = repositories.barangay.clone();
     let get_barangay_by_code_usecase = GetBarangayByCodeUsecase::new(barangay_repository);
 
     let barangay = get_barangay_by_code_usecase
@@ -125,3 +118,64 @@ async fn get_barangay_by_code<
         barangay,
     )))
 }
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/barangays/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Barangay slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved barangay", body = BarangayDTO),
+        (status = 404, description = "Barangay not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "barangays",
+    description = "Get barangay by slug"
+)]
+async fn get_barangay_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<BarangayDTO>>, APIErr> {
+    let barangay_repository = repositories.barangay.clone();
+    let get_barangay_by_slug_usecase = GetBarangayBySlugUsecase::new(barangay_repository);
+
+    let barangay = get_barangay_by_slug_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Barangay details".to_string(),
+        barangay,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/barangays/by-name",
+    params(
+        ("name" = String, Query, description = "Barangay name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching barangay, or every candidate when the name is shared", body = NameLookupDTO<BarangayDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "Barangay not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "barangays",
+    description = "Find barangay by exact name"
+)]
+async fn find_barangay_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<BarangayDTO>>>, APIErr> {
+    let barangay_repository = repositories.barangay.clone();
+    let find_barangay_by_name_usecase = FindBarangayByNameUsecase::new(barangay_repository);
+
+    let lookup = find_barangay_by_name_usecase.execute(&param.name).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Barangay by name".to_string(),
+        lookup,
+    )))
+}
@@ -0,0 +1,67 @@
+use actix_web::web::{self, Json, Query};
+use psgc_application::{
+    dto::change_dto::{ChangeDTO, ChangesDTO},
+    errors::UsecaseError,
+    usecases::change_usecases::ListChangesUsecase,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::ChangesQueryParam,
+    release::InRelease,
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(list_changes),
+    components(schemas(ChangesDTO, ChangeDTO, APIErr)),
+    tags((
+        name = "changes",
+        description = "Feed of changes for keeping a local copy of a release current"
+    ))
+)]
+pub struct ChangeAPIDoc;
+
+pub fn build_change_route() -> actix_web::Resource {
+    web::resource("/changes").route(web::get().to(list_changes))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/changes",
+    params(
+        ("since" = String, Query, description = "The `next` token of the last response, or a date (`YYYY-MM-DD`) or RFC 3339 timestamp to start from"),
+        ("limit" = Option<u64>, Query, description = "Most changes to return, up to 1000; 500 by default")
+    ),
+    responses(
+        (status = 200, description = "The changes since then, oldest first", body = ChangesDTO),
+        (status = 400, description = "Unreadable `since` or a limit out of range", body = APIErr),
+        (status = 404, description = "Release not loaded, or the in-memory backend, which keeps no changes", body = APIErr),
+        (status = 409, description = "`since` is before the release was loaded; download the release first", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "changes",
+    description = "Inserts, updates and deletions of units at every level of the release served, in the order they were made. A deletion is a tombstone with the level and code but no unit. An abolition is a deletion that carries the unit, whose `valid_to` is the day it is gone from, possibly later than the change. Keep calling with `next` while `has_more` is true, then store `next` for the following sync"
+)]
+async fn list_changes(
+    state: web::Data<APIState>,
+    repositories: InRelease,
+    Query(param): Query<ChangesQueryParam>,
+) -> Result<Json<APIOk<ChangesDTO>>, APIErr> {
+    let audit_repository = state
+        .repositories
+        .audit_log
+        .clone()
+        .ok_or(UsecaseError::NotFound)?;
+
+    let changes = ListChangesUsecase::new(audit_repository, state.repositories.releases.clone())
+        .execute(&repositories.release, &param.since, param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        format!("{} changes to {}", changes.changes.len(), changes.release),
+        changes,
+    )))
+}
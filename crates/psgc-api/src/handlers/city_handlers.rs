@@ -1,21 +1,20 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO, city_dto::CityDTO},
-    usecases::city_usecases::{
-        GetCityByCodeUsecase, ListBarangaysByCityUsecase, ListCitiesUsecase,
+    dto::{NameLookupDTO, PaginateResponseDTO, barangay_dto::BarangayDTO, city_dto::CityDTO},
+    usecases::{
+        city_usecases::{
+            FindCityByNameUsecase, GetCityByCodeUsecase, GetCityBySlugUsecase,
+            ListBarangaysByCityUsecase, ListCitiesUsecase,
+        },
+        island_group_usecases::ListCitiesByIslandGroupUsecase,
     },
 };
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
-};
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -23,11 +22,14 @@ use crate::{
     paths(
         list_cities,
         get_city_by_code,
+        get_city_by_slug,
+        find_city_by_name,
         get_barangays_by_city,
     ),
     components(schemas(
         CityDTO,
         PaginateResponseDTO<CityDTO>,
+        NameLookupDTO<CityDTO>,
         BarangayDTO,
         APIErr
     )),
@@ -38,28 +40,14 @@ use crate::{
 )]
 pub struct CityAPIDoc;
 
-pub fn build_city_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_city_route() -> actix_web::Scope {
     web::scope("/cities")
-        .service(web::resource("").route(web::get().to(list_cities::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_city_by_code::<R, P, M, D, C, B>)),
-        )
+        .service(web::resource("").route(web::get().to(list_cities)))
+        .service(web::resource("/by-name").route(web::get().to(find_city_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_city_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_city_by_code)))
         .service(
-            web::resource("/{city_code}/barangays").route(web::get().to(get_barangays_by_city::<
-                R,
-                P,
-                M,
-                D,
-                C,
-                B,
-            >)),
+            web::resource("/{city_code}/barangays").route(web::get().to(get_barangays_by_city)),
         )
 }
 
@@ -68,7 +56,8 @@ pub fn build_city_route<
     path = "/api/v1/cities",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only cities in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved cities", body = PaginateResponseDTO<CityDTO>),
@@ -78,23 +67,25 @@ pub fn build_city_route<
     tag = "cities",
     description = "Get all cities"
 )]
-async fn list_cities<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_cities(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<CityDTO>>>, APIErr> {
-    let city_repository = state.city_repository.clone();
-    let list_cities_usecase = ListCitiesUsecase::new(city_repository);
+    let city_repository = repositories.city.clone();
 
-    let cities = list_cities_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let cities = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListCitiesByIslandGroupUsecase::new(city_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListCitiesUsecase::new(city_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Cities".to_string(),
@@ -116,18 +107,11 @@ async fn list_cities<
     tag = "cities",
     description = "Get city by code"
 )]
-async fn get_city_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_city_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<CityDTO>>, APIErr> {
-    let city_repository = state.city_repository.clone();
+    let city_repository = repositories.city.clone();
     let get_city_by_code_usecase = GetCityByCodeUsecase::new(city_repository);
 
     let city = get_city_by_code_usecase.execute(&path.into_inner()).await?;
@@ -138,6 +122,65 @@ async fn get_city_by_code<
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/cities/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "City slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved city", body = CityDTO),
+        (status = 404, description = "City not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "cities",
+    description = "Get city by slug"
+)]
+async fn get_city_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<CityDTO>>, APIErr> {
+    let city_repository = repositories.city.clone();
+    let get_city_by_slug_usecase = GetCityBySlugUsecase::new(city_repository);
+
+    let city = get_city_by_slug_usecase.execute(&path.into_inner()).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "City details".to_string(),
+        city,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/cities/by-name",
+    params(
+        ("name" = String, Query, description = "City name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching city, or every candidate when the name is shared", body = NameLookupDTO<CityDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "City not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "cities",
+    description = "Find city by exact name"
+)]
+async fn find_city_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<CityDTO>>>, APIErr> {
+    let city_repository = repositories.city.clone();
+    let find_city_by_name_usecase = FindCityByNameUsecase::new(city_repository);
+
+    let lookup = find_city_by_name_usecase.execute(&param.name).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "City by name".to_string(),
+        lookup,
+    )))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/cities/{city_code}/barangays",
@@ -152,18 +195,11 @@ async fn get_city_by_code<
     tag = "cities",
     description = "Get barangays by city"
 )]
-async fn get_barangays_by_city<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_barangays_by_city(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<BarangayDTO>>>, APIErr> {
-    let city_repository = state.city_repository.clone();
+    let city_repository = repositories.city.clone();
     let list_barangays_by_city_usecase = ListBarangaysByCityUsecase::new(city_repository);
 
     let barangays = list_barangays_by_city_usecase
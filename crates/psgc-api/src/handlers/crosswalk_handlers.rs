@@ -0,0 +1,104 @@
+use actix_web::{
+    HttpResponse,
+    web::{self, Json, Query},
+};
+use psgc_application::{
+    dto::{
+        crosswalk_dto::{CodeResolutionDTO, CrosswalkDTO, CrosswalkRequestDTO},
+        release_dto::ReleaseUnitDTO,
+    },
+    errors::UsecaseError,
+    usecases::crosswalk_usecases::ResolveCodesUsecase,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::FormatQueryParam,
+    release::InRelease,
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+/// Room for the largest batch the resolver takes.
+const BODY_LIMIT: usize = 256 * 1024;
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(resolve_codes),
+    components(schemas(
+        CrosswalkRequestDTO,
+        CrosswalkDTO,
+        CodeResolutionDTO,
+        ReleaseUnitDTO,
+        APIErr
+    )),
+    tags((
+        name = "crosswalk",
+        description = "Resolve historical codes, such as pre-2019 9-digit codes and codes of earlier PSGC editions, to the codes in use now"
+    ))
+)]
+pub struct CrosswalkAPIDoc;
+
+pub fn build_crosswalk_route() -> actix_web::Resource {
+    web::resource("/crosswalk")
+        .app_data(
+            web::JsonConfig::default()
+                .limit(BODY_LIMIT)
+                .error_handler(|err, _req| {
+                    APIErr::from(UsecaseError::ValidationError(err.to_string())).into()
+                }),
+        )
+        .route(web::post().to(resolve_codes))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/crosswalk",
+    params(
+        ("format" = Option<String>, Query, description = "`json` (default) or `csv`, with a row per current unit")
+    ),
+    request_body = CrosswalkRequestDTO,
+    responses(
+        (status = 200, description = "What each code maps to now", body = CrosswalkDTO),
+        (status = 400, description = "No codes, too many, or an unknown format", body = APIErr),
+        (status = 404, description = "Release not loaded, or the in-memory backend, which has no crosswalk", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "crosswalk",
+    description = "Resolve a batch of historical codes to the current code(s) of the release served. A code still in use is `identical`; others are `renamed`, `split` or `merged`, taken from the curated crosswalk or else from the recorded lineage. Unknown codes come back without a mapping type"
+)]
+async fn resolve_codes(
+    state: web::Data<APIState>,
+    repositories: InRelease,
+    Query(param): Query<FormatQueryParam>,
+    Json(request): Json<CrosswalkRequestDTO>,
+) -> Result<HttpResponse, APIErr> {
+    if !matches!(param.format(), "json" | "csv") {
+        return Err(UsecaseError::ValidationError(format!(
+            "unknown format {}; use json or csv",
+            param.format()
+        ))
+        .into());
+    }
+
+    let (Some(crosswalk_repository), Some(lineage_repository)) = (
+        state.repositories.crosswalk.clone(),
+        state.repositories.lineage.clone(),
+    ) else {
+        return Err(UsecaseError::NotFound.into());
+    };
+
+    let crosswalk = ResolveCodesUsecase::new(crosswalk_repository, lineage_repository)
+        .execute(&repositories.release, request.codes)
+        .await?;
+
+    match param.format() {
+        "csv" => Ok(HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .body(crosswalk.to_csv())),
+        _ => Ok(HttpResponse::Ok().json(APIOk::success_with_message(
+            format!("Codes resolved against {}", crosswalk.release),
+            crosswalk,
+        ))),
+    }
+}
@@ -0,0 +1,265 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    body::SizedStream,
+    http::header::{
+        CacheControl, CacheDirective, ContentDisposition, DispositionParam, DispositionType, ETag,
+        EntityTag,
+    },
+    web::{self, Query},
+};
+use psgc_application::dto::dataset_dto::{
+    DatasetFileDTO, DatasetLevelManifestDTO, DatasetManifestDTO,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dataset::FORMAT_DESCRIPTION,
+    dto::DatasetQueryParam,
+    errors::APIError,
+    etag::{is_fresh, respond_with_etag},
+    release::InRelease,
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+/// Clients check back with `If-None-Match`, so the manifest is only kept briefly.
+const MANIFEST_MAX_AGE: u32 = 60;
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(download_dataset, get_dataset_manifest),
+    components(schemas(
+        DatasetManifestDTO,
+        DatasetLevelManifestDTO,
+        DatasetFileDTO,
+        APIErr
+    )),
+    tags((
+        name = "dataset",
+        description = "The whole current dataset of a release as one file, for offline use"
+    ))
+)]
+pub struct DatasetAPIDoc;
+
+pub fn build_dataset_route() -> actix_web::Scope {
+    web::scope("/dataset")
+        .service(web::resource("").route(web::get().to(download_dataset)))
+        .service(web::resource("/manifest").route(web::get().to(get_dataset_manifest)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/dataset",
+    params(
+        ("format" = Option<String>, Query, description = FORMAT_DESCRIPTION)
+    ),
+    responses(
+        (status = 200, description = "The gzipped dataset", content_type = "application/gzip"),
+        (status = 304, description = "The copy named in `If-None-Match` is still current"),
+        (status = 400, description = "Unknown format, or one this build can't produce", body = APIErr),
+        (status = 404, description = "Release not loaded", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "dataset",
+    description = "Every current unit of the release at every level, as one gzipped file. The NDJSON file starts with a `{\"manifest\": ...}` line, followed by a `{\"level\": ..., \"unit\": ...}` line per unit from regions down to barangays. The ETag is the file's SHA-256; send it back in `If-None-Match` to download only when the data changed"
+)]
+async fn download_dataset(
+    req: HttpRequest,
+    state: web::Data<APIState>,
+    repositories: InRelease,
+    Query(param): Query<DatasetQueryParam>,
+) -> Result<HttpResponse, APIErr> {
+    let file = state
+        .datasets
+        .file(
+            &state.repositories,
+            &repositories.release,
+            repositories.as_of,
+            param.format(),
+        )
+        .await?;
+    let described = &file.described;
+
+    let etag = EntityTag::new_strong(described.sha256.clone());
+    let cache_control = match repositories.as_of {
+        Some(_) => CacheControl(vec![CacheDirective::NoStore]),
+        None => CacheControl(vec![CacheDirective::Public, CacheDirective::NoCache]),
+    };
+    if is_fresh(&req, &etag) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header(cache_control)
+            .finish());
+    }
+
+    let file_name = described.file_name.clone();
+    let body = SizedStream::new(
+        described.bytes as u64,
+        file.chunks().await.map_err(|e| {
+            APIErr::builder()
+                .code("psgc-500".to_string())
+                .error(APIError::InternalError(e.to_string()))
+                .build()
+        })?,
+    );
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/gzip")
+        .insert_header(ETag(etag))
+        .insert_header(cache_control)
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .body(body))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/dataset/manifest",
+    params(
+        ("format" = Option<String>, Query, description = FORMAT_DESCRIPTION)
+    ),
+    responses(
+        (status = 200, description = "What the file holds and its checksum", body = DatasetManifestDTO),
+        (status = 304, description = "The manifest named in `If-None-Match` is still current"),
+        (status = 400, description = "Unknown format, or one this build can't produce", body = APIErr),
+        (status = 404, description = "Release not loaded", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "dataset",
+    description = "The manifest of the dataset file: the release, the sync token to follow `/api/v1/changes` from, the rows and SHA-256 of each level, and the size and SHA-256 of the gzipped file"
+)]
+async fn get_dataset_manifest(
+    req: HttpRequest,
+    state: web::Data<APIState>,
+    repositories: InRelease,
+    Query(param): Query<DatasetQueryParam>,
+) -> Result<HttpResponse, APIErr> {
+    let file = state
+        .datasets
+        .file(
+            &state.repositories,
+            &repositories.release,
+            repositories.as_of,
+            param.format(),
+        )
+        .await?;
+
+    respond_with_etag(
+        &req,
+        &APIOk::success_with_message(
+            format!("Dataset of {}", file.manifest.release),
+            file.full_manifest(),
+        ),
+        MANIFEST_MAX_AGE,
+    )
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use std::io::Read;
+
+    use actix_web::{
+        App,
+        http::header,
+        test::{TestRequest, call_service, init_service, read_body},
+    };
+    use flate2::read::GzDecoder;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn builds_downloads_as_of_a_day_apart() {
+        use chrono::{NaiveDate, Utc};
+        use psgc_domain::{
+            models::audit::AuditEntry,
+            repositories::municipality_repository::MunicipalityRepository,
+        };
+        use psgc_infrastructure::{
+            config::db_config::DatabaseConfig,
+            database::{
+                migrator::migrator,
+                pool::create_db_pool,
+                seed_data::{BUNDLED_RELEASE, SeedData},
+                seeder::seeder,
+            },
+            repositories::backend::Repositories,
+        };
+
+        use crate::metrics::Metrics;
+
+        let path = std::env::temp_dir().join(format!("psgc-dataset-{}.db", uuid::Uuid::now_v7()));
+        let config = DatabaseConfig::builder()
+            .db_url(format!("sqlite://{}", path.display()))
+            .build();
+        migrator(&config).await.unwrap();
+        let db = create_db_pool(&config).unwrap();
+        seeder(db.clone(), &SeedData::bundled()).await.unwrap();
+        let repositories = Repositories::sql(db);
+
+        let municipalities = repositories.for_release(BUNDLED_RELEASE, None).municipality;
+        let mut adams = municipalities.find_by_code("012801000").await.unwrap();
+        adams.valid_to = NaiveDate::from_ymd_opt(2025, 1, 1);
+        let audit = AuditEntry::builder()
+            .id(uuid::Uuid::now_v7())
+            .actor("test".to_string())
+            .entity("municipality".to_string())
+            .entity_id(adams.id)
+            .code(adams.code.clone())
+            .created_at(Utc::now())
+            .build();
+        municipalities.update(&adams, &audit).await.unwrap();
+
+        let state = APIState::builder()
+            .allowed_origins(Vec::new())
+            .repositories(repositories)
+            .metrics(Metrics::new().unwrap())
+            .build();
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(state))
+                .service(web::scope("/api/v1").service(build_dataset_route())),
+        )
+        .await;
+        let download = async |uri: &str| {
+            let res = call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+            let header = |name| {
+                res.headers()
+                    .get(name)
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            };
+            let (etag, cache_control) = (header(header::ETAG), header(header::CACHE_CONTROL));
+            let mut ndjson = String::new();
+            GzDecoder::new(&read_body(res).await[..])
+                .read_to_string(&mut ndjson)
+                .unwrap();
+
+            (etag, cache_control, ndjson)
+        };
+
+        let (before_etag, cache_control, before) =
+            download("/api/v1/dataset?as_of=2024-12-01").await;
+        assert!(before.contains("\"Adams\""));
+        assert_eq!(cache_control, "no-store");
+
+        let (now_etag, cache_control, now) = download("/api/v1/dataset").await;
+        assert!(!now.contains("\"Adams\""));
+        assert_ne!(now_etag, before_etag);
+        assert_eq!(cache_control, "public, no-cache");
+
+        // Neither is served the other's file
+        assert_eq!(
+            download("/api/v1/dataset?as_of=2024-12-01").await.0,
+            before_etag
+        );
+        assert_eq!(download("/api/v1/dataset").await.0, now_etag);
+
+        for suffix in ["", "-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+        }
+    }
+}
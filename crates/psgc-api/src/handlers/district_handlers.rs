@@ -1,25 +1,23 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, district_dto::DistrictDTO,
+        NameLookupDTO, PaginateResponseDTO, city_dto::CityDTO, district_dto::DistrictDTO,
         municipality_dto::MunicipalityDTO,
     },
-    usecases::district_usecases::{
-        GetDistrictByCodeUsecase, ListCitiesByDistrictUsecase, ListDistrictsUsecase,
-        ListMunicipalitiesByDistrictUsecase,
+    usecases::{
+        district_usecases::{
+            FindDistrictByNameUsecase, GetDistrictByCodeUsecase, GetDistrictBySlugUsecase,
+            ListCitiesByDistrictUsecase, ListDistrictsUsecase, ListMunicipalitiesByDistrictUsecase,
+        },
+        island_group_usecases::ListDistrictsByIslandGroupUsecase,
     },
 };
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
-};
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -27,12 +25,15 @@ use crate::{
     paths(
         list_districts,
         get_district_by_code,
+        get_district_by_slug,
+        find_district_by_name,
         get_cities_by_district,
         get_municipalities_by_district,
     ),
     components(schemas(
         DistrictDTO,
         PaginateResponseDTO<DistrictDTO>,
+        NameLookupDTO<DistrictDTO>,
         CityDTO,
         MunicipalityDTO,
         APIErr
@@ -44,26 +45,18 @@ use crate::{
 )]
 pub struct DistrictAPIDoc;
 
-pub fn build_district_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_district_route() -> actix_web::Scope {
     web::scope("/districts")
-        .service(web::resource("").route(web::get().to(list_districts::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_district_by_code::<R, P, M, D, C, B>)),
-        )
+        .service(web::resource("").route(web::get().to(list_districts)))
+        .service(web::resource("/by-name").route(web::get().to(find_district_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_district_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_district_by_code)))
         .service(
-            web::resource("/{district_code}/cities")
-                .route(web::get().to(get_cities_by_district::<R, P, M, D, C, B>)),
+            web::resource("/{district_code}/cities").route(web::get().to(get_cities_by_district)),
         )
         .service(
             web::resource("/{district_code}/municipalities")
-                .route(web::get().to(get_municipalities_by_district::<R, P, M, D, C, B>)),
+                .route(web::get().to(get_municipalities_by_district)),
         )
 }
 
@@ -72,7 +65,8 @@ pub fn build_district_route<
     path = "/api/v1/districts",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only districts in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved districts", body = PaginateResponseDTO<DistrictDTO>),
@@ -82,23 +76,25 @@ pub fn build_district_route<
     tag = "districts",
     description = "Get all districts"
 )]
-async fn list_districts<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_districts(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<DistrictDTO>>>, APIErr> {
-    let district_repository = state.district_repository.clone();
-    let list_districts_usecase = ListDistrictsUsecase::new(district_repository);
+    let district_repository = repositories.district.clone();
 
-    let districts = list_districts_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let districts = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListDistrictsByIslandGroupUsecase::new(district_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListDistrictsUsecase::new(district_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Districts".to_string(),
@@ -120,18 +116,11 @@ async fn list_districts<
     tag = "districts",
     description = "Get district by code"
 )]
-async fn get_district_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_district_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<DistrictDTO>>, APIErr> {
-    let district_repository = state.district_repository.clone();
+    let district_repository = repositories.district.clone();
     let get_district_by_code_usecase = GetDistrictByCodeUsecase::new(district_repository);
 
     let district = get_district_by_code_usecase
@@ -144,6 +133,67 @@ async fn get_district_by_code<
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/districts/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "District slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved district", body = DistrictDTO),
+        (status = 404, description = "District not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "districts",
+    description = "Get district by slug"
+)]
+async fn get_district_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<DistrictDTO>>, APIErr> {
+    let district_repository = repositories.district.clone();
+    let get_district_by_slug_usecase = GetDistrictBySlugUsecase::new(district_repository);
+
+    let district = get_district_by_slug_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "District details".to_string(),
+        district,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/districts/by-name",
+    params(
+        ("name" = String, Query, description = "District name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching district, or every candidate when the name is shared", body = NameLookupDTO<DistrictDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "District not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "districts",
+    description = "Find district by exact name"
+)]
+async fn find_district_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<DistrictDTO>>>, APIErr> {
+    let district_repository = repositories.district.clone();
+    let find_district_by_name_usecase = FindDistrictByNameUsecase::new(district_repository);
+
+    let lookup = find_district_by_name_usecase.execute(&param.name).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "District by name".to_string(),
+        lookup,
+    )))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/districts/{district_code}/cities",
@@ -158,18 +208,11 @@ async fn get_district_by_code<
     tag = "districts",
     description = "Get cities by district"
 )]
-async fn get_cities_by_district<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_cities_by_district(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<CityDTO>>>, APIErr> {
-    let district_repository = state.district_repository.clone();
+    let district_repository = repositories.district.clone();
     let list_cities_by_district_usecase = ListCitiesByDistrictUsecase::new(district_repository);
 
     let cities = list_cities_by_district_usecase
@@ -196,18 +239,11 @@ async fn get_cities_by_district<
     tag = "districts",
     description = "Get municipalities by district"
 )]
-async fn get_municipalities_by_district<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_municipalities_by_district(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<MunicipalityDTO>>>, APIErr> {
-    let district_repository = state.district_repository.clone();
+    let district_repository = repositories.district.clone();
     let list_municipalities_by_district_usecase =
         ListMunicipalitiesByDistrictUsecase::new(district_repository);
 
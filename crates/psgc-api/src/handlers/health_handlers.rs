@@ -0,0 +1,60 @@
+use actix_web::{HttpResponse, http::StatusCode, web};
+use utoipa::OpenApi;
+
+use crate::{dto::ReadinessDTO, response::APIOk, router::APIState};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(healthz, readyz),
+    components(schemas(ReadinessDTO)),
+    tags((
+        name = "health",
+        description = "Liveness and readiness probes"
+    ))
+)]
+pub struct HealthAPIDoc;
+
+/// Mounted at the root rather than under `/api/v1`, where probes usually look.
+pub fn build_health_routes(config: &mut web::ServiceConfig) {
+    config
+        .service(web::resource("/healthz").route(web::get().to(healthz)))
+        .service(web::resource("/readyz").route(web::get().to(readyz)));
+}
+
+#[utoipa::path(
+    get,
+    path = "/healthz",
+    responses(
+        (status = 200, description = "The process is up")
+    ),
+    tag = "health",
+    description = "Liveness probe; doesn't touch the database"
+)]
+async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().json(APIOk::<()>::empty_with_message("OK".to_string()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "Every check passed", body = ReadinessDTO),
+        (status = 503, description = "At least one check failed", body = ReadinessDTO)
+    ),
+    tag = "health",
+    description = "Readiness probe: database connection, migration version and seeded tables"
+)]
+async fn readyz(state: web::Data<APIState>) -> HttpResponse {
+    let report = state.repositories.readiness.check().await;
+
+    let (status, message) = if report.is_ready() {
+        (StatusCode::OK, "Ready")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "Not ready")
+    };
+
+    HttpResponse::build(status).json(APIOk::success_with_message(
+        message.to_string(),
+        ReadinessDTO::from(report),
+    ))
+}
@@ -0,0 +1,320 @@
+use actix_web::web::{self, Json, Query};
+use psgc_application::{
+    dto::{
+        PaginateResponseDTO, barangay_dto::BarangayDTO, city_dto::CityDTO,
+        district_dto::DistrictDTO, island_group_dto::IslandGroupDTO,
+        municipality_dto::MunicipalityDTO, province_dto::ProvinceDTO, region_dto::RegionDTO,
+    },
+    usecases::island_group_usecases::{
+        GetIslandGroupUsecase, ListBarangaysByIslandGroupUsecase, ListCitiesByIslandGroupUsecase,
+        ListDistrictsByIslandGroupUsecase, ListIslandGroupsUsecase,
+        ListMunicipalitiesByIslandGroupUsecase, ListProvincesByIslandGroupUsecase,
+        ListRegionsByIslandGroupUsecase,
+    },
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::PaginateQueryParam,
+    release::InRelease,
+    response::{APIErr, APIOk},
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(
+        list_island_groups,
+        get_island_group,
+        get_regions_by_island_group,
+        get_provinces_by_island_group,
+        get_districts_by_island_group,
+        get_cities_by_island_group,
+        get_municipalities_by_island_group,
+        get_barangays_by_island_group,
+    ),
+    components(schemas(
+        IslandGroupDTO,
+        PaginateResponseDTO<RegionDTO>,
+        PaginateResponseDTO<ProvinceDTO>,
+        PaginateResponseDTO<DistrictDTO>,
+        PaginateResponseDTO<CityDTO>,
+        PaginateResponseDTO<MunicipalityDTO>,
+        PaginateResponseDTO<BarangayDTO>,
+        APIErr
+    )),
+    tags((
+        name = "island-groups",
+        description = "Luzon, Visayas and Mindanao island groups API"
+    ))
+)]
+pub struct IslandGroupAPIDoc;
+
+pub fn build_island_group_route() -> actix_web::Scope {
+    web::scope("/island-groups")
+        .service(web::resource("").route(web::get().to(list_island_groups)))
+        .service(web::resource("/{id}").route(web::get().to(get_island_group)))
+        .service(web::resource("/{id}/regions").route(web::get().to(get_regions_by_island_group)))
+        .service(
+            web::resource("/{id}/provinces").route(web::get().to(get_provinces_by_island_group)),
+        )
+        .service(
+            web::resource("/{id}/districts").route(web::get().to(get_districts_by_island_group)),
+        )
+        .service(web::resource("/{id}/cities").route(web::get().to(get_cities_by_island_group)))
+        .service(
+            web::resource("/{id}/municipalities")
+                .route(web::get().to(get_municipalities_by_island_group)),
+        )
+        .service(
+            web::resource("/{id}/barangays").route(web::get().to(get_barangays_by_island_group)),
+        )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups",
+    responses(
+        (status = 200, description = "Successfully retrieved island groups", body = Vec<IslandGroupDTO>)
+    ),
+    tag = "island-groups",
+    description = "Get all island groups"
+)]
+async fn list_island_groups() -> Result<Json<APIOk<Vec<IslandGroupDTO>>>, APIErr> {
+    let island_groups = ListIslandGroupsUsecase::new().execute();
+
+    Ok(Json(APIOk::success_with_message(
+        "All Island Groups".to_string(),
+        island_groups,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved island group", body = IslandGroupDTO),
+        (status = 404, description = "Island group not found", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get island group by id"
+)]
+async fn get_island_group(path: web::Path<String>) -> Result<Json<APIOk<IslandGroupDTO>>, APIErr> {
+    let island_group = GetIslandGroupUsecase::new().execute(&path.into_inner())?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Island group details".to_string(),
+        island_group,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/regions",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved regions", body = PaginateResponseDTO<RegionDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get regions by island group"
+)]
+async fn get_regions_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<RegionDTO>>>, APIErr> {
+    let region_repository = repositories.region.clone();
+    let list_regions_by_island_group_usecase =
+        ListRegionsByIslandGroupUsecase::new(region_repository);
+
+    let regions = list_regions_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Regions by island group".to_string(),
+        regions,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/provinces",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved provinces", body = PaginateResponseDTO<ProvinceDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get provinces by island group"
+)]
+async fn get_provinces_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<ProvinceDTO>>>, APIErr> {
+    let province_repository = repositories.province.clone();
+    let list_provinces_by_island_group_usecase =
+        ListProvincesByIslandGroupUsecase::new(province_repository);
+
+    let provinces = list_provinces_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Provinces by island group".to_string(),
+        provinces,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/districts",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved districts", body = PaginateResponseDTO<DistrictDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get districts by island group"
+)]
+async fn get_districts_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<DistrictDTO>>>, APIErr> {
+    let district_repository = repositories.district.clone();
+    let list_districts_by_island_group_usecase =
+        ListDistrictsByIslandGroupUsecase::new(district_repository);
+
+    let districts = list_districts_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Districts by island group".to_string(),
+        districts,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/cities",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved cities", body = PaginateResponseDTO<CityDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get cities by island group"
+)]
+async fn get_cities_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<CityDTO>>>, APIErr> {
+    let city_repository = repositories.city.clone();
+    let list_cities_by_island_group_usecase = ListCitiesByIslandGroupUsecase::new(city_repository);
+
+    let cities = list_cities_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Cities by island group".to_string(),
+        cities,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/municipalities",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved municipalities", body = PaginateResponseDTO<MunicipalityDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get municipalities by island group"
+)]
+async fn get_municipalities_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<MunicipalityDTO>>>, APIErr> {
+    let municipality_repository = repositories.municipality.clone();
+    let list_municipalities_by_island_group_usecase =
+        ListMunicipalitiesByIslandGroupUsecase::new(municipality_repository);
+
+    let municipalities = list_municipalities_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Municipalities by island group".to_string(),
+        municipalities,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/island-groups/{id}/barangays",
+    params(
+        ("id" = String, Path, description = "Island group id: luzon, visayas or mindanao"),
+        ("page" = Option<u32>, Query, description = "Page number"),
+        ("limit" = Option<u32>, Query, description = "Items per page")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved barangays", body = PaginateResponseDTO<BarangayDTO>),
+        (status = 400, description = "Unknown island group", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "island-groups",
+    description = "Get barangays by island group"
+)]
+async fn get_barangays_by_island_group(
+    repositories: InRelease,
+    path: web::Path<String>,
+    Query(param): Query<PaginateQueryParam>,
+) -> Result<Json<APIOk<PaginateResponseDTO<BarangayDTO>>>, APIErr> {
+    let barangay_repository = repositories.barangay.clone();
+    let list_barangays_by_island_group_usecase =
+        ListBarangaysByIslandGroupUsecase::new(barangay_repository);
+
+    let barangays = list_barangays_by_island_group_usecase
+        .execute(&path.into_inner(), param.page(), param.limit())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Barangays by island group".to_string(),
+        barangays,
+    )))
+}
@@ -0,0 +1,97 @@
+use actix_web::web::{self, Json};
+use psgc_application::{
+    dto::lineage_dto::{LineageDTO, LineageEventDTO, LineageStepDTO},
+    usecases::lineage_usecases::TraceLineageUsecase,
+};
+use psgc_domain::models::lineage::LineageDirection;
+use utoipa::OpenApi;
+
+use crate::{
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(get_successors, get_predecessors),
+    components(schemas(LineageDTO, LineageStepDTO, LineageEventDTO, APIErr)),
+    tags((
+        name = "lineage",
+        description = "Conversions, splits, merges, renames and code changes that link old codes to the codes that replaced them, across releases"
+    ))
+)]
+pub struct LineageAPIDoc;
+
+pub fn build_code_route() -> actix_web::Scope {
+    web::scope("/codes")
+        .service(web::resource("/{code}/successors").route(web::get().to(get_successors)))
+        .service(web::resource("/{code}/predecessors").route(web::get().to(get_predecessors)))
+}
+
+async fn trace(
+    state: &APIState,
+    code: String,
+    direction: LineageDirection,
+) -> Result<LineageDTO, APIErr> {
+    // The in-memory backend has no lineage to follow
+    let Some(lineage_repository) = state.repositories.lineage.clone() else {
+        return Ok(LineageDTO {
+            code,
+            events: vec![],
+        });
+    };
+
+    Ok(TraceLineageUsecase::new(lineage_repository)
+        .execute(&code, direction)
+        .await?)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/codes/{code}/successors",
+    params(
+        ("code" = String, Path, description = "PSGC code, current or historical")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved successors", body = LineageDTO),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "lineage",
+    description = "Get the events that replaced a code, followed through their successors to the codes in use now. The codes of the deepest events with no successors of their own are the current ones"
+)]
+async fn get_successors(
+    state: web::Data<APIState>,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<LineageDTO>>, APIErr> {
+    let lineage = trace(&state, path.into_inner(), LineageDirection::Successors).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Successors".to_string(),
+        lineage,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/codes/{code}/predecessors",
+    params(
+        ("code" = String, Path, description = "PSGC code, current or historical")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved predecessors", body = LineageDTO),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "lineage",
+    description = "Get the events that produced a code, followed back through their predecessors"
+)]
+async fn get_predecessors(
+    state: web::Data<APIState>,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<LineageDTO>>, APIErr> {
+    let lineage = trace(&state, path.into_inner(), LineageDirection::Predecessors).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Predecessors".to_string(),
+        lineage,
+    )))
+}
@@ -1,6 +1,16 @@
+pub mod admin_handlers;
 pub mod barangay_handlers;
+pub mod change_handlers;
 pub mod city_handlers;
+pub mod crosswalk_handlers;
+pub mod dataset_handlers;
 pub mod district_handlers;
+pub mod health_handlers;
+pub mod island_group_handlers;
+pub mod lineage_handlers;
 pub mod municipality_handlers;
+pub mod option_handlers;
 pub mod province_handlers;
 pub mod region_handlers;
+pub mod release_handlers;
+pub mod stats_handlers;
@@ -1,21 +1,24 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO, municipality_dto::MunicipalityDTO},
-    usecases::municipality_usecases::{
-        GetMunicipalityByCodeUsecase, ListBarangaysByMunicipalityUsecase, ListMunicipalitiesUsecase,
+    dto::{
+        NameLookupDTO, PaginateResponseDTO, barangay_dto::BarangayDTO,
+        municipality_dto::MunicipalityDTO,
+    },
+    usecases::{
+        island_group_usecases::ListMunicipalitiesByIslandGroupUsecase,
+        municipality_usecases::{
+            FindMunicipalityByNameUsecase, GetMunicipalityByCodeUsecase,
+            GetMunicipalityBySlugUsecase, ListBarangaysByMunicipalityUsecase,
+            ListMunicipalitiesUsecase,
+        },
     },
-};
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
 };
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -23,11 +26,14 @@ use crate::{
     paths(
         list_municipalities,
         get_municipality_by_code,
+        get_municipality_by_slug,
+        find_municipality_by_name,
         get_barangays_by_municipality,
     ),
     components(schemas(
         MunicipalityDTO,
         PaginateResponseDTO<MunicipalityDTO>,
+        NameLookupDTO<MunicipalityDTO>,
         BarangayDTO,
         APIErr
     )),
@@ -38,29 +44,15 @@ use crate::{
 )]
 pub struct MunicipalityAPIDoc;
 
-pub fn build_municipality_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_municipality_route() -> actix_web::Scope {
     web::scope("/municipalities")
-        .service(web::resource("").route(web::get().to(list_municipalities::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_municipality_by_code::<
-                R,
-                P,
-                M,
-                D,
-                C,
-                B,
-            >)),
-        )
+        .service(web::resource("").route(web::get().to(list_municipalities)))
+        .service(web::resource("/by-name").route(web::get().to(find_municipality_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_municipality_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_municipality_by_code)))
         .service(
             web::resource("/{municipality_code}/barangays")
-                .route(web::get().to(get_barangays_by_municipality::<R, P, M, D, C, B>)),
+                .route(web::get().to(get_barangays_by_municipality)),
         )
 }
 
@@ -69,7 +61,8 @@ pub fn build_municipality_route<
     path = "/api/v1/municipalities",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only municipalities in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved municipalities", body = PaginateResponseDTO<MunicipalityDTO>),
@@ -79,23 +72,25 @@ pub fn build_municipality_route<
     tag = "municipalities",
     description = "Get all municipalities"
 )]
-async fn list_municipalities<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_municipalities(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<MunicipalityDTO>>>, APIErr> {
-    let municipality_repository = state.municipality_repository.clone();
-    let list_municipalities_usecase = ListMunicipalitiesUsecase::new(municipality_repository);
+    let municipality_repository = repositories.municipality.clone();
 
-    let municipalities = list_municipalities_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let municipalities = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListMunicipalitiesByIslandGroupUsecase::new(municipality_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListMunicipalitiesUsecase::new(municipality_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Municipalities".to_string(),
@@ -117,18 +112,11 @@ async fn list_municipalities<
     tag = "municipalities",
     description = "Get municipality by code"
 )]
-async fn get_municipality_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_municipality_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<MunicipalityDTO>>, APIErr> {
-    let municipality_repository = state.municipality_repository.clone();
+    let municipality_repository = repositories.municipality.clone();
     let get_municipality_by_code_usecase =
         GetMunicipalityByCodeUsecase::new(municipality_repository);
 
@@ -142,6 +130,71 @@ async fn get_municipality_by_code<
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/municipalities/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Municipality slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved municipality", body = MunicipalityDTO),
+        (status = 404, description = "Municipality not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "municipalities",
+    description = "Get municipality by slug"
+)]
+async fn get_municipality_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<MunicipalityDTO>>, APIErr> {
+    let municipality_repository = repositories.municipality.clone();
+    let get_municipality_by_slug_usecase =
+        GetMunicipalityBySlugUsecase::new(municipality_repository);
+
+    let municipality = get_municipality_by_slug_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Municipality details".to_string(),
+        municipality,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/municipalities/by-name",
+    params(
+        ("name" = String, Query, description = "Municipality name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching municipality, or every candidate when the name is shared", body = NameLookupDTO<MunicipalityDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "Municipality not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "municipalities",
+    description = "Find municipality by exact name"
+)]
+async fn find_municipality_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<MunicipalityDTO>>>, APIErr> {
+    let municipality_repository = repositories.municipality.clone();
+    let find_municipality_by_name_usecase =
+        FindMunicipalityByNameUsecase::new(municipality_repository);
+
+    let lookup = find_municipality_by_name_usecase
+        .execute(&param.name)
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Municipality by name".to_string(),
+        lookup,
+    )))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/municipalities/{municipality_code}/barangays",
@@ -156,18 +209,11 @@ async fn get_municipality_by_code<
     tag = "municipalities",
     description = "Get barangays by municipality"
 )]
-async fn get_barangays_by_municipality<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_barangays_by_municipality(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<BarangayDTO>>>, APIErr> {
-    let municipality_repository = state.municipality_repository.clone();
+    let municipality_repository = repositories.municipality.clone();
     let list_barangays_by_municipality_usecase =
         ListBarangaysByMunicipalityUsecase::new(municipality_repository);
 
@@ -0,0 +1,70 @@
+use actix_web::{
+    HttpRequest, HttpResponse,
+    web::{self, Query},
+};
+use psgc_application::{
+    dto::option_dto::OptionDTO, usecases::option_usecases::ListOptionsByParentUsecase,
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::OptionsQueryParam,
+    etag::respond_with_etag,
+    release::InRelease,
+    response::{APIErr, APIOk},
+};
+
+/// Options only change when the dataset is reseeded, so let browsers keep them for a day.
+const OPTIONS_MAX_AGE: u32 = 60 * 60 * 24;
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(list_options),
+    components(schemas(OptionDTO, APIErr)),
+    tags((
+        name = "options",
+        description = "Compact code/name pairs for cascading address dropdowns"
+    ))
+)]
+pub struct OptionAPIDoc;
+
+pub fn build_option_route() -> actix_web::Scope {
+    web::scope("/options").service(web::resource("").route(web::get().to(list_options)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/options",
+    params(
+        ("parent" = String, Query, description = "Region, province, city or municipality code")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved options", body = Vec<OptionDTO>),
+        (status = 304, description = "Options unchanged since the given ETag"),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "Parent not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "options",
+    description = "Get the direct children of a parent code, sorted by name"
+)]
+async fn list_options(
+    req: HttpRequest,
+    repositories: InRelease,
+    Query(param): Query<OptionsQueryParam>,
+) -> Result<HttpResponse, APIErr> {
+    let list_options_usecase = ListOptionsByParentUsecase::new(
+        repositories.region.clone(),
+        repositories.province.clone(),
+        repositories.city.clone(),
+        repositories.municipality.clone(),
+    );
+
+    let options = list_options_usecase.execute(&param.parent).await?;
+
+    respond_with_etag(
+        &req,
+        &APIOk::success_with_message("Options by parent".to_string(), options),
+        OPTIONS_MAX_AGE,
+    )
+}
@@ -1,25 +1,23 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
+        NameLookupDTO, PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
         province_dto::ProvinceDTO,
     },
-    usecases::province_usecases::{
-        GetProvinceByCodeUsecase, ListCitiesByProvinceUsecase, ListMunicipalitiesByProvinceUsecase,
-        ListProvincesUsecase,
+    usecases::{
+        island_group_usecases::ListProvincesByIslandGroupUsecase,
+        province_usecases::{
+            FindProvinceByNameUsecase, GetProvinceByCodeUsecase, GetProvinceBySlugUsecase,
+            ListCitiesByProvinceUsecase, ListMunicipalitiesByProvinceUsecase, ListProvincesUsecase,
+        },
     },
 };
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
-};
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -27,12 +25,15 @@ use crate::{
     paths(
         list_provinces,
         get_province_by_code,
+        get_province_by_slug,
+        find_province_by_name,
         get_cities_by_province,
         get_municipalities_by_province,
     ),
     components(schemas(
         ProvinceDTO,
         PaginateResponseDTO<ProvinceDTO>,
+        NameLookupDTO<ProvinceDTO>,
         CityDTO,
         MunicipalityDTO,
         APIErr
@@ -44,26 +45,18 @@ use crate::{
 )]
 pub struct ProvinceAPIDoc;
 
-pub fn build_province_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_province_route() -> actix_web::Scope {
     web::scope("/provinces")
-        .service(web::resource("").route(web::get().to(list_provinces::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_province_by_code::<R, P, M, D, C, B>)),
-        )
+        .service(web::resource("").route(web::get().to(list_provinces)))
+        .service(web::resource("/by-name").route(web::get().to(find_province_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_province_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_province_by_code)))
         .service(
-            web::resource("/{province_code}/cities")
-                .route(web::get().to(get_cities_by_province::<R, P, M, D, C, B>)),
+            web::resource("/{province_code}/cities").route(web::get().to(get_cities_by_province)),
         )
         .service(
             web::resource("/{province_code}/municipalities")
-                .route(web::get().to(get_municipalities_by_province::<R, P, M, D, C, B>)),
+                .route(web::get().to(get_municipalities_by_province)),
         )
 }
 
@@ -72,7 +65,8 @@ pub fn build_province_route<
     path = "/api/v1/provinces",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only provinces in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved provinces", body = PaginateResponseDTO<ProvinceDTO>),
@@ -82,23 +76,25 @@ pub fn build_province_route<
     tag = "provinces",
     description = "Get all provinces"
 )]
-async fn list_provinces<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_provinces(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<ProvinceDTO>>>, APIErr> {
-    let province_repository = state.province_repository.clone();
-    let list_provinces_usecase = ListProvincesUsecase::new(province_repository);
+    let province_repository = repositories.province.clone();
 
-    let provinces = list_provinces_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let provinces = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListProvincesByIslandGroupUsecase::new(province_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListProvincesUsecase::new(province_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Provinces".to_string(),
@@ -120,18 +116,11 @@ async fn list_provinces<
     tag = "provinces",
     description = "Get province by code"
 )]
-async fn get_province_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_province_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<ProvinceDTO>>, APIErr> {
-    let province_repository = state.province_repository.clone();
+    let province_repository = repositories.province.clone();
     let get_province_by_code_usecase = GetProvinceByCodeUsecase::new(province_repository);
 
     let province = get_province_by_code_usecase
@@ -144,6 +133,67 @@ async fn get_province_by_code<
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/provinces/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Province slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved province", body = ProvinceDTO),
+        (status = 404, description = "Province not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "provinces",
+    description = "Get province by slug"
+)]
+async fn get_province_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<ProvinceDTO>>, APIErr> {
+    let province_repository = repositories.province.clone();
+    let get_province_by_slug_usecase = GetProvinceBySlugUsecase::new(province_repository);
+
+    let province = get_province_by_slug_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province details".to_string(),
+        province,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/provinces/by-name",
+    params(
+        ("name" = String, Query, description = "Province name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching province, or every candidate when the name is shared", body = NameLookupDTO<ProvinceDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "Province not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "provinces",
+    description = "Find province by exact name"
+)]
+async fn find_province_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<ProvinceDTO>>>, APIErr> {
+    let province_repository = repositories.province.clone();
+    let find_province_by_name_usecase = FindProvinceByNameUsecase::new(province_repository);
+
+    let lookup = find_province_by_name_usecase.execute(&param.name).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province by name".to_string(),
+        lookup,
+    )))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/provinces/{province_code}/cities",
@@ -158,18 +208,11 @@ async fn get_province_by_code<
     tag = "provinces",
     description = "Get cities by province"
 )]
-async fn get_cities_by_province<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_cities_by_province(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<CityDTO>>>, APIErr> {
-    let province_repository = state.province_repository.clone();
+    let province_repository = repositories.province.clone();
     let list_cities_by_province_usecase = ListCitiesByProvinceUsecase::new(province_repository);
 
     let cities = list_cities_by_province_usecase
@@ -196,18 +239,11 @@ async fn get_cities_by_province<
     tag = "provinces",
     description = "Get municipalities by province"
 )]
-async fn get_municipalities_by_province<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_municipalities_by_province(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<MunicipalityDTO>>>, APIErr> {
-    let province_repository = state.province_repository.clone();
+    let province_repository = repositories.province.clone();
     let list_municipalities_by_province_usecase =
         ListMunicipalitiesByProvinceUsecase::new(province_repository);
 
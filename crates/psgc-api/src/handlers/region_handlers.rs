@@ -1,28 +1,24 @@
 use actix_web::web::{self, Json, Query};
 use psgc_application::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
+        NameLookupDTO, PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
         province_dto::ProvinceDTO, region_dto::RegionDTO,
     },
     usecases::{
+        island_group_usecases::ListRegionsByIslandGroupUsecase,
         province_usecases::ListProvincesByRegionUsecase,
         region_usecases::{
-            GetRegionByCodeUsecase, ListCitiesByRegionUsecase, ListMunicipalitiesByRegionUsecase,
-            ListRegionsUsecase,
+            FindRegionByNameUsecase, GetRegionByCodeUsecase, GetRegionBySlugUsecase,
+            ListCitiesByRegionUsecase, ListMunicipalitiesByRegionUsecase, ListRegionsUsecase,
         },
     },
 };
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
-};
 use utoipa::OpenApi;
 
 use crate::{
-    dto::PaginateQueryParam,
+    dto::{IslandGroupQueryParam, NameQueryParam, PaginateQueryParam},
+    release::InRelease,
     response::{APIErr, APIOk},
-    router::APIState,
 };
 
 #[derive(Debug, OpenApi)]
@@ -30,12 +26,15 @@ use crate::{
     paths(
         list_regions,
         get_region_by_code,
+        get_region_by_slug,
+        find_region_by_name,
         get_cities_by_region,
         get_municipalities_by_region,
     ),
     components(schemas(
         RegionDTO,
         PaginateResponseDTO<RegionDTO>,
+        NameLookupDTO<RegionDTO>,
         CityDTO,
         MunicipalityDTO,
         APIErr
@@ -47,36 +46,19 @@ use crate::{
 )]
 pub struct RegionAPIDoc;
 
-pub fn build_region_route<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->() -> actix_web::Scope {
+pub fn build_region_route() -> actix_web::Scope {
     web::scope("/regions")
-        .service(web::resource("").route(web::get().to(list_regions::<R, P, M, D, C, B>)))
-        .service(
-            web::resource("/{code}").route(web::get().to(get_region_by_code::<R, P, M, D, C, B>)),
-        )
+        .service(web::resource("").route(web::get().to(list_regions)))
+        .service(web::resource("/by-name").route(web::get().to(find_region_by_name)))
+        .service(web::resource("/by-slug/{slug}").route(web::get().to(get_region_by_slug)))
+        .service(web::resource("/{code}").route(web::get().to(get_region_by_code)))
+        .service(web::resource("/{region_code}/cities").route(web::get().to(get_cities_by_region)))
         .service(
-            web::resource("/{region_code}/cities").route(web::get().to(get_cities_by_region::<
-                R,
-                P,
-                M,
-                D,
-                C,
-                B,
-            >)),
-        )
-        .service(
-            web::resource("/{region_code}/provinces")
-                .route(web::get().to(get_provinces_by_region::<R, P, M, D, C, B>)),
+            web::resource("/{region_code}/provinces").route(web::get().to(get_provinces_by_region)),
         )
         .service(
             web::resource("/{region_code}/municipalities")
-                .route(web::get().to(get_municipalities_by_region::<R, P, M, D, C, B>)),
+                .route(web::get().to(get_municipalities_by_region)),
         )
 }
 
@@ -85,7 +67,8 @@ pub fn build_region_route<
     path = "/api/v1/regions",
     params(
         ("page" = Option<u32>, Query, description = "Page number"),
-        ("limit" = Option<u32>, Query, description = "Items per page")
+        ("limit" = Option<u32>, Query, description = "Items per page"),
+        ("island_group" = Option<String>, Query, description = "Only regions in this island group: luzon, visayas or mindanao")
     ),
     responses(
         (status = 200, description = "Successfully retrieved regions", body = PaginateResponseDTO<RegionDTO>),
@@ -95,23 +78,25 @@ pub fn build_region_route<
     tag = "regions",
     description = "Get all regions"
 )]
-async fn list_regions<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: actix_web::web::Data<APIState<R, P, M, D, C, B>>,
+async fn list_regions(
+    repositories: InRelease,
     Query(param): Query<PaginateQueryParam>,
+    Query(filter): Query<IslandGroupQueryParam>,
 ) -> Result<Json<APIOk<PaginateResponseDTO<RegionDTO>>>, APIErr> {
-    let region_repository = state.region_repository.clone();
-    let list_regions_usecase = ListRegionsUsecase::new(region_repository);
+    let region_repository = repositories.region.clone();
 
-    let regions = list_regions_usecase
-        .execute(param.page(), param.limit())
-        .await?;
+    let regions = match filter.island_group.as_deref() {
+        Some(island_group) => {
+            ListRegionsByIslandGroupUsecase::new(region_repository)
+                .execute(island_group, param.page(), param.limit())
+                .await?
+        }
+        None => {
+            ListRegionsUsecase::new(region_repository)
+                .execute(param.page(), param.limit())
+                .await?
+        }
+    };
 
     Ok(Json(APIOk::success_with_message(
         "All Regions".to_string(),
@@ -123,7 +108,7 @@ async fn list_regions<
     get,
     path = "/api/v1/regions/{code}",
     params(
-        ("code" = String, Path, description = "Region code")
+        ("code" = String, Path, description = "Region code, designation or alias such as `IV-A`, `Region 4A` or `BARMM`")
     ),
     responses(
         (status = 200, description = "Successfully retrieved region", body = RegionDTO),
@@ -133,18 +118,11 @@ async fn list_regions<
     tag = "regions",
     description = "Get region by code"
 )]
-async fn get_region_by_code<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_region_by_code(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<RegionDTO>>, APIErr> {
-    let region_repository = state.region_repository.clone();
+    let region_repository = repositories.region.clone();
     let get_region_by_code_usecase = GetRegionByCodeUsecase::new(region_repository);
 
     let region = get_region_by_code_usecase
@@ -157,6 +135,67 @@ async fn get_region_by_code<
     )))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/regions/by-slug/{slug}",
+    params(
+        ("slug" = String, Path, description = "Region slug")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved region", body = RegionDTO),
+        (status = 404, description = "Region not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "regions",
+    description = "Get region by slug"
+)]
+async fn get_region_by_slug(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<RegionDTO>>, APIErr> {
+    let region_repository = repositories.region.clone();
+    let get_region_by_slug_usecase = GetRegionBySlugUsecase::new(region_repository);
+
+    let region = get_region_by_slug_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region details".to_string(),
+        region,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/regions/by-name",
+    params(
+        ("name" = String, Query, description = "Region name, matched ignoring case and accents")
+    ),
+    responses(
+        (status = 200, description = "The matching region, or every candidate when the name is shared", body = NameLookupDTO<RegionDTO>),
+        (status = 400, description = "Bad request", body = APIErr),
+        (status = 404, description = "Region not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "regions",
+    description = "Find region by exact name"
+)]
+async fn find_region_by_name(
+    repositories: InRelease,
+    Query(param): Query<NameQueryParam>,
+) -> Result<Json<APIOk<NameLookupDTO<RegionDTO>>>, APIErr> {
+    let region_repository = repositories.region.clone();
+    let find_region_by_name_usecase = FindRegionByNameUsecase::new(region_repository);
+
+    let lookup = find_region_by_name_usecase.execute(&param.name).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region by name".to_string(),
+        lookup,
+    )))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/regions/{region_code}/provinces",
@@ -171,18 +210,11 @@ async fn get_region_by_code<
     tag = "regions",
     description = "Get provinces by region"
 )]
-async fn get_provinces_by_region<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_provinces_by_region(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<ProvinceDTO>>>, APIErr> {
-    let province_repository = state.province_repository.clone();
+    let province_repository = repositories.province.clone();
     let list_provinces_by_region_usecase = ListProvincesByRegionUsecase::new(province_repository);
 
     let provinces = list_provinces_by_region_usecase
@@ -209,18 +241,11 @@ async fn get_provinces_by_region<
     tag = "regions",
     description = "Get cities by region"
 )]
-async fn get_cities_by_region<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_cities_by_region(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<CityDTO>>>, APIErr> {
-    let region_repository = state.region_repository.clone();
+    let region_repository = repositories.region.clone();
     let list_cities_by_region_usecase = ListCitiesByRegionUsecase::new(region_repository);
 
     let cities = list_cities_by_region_usecase
@@ -247,18 +272,11 @@ async fn get_cities_by_region<
     tag = "regions",
     description = "Get municipalities by region"
 )]
-async fn get_municipalities_by_region<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: web::Data<APIState<R, P, M, D, C, B>>,
+async fn get_municipalities_by_region(
+    repositories: InRelease,
     path: web::Path<String>,
 ) -> Result<Json<APIOk<Vec<MunicipalityDTO>>>, APIErr> {
-    let region_repository = state.region_repository.clone();
+    let region_repository = repositories.region.clone();
     let list_municipalities_by_region_usecase =
         ListMunicipalitiesByRegionUsecase::new(region_repository);
 
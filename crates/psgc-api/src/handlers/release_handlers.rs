@@ -0,0 +1,147 @@
+use actix_web::{
+    HttpResponse,
+    web::{self, Json, Query},
+};
+use psgc_application::{
+    dto::release_dto::{
+        ReleaseDTO, ReleaseDiffDTO, ReleaseDiffSummaryDTO, ReleaseListDTO, ReleaseUnitDTO,
+        UnitChangeDTO,
+    },
+    errors::UsecaseError,
+    usecases::release_usecases::{DiffReleasesUsecase, ListReleasesUsecase, ResolveReleaseUsecase},
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::FormatQueryParam,
+    response::{APIErr, APIOk},
+    router::APIState,
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(list_releases, get_release, diff_releases),
+    components(schemas(
+        ReleaseDTO,
+        ReleaseListDTO,
+        ReleaseDiffDTO,
+        ReleaseDiffSummaryDTO,
+        UnitChangeDTO,
+        ReleaseUnitDTO,
+        APIErr
+    )),
+    tags((
+        name = "releases",
+        description = "PSGC releases loaded side by side. Every endpoint serves the latest release unless given `?release=2024Q3` or called as `/api/v1/releases/2024Q3/...`"
+    ))
+)]
+pub struct ReleaseAPIDoc;
+
+/// Other routes are served under `/releases/{release}` by [`crate::release::route_release`].
+pub fn build_release_route() -> actix_web::Scope {
+    web::scope("/releases")
+        .service(web::resource("").route(web::get().to(list_releases)))
+        .service(web::resource("/{release}").route(web::get().to(get_release)))
+        .service(web::resource("/{from}/diff/{to}").route(web::get().to(diff_releases)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/releases",
+    responses(
+        (status = 200, description = "Successfully retrieved releases", body = ReleaseListDTO),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "releases",
+    description = "Get every loaded release, newest first, and the one served by default"
+)]
+async fn list_releases(state: web::Data<APIState>) -> Result<Json<APIOk<ReleaseListDTO>>, APIErr> {
+    let release_repository = state.repositories.releases.clone();
+    let list_releases_usecase = ListReleasesUsecase::new(release_repository);
+
+    let releases = list_releases_usecase.execute().await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "All Releases".to_string(),
+        releases,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/releases/{release}",
+    params(
+        ("release" = String, Path, description = "Release id, the year and quarter such as `2024Q3`")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved release", body = ReleaseDTO),
+        (status = 400, description = "Not a release id", body = APIErr),
+        (status = 404, description = "Release not loaded", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "releases",
+    description = "Get a release"
+)]
+async fn get_release(
+    state: web::Data<APIState>,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<ReleaseDTO>>, APIErr> {
+    let release_repository = state.repositories.releases.clone();
+    let resolve_release_usecase = ResolveReleaseUsecase::new(release_repository);
+
+    let release = resolve_release_usecase
+        .execute(Some(&path.into_inner()))
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Release details".to_string(),
+        release,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/releases/{from}/diff/{to}",
+    params(
+        ("from" = String, Path, description = "The older release, e.g. `2024Q2`"),
+        ("to" = String, Path, description = "The newer release, e.g. `2024Q3`"),
+        ("format" = Option<String>, Query, description = "`json` (default) or `csv`")
+    ),
+    responses(
+        (status = 200, description = "Every unit added, abolished, renamed, recoded or reclassified between the releases", body = ReleaseDiffDTO),
+        (status = 400, description = "Not a release id or an unknown format", body = APIErr),
+        (status = 404, description = "Release not loaded", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "releases",
+    description = "Compare two releases. Renames and code changes are inferred by matching units on code, then on name under the same parent"
+)]
+async fn diff_releases(
+    state: web::Data<APIState>,
+    path: web::Path<(String, String)>,
+    Query(param): Query<FormatQueryParam>,
+) -> Result<HttpResponse, APIErr> {
+    let (from, to) = path.into_inner();
+    let release_repository = state.repositories.releases.clone();
+    let diff_releases_usecase = DiffReleasesUsecase::new(release_repository);
+
+    if !matches!(param.format(), "json" | "csv") {
+        return Err(UsecaseError::ValidationError(format!(
+            "unknown format {}; use json or csv",
+            param.format()
+        ))
+        .into());
+    }
+
+    let diff = diff_releases_usecase.execute(&from, &to).await?;
+
+    match param.format() {
+        "csv" => Ok(HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .body(diff.to_csv())),
+        _ => Ok(HttpResponse::Ok().json(APIOk::success_with_message(
+            format!("Changes from {} to {}", diff.from, diff.to),
+            diff,
+        ))),
+    }
+}
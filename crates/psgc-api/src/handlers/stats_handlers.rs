@@ -0,0 +1,213 @@
+use actix_web::web::{self, Json, Query};
+use psgc_application::{
+    dto::stats_dto::{GroupCountDTO, ProvinceStatsDTO, RegionStatsDTO},
+    usecases::stats_usecases::{
+        CountBarangaysByUrbanRuralUsecase, CountByIncomeClassUsecase, GetProvinceStatsUsecase,
+        GetRegionStatsUsecase, ListProvinceStatsUsecase, ListRegionStatsUsecase,
+    },
+};
+use utoipa::OpenApi;
+
+use crate::{
+    dto::LevelQueryParam,
+    release::InRelease,
+    response::{APIErr, APIOk},
+};
+
+#[derive(Debug, OpenApi)]
+#[openapi(
+    paths(
+        list_region_stats,
+        get_region_stats,
+        list_province_stats,
+        get_province_stats,
+        count_by_income_class,
+        count_barangays_by_urban_rural,
+    ),
+    components(schemas(
+        RegionStatsDTO,
+        ProvinceStatsDTO,
+        GroupCountDTO,
+        APIErr
+    )),
+    tags((
+        name = "stats",
+        description = "Counts and totals aggregated by PSGC level"
+    ))
+)]
+pub struct StatsAPIDoc;
+
+pub fn build_stats_route() -> actix_web::Scope {
+    web::scope("/stats")
+        .service(web::resource("/regions").route(web::get().to(list_region_stats)))
+        .service(web::resource("/regions/{code}").route(web::get().to(get_region_stats)))
+        .service(web::resource("/provinces").route(web::get().to(list_province_stats)))
+        .service(web::resource("/provinces/{code}").route(web::get().to(get_province_stats)))
+        .service(web::resource("/income-classes").route(web::get().to(count_by_income_class)))
+        .service(web::resource("/urban-rural").route(web::get().to(count_barangays_by_urban_rural)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/regions",
+    responses(
+        (status = 200, description = "Successfully retrieved region stats", body = Vec<RegionStatsDTO>),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Get population and unit counts for every region"
+)]
+async fn list_region_stats(
+    repositories: InRelease,
+) -> Result<Json<APIOk<Vec<RegionStatsDTO>>>, APIErr> {
+    let region_repository = repositories.region.clone();
+    let list_region_stats_usecase = ListRegionStatsUsecase::new(region_repository);
+
+    let stats = list_region_stats_usecase.execute().await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region stats".to_string(),
+        stats,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/regions/{code}",
+    params(
+        ("code" = String, Path, description = "Region code")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved region stats", body = RegionStatsDTO),
+        (status = 404, description = "Region not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Get population and unit counts for a region"
+)]
+async fn get_region_stats(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<RegionStatsDTO>>, APIErr> {
+    let region_repository = repositories.region.clone();
+    let get_region_stats_usecase = GetRegionStatsUsecase::new(region_repository);
+
+    let stats = get_region_stats_usecase.execute(&path.into_inner()).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Region stats".to_string(),
+        stats,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/provinces",
+    responses(
+        (status = 200, description = "Successfully retrieved province stats", body = Vec<ProvinceStatsDTO>),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Get unit counts, including barangays, for every province"
+)]
+async fn list_province_stats(
+    repositories: InRelease,
+) -> Result<Json<APIOk<Vec<ProvinceStatsDTO>>>, APIErr> {
+    let province_repository = repositories.province.clone();
+    let list_province_stats_usecase = ListProvinceStatsUsecase::new(province_repository);
+
+    let stats = list_province_stats_usecase.execute().await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province stats".to_string(),
+        stats,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/provinces/{code}",
+    params(
+        ("code" = String, Path, description = "Province code")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved province stats", body = ProvinceStatsDTO),
+        (status = 404, description = "Province not found", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Get unit counts for a province"
+)]
+async fn get_province_stats(
+    repositories: InRelease,
+    path: web::Path<String>,
+) -> Result<Json<APIOk<ProvinceStatsDTO>>, APIErr> {
+    let province_repository = repositories.province.clone();
+    let get_province_stats_usecase = GetProvinceStatsUsecase::new(province_repository);
+
+    let stats = get_province_stats_usecase
+        .execute(&path.into_inner())
+        .await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Province stats".to_string(),
+        stats,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/income-classes",
+    params(
+        ("level" = Option<String>, Query, description = "province, city or municipality (default)")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved income class counts", body = Vec<GroupCountDTO>),
+        (status = 400, description = "Unsupported level", body = APIErr),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Count units by income class"
+)]
+async fn count_by_income_class(
+    repositories: InRelease,
+    Query(param): Query<LevelQueryParam>,
+) -> Result<Json<APIOk<Vec<GroupCountDTO>>>, APIErr> {
+    let count_by_income_class_usecase = CountByIncomeClassUsecase::new(
+        repositories.province.clone(),
+        repositories.city.clone(),
+        repositories.municipality.clone(),
+    );
+
+    let counts = count_by_income_class_usecase.execute(param.level()).await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Income class counts".to_string(),
+        counts,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/stats/urban-rural",
+    responses(
+        (status = 200, description = "Successfully retrieved urban/rural counts", body = Vec<GroupCountDTO>),
+        (status = 500, description = "Internal server error", body = APIErr)
+    ),
+    tag = "stats",
+    description = "Count barangays by urban/rural classification"
+)]
+async fn count_barangays_by_urban_rural(
+    repositories: InRelease,
+) -> Result<Json<APIOk<Vec<GroupCountDTO>>>, APIErr> {
+    let barangay_repository = repositories.barangay.clone();
+    let count_barangays_by_urban_rural_usecase =
+        CountBarangaysByUrbanRuralUsecase::new(barangay_repository);
+
+    let counts = count_barangays_by_urban_rural_usecase.execute().await?;
+
+    Ok(Json(APIOk::success_with_message(
+        "Urban/rural barangay counts".to_string(),
+        counts,
+    )))
+}
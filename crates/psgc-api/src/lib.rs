@@ -1,5 +1,12 @@
+pub mod api_key;
+pub mod dataset;
 pub(crate) mod dto;
 pub(crate) mod errors;
+pub(crate) mod etag;
 pub(crate) mod handlers;
+pub mod metrics;
+pub mod rate_limit;
+pub mod release;
+pub mod request_id;
 pub(crate) mod response;
 pub mod router;
@@ -0,0 +1,217 @@
+use std::time::Instant;
+
+use actix_web::{
+    Error, HttpResponse,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::StatusCode,
+    middleware::Next,
+    web::Data,
+};
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+use psgc_infrastructure::{cache::CacheStats, database::pool::PoolStats};
+
+use crate::router::APIState;
+
+/// Label used for requests that never matched a route, e.g. 404s and rate-limited calls.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Prometheus collectors for the API. Cheap to clone; every clone shares the same registry.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    latency: HistogramVec,
+    rate_limited: IntCounter,
+    pool: IntGaugeVec,
+    cache_hits: IntGauge,
+    cache_misses: IntGauge,
+    cache_hit_ratio: Gauge,
+}
+
+impl Metrics {
+    pub fn new() -> prometheus::Result<Self> {
+        let registry = Registry::new_custom(Some("psgc".to_string()), None)?;
+
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route and status"),
+            &["method", "route", "status"],
+        )?;
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency by route and status",
+            ),
+            &["method", "route", "status"],
+        )?;
+        let rate_limited = IntCounter::new(
+            "rate_limited_requests_total",
+            "Requests rejected by the rate limiter",
+        )?;
+        let pool = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database pool connections by state"),
+            &["state"],
+        )?;
+        let cache_hits = IntGauge::new("cache_hits", "Cache lookups served from Redis")?;
+        let cache_misses = IntGauge::new("cache_misses", "Cache lookups that fell through")?;
+        let cache_hit_ratio = Gauge::new("cache_hit_ratio", "Cache hits over all lookups")?;
+
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(latency.clone()))?;
+        registry.register(Box::new(rate_limited.clone()))?;
+        registry.register(Box::new(pool.clone()))?;
+        registry.register(Box::new(cache_hits.clone()))?;
+        registry.register(Box::new(cache_misses.clone()))?;
+        registry.register(Box::new(cache_hit_ratio.clone()))?;
+
+        Ok(Self {
+            registry,
+            requests,
+            latency,
+            rate_limited,
+            pool,
+            cache_hits,
+            cache_misses,
+            cache_hit_ratio,
+        })
+    }
+
+    fn observe(&self, method: &str, route: &str, status: StatusCode, elapsed_secs: f64) {
+        let labels = [method, route, status.as_str()];
+
+        self.requests.with_label_values(&labels).inc();
+        self.latency
+            .with_label_values(&labels)
+            .observe(elapsed_secs);
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limited.inc();
+        }
+    }
+
+    /// Encodes every collector in the Prometheus text format, refreshing the pool and
+    /// cache gauges first. Backends without a pool or cache leave those series out.
+    pub fn render(
+        &self,
+        pool: Option<PoolStats>,
+        cache: Option<CacheStats>,
+    ) -> prometheus::Result<String> {
+        if let Some(pool) = pool {
+            for (state, value) in [
+                ("max_open", pool.max_open),
+                ("open", pool.connections),
+                ("in_use", pool.in_use),
+                ("idle", pool.idle),
+                ("waiting", pool.waits),
+            ] {
+                self.pool
+                    .with_label_values(&[state])
+                    .set(value.try_into().unwrap_or(i64::MAX));
+            }
+        }
+
+        if let Some(cache) = cache {
+            self.cache_hits
+                .set(cache.hits.try_into().unwrap_or(i64::MAX));
+            self.cache_misses
+                .set(cache.misses.try_into().unwrap_or(i64::MAX));
+            self.cache_hit_ratio.set(cache.hit_ratio());
+        }
+
+        let mut families = self.registry.gather();
+        if pool.is_none() {
+            families.retain(|family| !family.name().ends_with("db_pool_connections"));
+        }
+        if cache.is_none() {
+            families.retain(|family| !family.name().starts_with("psgc_cache_"));
+        }
+
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&families, &mut buffer)?;
+
+        String::from_utf8(buffer).map_err(|e| prometheus::Error::Msg(e.to_string()))
+    }
+}
+
+/// Records the count and latency of every request. Wrapped outermost so that requests
+/// rejected by the rate limiter are seen too.
+pub async fn track_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let metrics = req
+        .app_data::<Data<APIState>>()
+        .map(|state| state.metrics.clone());
+    let method = req.method().to_string();
+    let started = Instant::now();
+
+    let res = match next.call(req).await {
+        Ok(res) => res,
+        Err(e) => {
+            if let Some(metrics) = metrics {
+                metrics.observe(
+                    &method,
+                    UNMATCHED_ROUTE,
+                    e.as_response_error().status_code(),
+                    started.elapsed().as_secs_f64(),
+                );
+            }
+
+            return Err(e);
+        }
+    };
+
+    if let Some(metrics) = metrics {
+        let route = res.request().match_pattern();
+
+        metrics.observe(
+            &method,
+            route.as_deref().unwrap_or(UNMATCHED_ROUTE),
+            res.status(),
+            started.elapsed().as_secs_f64(),
+        );
+    }
+
+    Ok(res)
+}
+
+pub async fn metrics(state: Data<APIState>) -> HttpResponse {
+    let pool = state.repositories.pool_stats().await;
+    let cache = state.repositories.cache_stats();
+
+    match state.metrics.render(pool, cache) {
+        Ok(body) => HttpResponse::Ok()
+            .content_type(TextEncoder::new().format_type())
+            .body(body),
+        Err(e) => HttpResponse::InternalServerError().body(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_requests_and_skips_missing_backends() {
+        let metrics = Metrics::new().unwrap();
+        metrics.observe("GET", "/api/v1/regions/{code}", StatusCode::OK, 0.01);
+        metrics.observe("GET", UNMATCHED_ROUTE, StatusCode::TOO_MANY_REQUESTS, 0.0);
+
+        let body = metrics.render(None, None).unwrap();
+
+        assert!(body.contains(
+            r#"psgc_http_requests_total{method="GET",route="/api/v1/regions/{code}",status="200"} 1"#
+        ));
+        assert!(body.contains("psgc_rate_limited_requests_total 1"));
+        assert!(!body.contains("psgc_db_pool_connections"));
+        assert!(!body.contains("psgc_cache_hit_ratio"));
+
+        let body = metrics
+            .render(None, Some(CacheStats { hits: 3, misses: 1 }))
+            .unwrap();
+        assert!(body.contains("psgc_cache_hit_ratio 0.75"));
+    }
+}
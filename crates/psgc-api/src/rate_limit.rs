@@ -0,0 +1,303 @@
+use std::{
+    net::IpAddr,
+    num::NonZeroU32,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use actix_web::{
+    Error, ResponseError,
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{self, HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+    web::Data,
+};
+use governor::{
+    DefaultKeyedRateLimiter, Quota,
+    clock::{Clock, DefaultClock},
+};
+use psgc_infrastructure::config::rate_limit_config::{
+    IpCidr, RateLimit, RateLimitConfig, RouteRateLimit,
+};
+
+use crate::{response::APIErr, router::APIState};
+
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// Forget clients that have been idle long enough to be back at a full burst every so often,
+/// so the limiter doesn't grow with every address ever seen.
+const RETAIN_RECENT_EVERY: u64 = 4096;
+
+/// Per-client-IP limits shared by every worker, with one limiter per route override.
+#[derive(Clone)]
+pub struct RateLimiter(Arc<Limiters>);
+
+struct Limiters {
+    default: Option<DefaultKeyedRateLimiter<IpAddr>>,
+    routes: Vec<(RouteRateLimit, DefaultKeyedRateLimiter<IpAddr>)>,
+    trusted_proxies: Vec<IpCidr>,
+    checks: AtomicU64,
+}
+
+fn keyed_limiter(limit: RateLimit) -> DefaultKeyedRateLimiter<IpAddr> {
+    let per_minute = NonZeroU32::new(limit.per_minute).unwrap_or(NonZeroU32::MIN);
+    let burst = NonZeroU32::new(limit.burst).unwrap_or(NonZeroU32::MIN);
+
+    governor::RateLimiter::keyed(Quota::per_minute(per_minute).allow_burst(burst))
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let routes = config
+            .routes
+            .iter()
+            .map(|route| (route.clone(), keyed_limiter(route.limit)))
+            .collect();
+
+        Self(Arc::new(Limiters {
+            default: config.default.map(keyed_limiter),
+            routes,
+            trusted_proxies: config.trusted_proxies.clone(),
+            checks: AtomicU64::new(0),
+        }))
+    }
+
+    /// The first matching override wins; everything else falls back to the default limit.
+    fn limiter_for(&self, route: Option<&str>) -> Option<&DefaultKeyedRateLimiter<IpAddr>> {
+        let route_limiter = route.and_then(|route| {
+            self.0
+                .routes
+                .iter()
+                .find(|(override_, _)| override_.matches(route))
+                .map(|(_, limiter)| limiter)
+        });
+
+        route_limiter.or(self.0.default.as_ref())
+    }
+
+    /// Counts a request from `client`; `Err` carries how long it has to wait.
+    fn check(&self, route: Option<&str>, client: IpAddr) -> Result<(), Duration> {
+        let Some(limiter) = self.limiter_for(route) else {
+            return Ok(());
+        };
+
+        if self
+            .0
+            .checks
+            .fetch_add(1, Ordering::Relaxed)
+            .is_multiple_of(RETAIN_RECENT_EVERY)
+        {
+            limiter.retain_recent();
+        }
+
+        limiter
+            .check_key(&client)
+            .map_err(|not_until| not_until.wait_time_from(DefaultClock::default().now()))
+    }
+}
+
+/// The address of the client, looking past trusted proxies.
+///
+/// Proxies append the address they received a request from, so the hops are walked from
+/// the peer backwards; the first address that isn't a trusted proxy is the client. Headers
+/// from untrusted peers are ignored, since anyone can send them.
+fn client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpCidr]) -> IpAddr {
+    let is_trusted = |ip: &IpAddr| trusted_proxies.iter().any(|proxy| proxy.contains(ip));
+
+    if !is_trusted(&peer) {
+        return peer;
+    }
+
+    let mut hops = forwarded_for(headers);
+    let mut client = peer;
+
+    while is_trusted(&client) {
+        match hops.pop() {
+            Some(Some(hop)) => client = hop,
+            // An obfuscated or malformed hop; the proxy that reported it is as far as we can trust
+            Some(None) | None => break,
+        }
+    }
+
+    client
+}
+
+/// Hops from `Forwarded` (preferred) or `X-Forwarded-For`, in the order they were added.
+fn forwarded_for(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let values = |name| {
+        headers
+            .get_all(name)
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .filter(|hop| !hop.is_empty())
+            .collect::<Vec<_>>()
+    };
+
+    let forwarded = values(header::FORWARDED);
+    if !forwarded.is_empty() {
+        return forwarded
+            .into_iter()
+            .map(|element| {
+                element
+                    .split(';')
+                    .filter_map(|pair| pair.trim().split_once('='))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("for"))
+                    .and_then(|(_, node)| parse_node(node))
+            })
+            .collect();
+    }
+
+    values(X_FORWARDED_FOR)
+        .into_iter()
+        .map(parse_node)
+        .collect()
+}
+
+/// Parses `1.2.3.4`, `1.2.3.4:80`, `2001:db8::1` or `"[2001:db8::1]:80"`.
+fn parse_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+
+    if let Some(bracketed) = node.strip_prefix('[') {
+        return bracketed.split(']').next()?.parse().ok();
+    }
+
+    node.parse().ok().or_else(|| {
+        let (ip, _port) = node.rsplit_once(':')?;
+        ip.parse().ok()
+    })
+}
+
+/// Rejects clients over their limit with a 429 and a `Retry-After`. The route's own limit
+/// applies when it has an override, otherwise the default one.
+pub async fn limit_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let limiter = req
+        .app_data::<Data<APIState>>()
+        .and_then(|state| state.rate_limiter.clone());
+    let peer = req.peer_addr().map(|addr| addr.ip());
+
+    let (Some(limiter), Some(peer)) = (limiter, peer) else {
+        return Ok(next.call(req).await?.map_into_left_body());
+    };
+
+    // Runs before routing and `NormalizePath`, so the pattern is looked up on the trimmed path.
+    let route = req
+        .resource_map()
+        .match_pattern(req.path().trim_end_matches('/'));
+    let client = client_ip(peer, req.headers(), &limiter.0.trusted_proxies);
+
+    if let Err(wait) = limiter.check(route.as_deref(), client) {
+        let retry_after = (wait.as_millis().div_ceil(1000) as u64).max(1);
+        let err =
+            APIErr::too_many_requests(format!("Rate limit exceeded, retry in {retry_after}s"));
+
+        let mut res = req.into_response(err.error_response());
+        res.headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+
+        return Ok(res.map_into_right_body());
+    }
+
+    Ok(next.call(req).await?.map_into_left_body())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(ip: &str) -> IpAddr {
+        ip.parse().unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(
+                HeaderName::from_static(name),
+                HeaderValue::from_static(value),
+            );
+        }
+        headers
+    }
+
+    #[test]
+    fn takes_the_client_ip_from_trusted_proxies_only() {
+        let trusted = ["10.0.0.0/8".parse().unwrap()];
+        let forwarded = headers(&[("x-forwarded-for", "203.0.113.7, 10.0.0.2")]);
+
+        assert_eq!(
+            client_ip(ip("10.0.0.1"), &forwarded, &trusted),
+            ip("203.0.113.7")
+        );
+        assert_eq!(
+            client_ip(ip("198.51.100.1"), &forwarded, &trusted),
+            ip("198.51.100.1")
+        );
+
+        // A spoofed leftmost entry doesn't matter; the last untrusted hop is the client.
+        let spoofed = headers(&[("x-forwarded-for", "1.1.1.1, 203.0.113.7")]);
+        assert_eq!(
+            client_ip(ip("10.0.0.1"), &spoofed, &trusted),
+            ip("203.0.113.7")
+        );
+
+        let rfc7239 = headers(&[(
+            "forwarded",
+            r#"for="[2001:db8::7]:4711";proto=https, for=10.0.0.2"#,
+        )]);
+        assert_eq!(
+            client_ip(ip("10.0.0.1"), &rfc7239, &trusted),
+            ip("2001:db8::7")
+        );
+
+        let hidden = headers(&[("forwarded", "for=_hidden, for=10.0.0.2")]);
+        assert_eq!(client_ip(ip("10.0.0.1"), &hidden, &trusted), ip("10.0.0.2"));
+    }
+
+    #[test]
+    fn applies_route_overrides_before_the_default_limit() {
+        let config = RateLimitConfig::builder()
+            .default(RateLimit {
+                per_minute: 60,
+                burst: 3,
+            })
+            .routes(vec![RouteRateLimit {
+                pattern: "/api/v1/*/by-name".to_string(),
+                limit: RateLimit {
+                    per_minute: 60,
+                    burst: 1,
+                },
+            }])
+            .build();
+        let limiter = RateLimiter::new(&config);
+        let client = ip("203.0.113.7");
+
+        assert!(
+            limiter
+                .check(Some("/api/v1/regions/by-name"), client)
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .check(Some("/api/v1/regions/by-name"), client)
+                .is_err()
+        );
+
+        for _ in 0..3 {
+            assert!(
+                limiter
+                    .check(Some("/api/v1/regions/{code}"), client)
+                    .is_ok()
+            );
+        }
+        assert!(limiter.check(None, client).is_err());
+        assert!(limiter.check(None, ip("203.0.113.8")).is_ok());
+    }
+}
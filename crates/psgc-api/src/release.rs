@@ -0,0 +1,187 @@
+use std::{future::Future, ops::Deref, pin::Pin, str::FromStr};
+
+use actix_web::{
+    Error, FromRequest, HttpMessage, HttpRequest,
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    http::uri::{PathAndQuery, Uri},
+    middleware::Next,
+    web::{Data, Query},
+};
+use chrono::NaiveDate;
+use psgc_application::{errors::UsecaseError, usecases::release_usecases::ResolveReleaseUsecase};
+use psgc_infrastructure::repositories::backend::UnitRepositories;
+use serde::Deserialize;
+
+use crate::{response::APIErr, router::APIState};
+
+const RELEASES_PREFIX: &str = "/api/v1/releases/";
+
+/// The release named in a `/api/v1/releases/{release}/...` path.
+#[derive(Debug, Clone)]
+struct PathRelease(String);
+
+#[derive(Debug, Default, Deserialize)]
+struct ReleaseQueryParam {
+    release: Option<String>,
+    as_of: Option<String>,
+}
+
+/// Serves `/api/v1/releases/{release}/<route>` with the handler of `/api/v1/<route>`.
+///
+/// The path is rewritten before routing and the release kept for [`InRelease`], so every
+/// route is available under a release without registering it twice. Paths registered
+/// under `/releases` themselves, such as the diff, are left alone.
+pub async fn route_release(
+    mut req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let registered = req
+        .resource_map()
+        .match_pattern(req.path().trim_end_matches('/'))
+        .is_some();
+    let rewrite = req
+        .path()
+        .strip_prefix(RELEASES_PREFIX)
+        .filter(|_| !registered)
+        .and_then(|rest| rest.split_once('/'))
+        .filter(|(_, route)| !route.is_empty())
+        .map(|(release, route)| {
+            let path = match req.query_string() {
+                "" => format!("/api/v1/{route}"),
+                query => format!("/api/v1/{route}?{query}"),
+            };
+            (release.to_string(), path)
+        });
+
+    if let Some((release, path)) = rewrite {
+        let mut parts = req.head().uri.clone().into_parts();
+        parts.path_and_query = PathAndQuery::from_str(&path).ok();
+
+        if let Ok(uri) = Uri::from_parts(parts) {
+            req.match_info_mut().get_mut().update(&uri);
+            req.head_mut().uri = uri;
+            req.extensions_mut().insert(PathRelease(release));
+        }
+    }
+
+    next.call(req).await
+}
+
+/// The unit repositories of the release a request asked for, from the path or the
+/// `release` query parameter, or of the latest release when it didn't ask for one. They
+/// read the units as they stood on the `as_of` query parameter, or today.
+pub struct InRelease {
+    pub release: String,
+    /// The day asked for with `as_of`, if any
+    pub as_of: Option<NaiveDate>,
+    repositories: UnitRepositories,
+}
+
+impl Deref for InRelease {
+    type Target = UnitRepositories;
+
+    fn deref(&self) -> &Self::Target {
+        &self.repositories
+    }
+}
+
+impl FromRequest for InRelease {
+    type Error = APIErr;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let state = req.app_data::<Data<APIState>>().cloned();
+        let param = Query::<ReleaseQueryParam>::from_query(req.query_string())
+            .map(Query::into_inner)
+            .unwrap_or_default();
+        let requested = req
+            .extensions()
+            .get::<PathRelease>()
+            .map(|release| release.0.clone())
+            .or(param.release);
+        let as_of = param.as_of.map(|date| parse_as_of(&date)).transpose();
+
+        Box::pin(async move {
+            let state = state.expect("APIState is registered on the app");
+            let as_of = as_of?;
+            let release = ResolveReleaseUsecase::new(state.repositories.releases.clone())
+                .execute(requested.as_deref())
+                .await?;
+
+            Ok(Self {
+                repositories: state.repositories.for_release(&release.id, as_of),
+                release: release.id,
+                as_of,
+            })
+        })
+    }
+}
+
+fn parse_as_of(date: &str) -> Result<NaiveDate, APIErr> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+        UsecaseError::ValidationError(format!("as_of {date} is not a date; use YYYY-MM-DD")).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        App, HttpResponse,
+        middleware::from_fn,
+        test::{TestRequest, call_and_read_body, init_service},
+        web,
+    };
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn serves_routes_under_a_release() {
+        let app = init_service(
+            App::new()
+                .wrap(from_fn(route_release))
+                .route(
+                    "/api/v1/regions/{code}",
+                    web::get().to(|req: HttpRequest, code: web::Path<String>| async move {
+                        let release = req.extensions().get::<PathRelease>().map(|r| r.0.clone());
+                        HttpResponse::Ok().body(format!(
+                            "{} {} {}",
+                            release.unwrap_or_default(),
+                            code.into_inner(),
+                            req.query_string()
+                        ))
+                    }),
+                )
+                .route(
+                    "/api/v1/releases/{from}/diff/{to}",
+                    web::get().to(|| async { HttpResponse::Ok().body("diff") }),
+                ),
+        )
+        .await;
+
+        let body = call_and_read_body(
+            &app,
+            TestRequest::get()
+                .uri("/api/v1/releases/2023Q4/regions/13?x=1")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(body, "2023Q4 13 x=1");
+
+        let body = call_and_read_body(
+            &app,
+            TestRequest::get().uri("/api/v1/regions/13").to_request(),
+        )
+        .await;
+        assert_eq!(body, " 13 ");
+
+        let body = call_and_read_body(
+            &app,
+            TestRequest::get()
+                .uri("/api/v1/releases/2023Q4/diff/2024Q3")
+                .to_request(),
+        )
+        .await;
+        assert_eq!(body, "diff");
+    }
+}
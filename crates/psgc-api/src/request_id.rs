@@ -0,0 +1,191 @@
+use std::time::Instant;
+
+use actix_web::{
+    Error, HttpMessage,
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    http::header::{HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+};
+use opentelemetry::propagation::Extractor;
+use tracing::{Instrument, field::Empty, info, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest caller-supplied id we pass through; anything else gets a fresh one.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: RequestId;
+}
+
+/// Correlation id of the request being handled, taken from `X-Request-Id` or generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|id| is_valid_request_id(id))
+            .map(|id| Self(id.to_string()))
+            .unwrap_or_else(|| Self(Uuid::now_v7().to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'))
+}
+
+/// The id of the request currently being handled, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.0.clone()).ok()
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(HeaderName::as_str).collect()
+    }
+}
+
+/// Opens the root span for a request and tags it with its request id.
+///
+/// The span continues the caller's trace when a `traceparent` header is present, every log
+/// line emitted while handling the request carries the id, and the id is echoed back in the
+/// `X-Request-Id` response header.
+pub async fn request_context(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let request_id = RequestId::from_headers(req.headers());
+    let method = req.method().to_string();
+    // `NormalizePath` runs further in, so look the pattern up on the trimmed path ourselves;
+    // the exported span name can't be changed once the span has started.
+    let route = req
+        .resource_map()
+        .match_pattern(req.path().trim_end_matches('/'));
+    let name = match &route {
+        Some(route) => format!("{method} {route}"),
+        None => method.clone(),
+    };
+    let target = req.uri().to_string();
+
+    let span = info_span!(
+        "HTTP request",
+        otel.name = %name,
+        otel.kind = "server",
+        otel.status_code = Empty,
+        http.method = %method,
+        http.route = route.as_deref().unwrap_or("unmatched"),
+        http.target = %target,
+        http.status_code = Empty,
+        request_id = %request_id.as_str(),
+    );
+    let parent = opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(req.headers()))
+    });
+    let _ = span.set_parent(parent);
+
+    req.extensions_mut().insert(request_id.clone());
+    let started = Instant::now();
+
+    let result = REQUEST_ID
+        .scope(request_id.clone(), next.call(req))
+        .instrument(span.clone())
+        .await;
+
+    let status = match &result {
+        Ok(res) => res.status(),
+        Err(e) => e.as_response_error().status_code(),
+    };
+    span.record("http.status_code", status.as_u16());
+    span.record(
+        "otel.status_code",
+        if status.is_server_error() {
+            "ERROR"
+        } else {
+            "OK"
+        },
+    );
+    span.in_scope(|| {
+        info!(
+            status = status.as_u16(),
+            latency_ms = started.elapsed().as_secs_f64() * 1000.0,
+            "{} {}",
+            method,
+            target
+        )
+    });
+
+    let mut res = result?;
+    if let Ok(value) = HeaderValue::from_str(request_id.as_str()) {
+        res.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        App, HttpResponse,
+        middleware::from_fn,
+        test::{TestRequest, call_service, init_service},
+        web,
+    };
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn propagates_or_generates_request_ids() {
+        let app =
+            init_service(App::new().wrap(from_fn(request_context)).route(
+                "/",
+                web::get().to(|| async {
+                    HttpResponse::Ok().body(current_request_id().unwrap_or_default())
+                }),
+            ))
+            .await;
+
+        let res = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((REQUEST_ID_HEADER, "abc-123"))
+                .to_request(),
+        )
+        .await;
+        assert_eq!(res.headers().get(&REQUEST_ID_HEADER).unwrap(), "abc-123");
+        let body = actix_web::test::read_body(res).await;
+        assert_eq!(body, "abc-123");
+
+        let res = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((REQUEST_ID_HEADER, "not a valid id"))
+                .to_request(),
+        )
+        .await;
+        let generated = res.headers().get(&REQUEST_ID_HEADER).unwrap();
+        assert!(Uuid::parse_str(generated.to_str().unwrap()).is_ok());
+    }
+}
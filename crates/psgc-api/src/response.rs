@@ -1,11 +1,11 @@
 use std::fmt::Display;
 
-use actix_web::{ResponseError, http::StatusCode};
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
 use psgc_application::errors::UsecaseError;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::errors::APIError;
+use crate::{errors::APIError, request_id::current_request_id};
 
 #[derive(Debug, Deserialize, Serialize, ToSchema, bon::Builder)]
 pub struct APIOk<T: Serialize> {
@@ -46,6 +46,26 @@ impl<T: Serialize> APIOk<T> {
 pub struct APIErr {
     pub error: APIError,
     pub code: String,
+    /// Filled in from the request being handled when the error is rendered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct APIErrBody<'a> {
+    error: &'a APIError,
+    code: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+}
+
+impl APIErr {
+    pub(crate) fn too_many_requests(message: String) -> Self {
+        Self::builder()
+            .code("psgc-429".to_string())
+            .error(APIError::TooManyRequests(message))
+            .build()
+    }
 }
 
 impl Display for APIErr {
@@ -59,6 +79,7 @@ impl Display for APIErr {
             Forbidden => write!(f, "Forbidden access."),
             Unauthorized => write!(f, "Unauthorized access."),
             Conflict(e) => write!(f, "Resource conflict. {e}"),
+            TooManyRequests(e) => write!(f, "Too many requests. {e}"),
         }
     }
 }
@@ -74,8 +95,17 @@ impl ResponseError for APIErr {
             Forbidden => StatusCode::FORBIDDEN,
             Unauthorized => StatusCode::UNAUTHORIZED,
             Conflict(_) => StatusCode::CONFLICT,
+            TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
         }
     }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(APIErrBody {
+            error: &self.error,
+            code: &self.code,
+            request_id: self.request_id.clone().or_else(current_request_id),
+        })
+    }
 }
 
 impl From<UsecaseError> for APIErr {
@@ -1,16 +1,31 @@
-use actix_governor::{Governor, GovernorConfigBuilder};
 use std::sync::Arc;
 use utoipa::OpenApi as OpenApiT;
 
 use crate::{
+    api_key::{ApiKeyAuth, require_admin, require_api_key},
+    dataset::DatasetCache,
     handlers::{
+        admin_handlers::{AdminAPIDoc, build_admin_route},
         barangay_handlers::{BarangayAPIDoc, build_barangay_route},
+        change_handlers::{ChangeAPIDoc, build_change_route},
         city_handlers::{CityAPIDoc, build_city_route},
+        crosswalk_handlers::{CrosswalkAPIDoc, build_crosswalk_route},
+        dataset_handlers::{DatasetAPIDoc, build_dataset_route},
         district_handlers::{DistrictAPIDoc, build_district_route},
+        health_handlers::{HealthAPIDoc, build_health_routes},
+        island_group_handlers::{IslandGroupAPIDoc, build_island_group_route},
+        lineage_handlers::{LineageAPIDoc, build_code_route},
         municipality_handlers::{MunicipalityAPIDoc, build_municipality_route},
+        option_handlers::{OptionAPIDoc, build_option_route},
         province_handlers::{ProvinceAPIDoc, build_province_route},
         region_handlers::{RegionAPIDoc, build_region_route},
+        release_handlers::{ReleaseAPIDoc, build_release_route},
+        stats_handlers::{StatsAPIDoc, build_stats_route},
     },
+    metrics::{Metrics, metrics, track_requests},
+    rate_limit::{RateLimiter, limit_requests},
+    release::route_release,
+    request_id::request_context,
     response::{APIErr, APIOk},
 };
 use actix_cors::Cors;
@@ -19,48 +34,36 @@ use actix_web::{
     body::MessageBody,
     dev::{ServiceFactory, ServiceRequest, ServiceResponse},
     http::{Method, StatusCode},
-    middleware::{ErrorHandlerResponse, ErrorHandlers, Logger, NormalizePath},
+    middleware::{ErrorHandlerResponse, ErrorHandlers, NormalizePath, from_fn},
     web::{self, Data, Json, get},
 };
-use psgc_domain::repositories::{
-    barangay_repository::BarangayRepository, city_repository::CityRepository,
-    district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
-    province_repository::ProvinceRepository, region_repository::RegionRepository,
-};
+use psgc_infrastructure::repositories::backend::Repositories;
 use scalar_doc::favicon::FaviconMimeType;
 use utoipa::openapi::OpenApi;
 use utoipa_actix_web::AppExt;
 
-#[derive(Debug, bon::Builder)]
-pub struct APIState<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
-> {
+#[derive(bon::Builder)]
+pub struct APIState {
     pub allowed_origins: Vec<String>,
-    pub region_repository: Arc<R>,
-    pub province_repository: Arc<P>,
-    pub municipality_repository: Arc<M>,
-    pub district_repository: Arc<D>,
-    pub city_repository: Arc<C>,
-    pub barangay_repository: Arc<B>,
+    pub repositories: Repositories,
+    pub metrics: Metrics,
+    /// Per-client-IP limits; requests aren't limited by IP when unset
+    pub rate_limiter: Option<RateLimiter>,
+    /// Set when every `/api/v1` call must carry an API key
+    pub api_key_auth: Option<ApiKeyAuth>,
+    /// Serve `/metrics` on the public router; turned off when an admin port takes it over.
+    #[builder(default = true)]
+    pub expose_metrics: bool,
+    /// Dataset downloads built so far; share one between workers
+    #[builder(default)]
+    pub datasets: DatasetCache,
 }
 
 #[derive(Debug, OpenApiT)]
 pub struct PSGCApiDoc;
 
-pub fn create_api_router<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: APIState<R, P, M, D, C, B>,
+pub fn create_api_router(
+    state: APIState,
 ) -> App<
     impl ServiceFactory<
         ServiceRequest,
@@ -71,13 +74,7 @@ pub fn create_api_router<
     >,
 > {
     let allowed_origins = state.allowed_origins.clone();
-
-    let governor_config = GovernorConfigBuilder::default()
-        .seconds_per_request(10)
-        .burst_size(1000)
-        .finish()
-        .unwrap();
-    let governor = Governor::new(&governor_config);
+    let expose_metrics = state.expose_metrics;
 
     let cors = Cors::default()
         .allowed_methods(vec![
@@ -99,39 +96,65 @@ pub fn create_api_router<
         .supports_credentials();
 
     let (mut app, docs) = App::new()
-        .wrap(Logger::default())
         .wrap(NormalizePath::trim())
+        // Inside CORS, so 429s carry the CORS headers and preflights aren't counted
+        .wrap(from_fn(limit_requests))
         .wrap(cors)
-        .wrap(governor)
-        .wrap(ErrorHandlers::new().handler(
-            StatusCode::NOT_FOUND,
-            error_404_error_handler::<_, R, P, M, D, C, B>,
-        ))
+        .wrap(from_fn(route_release))
+        .wrap(ErrorHandlers::new().handler(StatusCode::NOT_FOUND, error_404_error_handler))
+        .wrap(from_fn(request_context))
+        .wrap(from_fn(track_requests))
         .app_data(Data::new(state))
         .service(web::resource("/favicon.svg").route(get().to(favicon)))
-        .service(web::resource("/").route(get().to(index::<R, P, M, D, C, B>)))
+        .service(web::resource("/").route(get().to(index)))
         .service(web::resource("/docs").route(get().to(docs)))
+        .configure(build_health_routes)
+        .configure(|config| {
+            if expose_metrics {
+                config.service(web::resource("/metrics").route(get().to(metrics)));
+            }
+        })
         .service(
             web::scope("/api/v1")
+                .wrap(from_fn(require_api_key))
                 .route("", get().to(openapi_json))
-                .service(build_region_route::<R, P, M, D, C, B>())
-                .service(build_province_route::<R, P, M, D, C, B>())
-                .service(build_municipality_route::<R, P, M, D, C, B>())
-                .service(build_district_route::<R, P, M, D, C, B>())
-                .service(build_city_route::<R, P, M, D, C, B>())
-                .service(build_barangay_route::<R, P, M, D, C, B>()),
+                .service(build_release_route())
+                .service(build_region_route())
+                .service(build_province_route())
+                .service(build_municipality_route())
+                .service(build_district_route())
+                .service(build_city_route())
+                .service(build_barangay_route())
+                .service(build_option_route())
+                .service(build_island_group_route())
+                .service(build_code_route())
+                .service(build_crosswalk_route())
+                .service(build_change_route())
+                .service(build_dataset_route())
+                .service(build_stats_route())
+                .service(build_admin_route().wrap(from_fn(require_admin))),
         )
         .into_utoipa_app()
         .split_for_parts();
 
     let mut docs = docs
         .merge_from(PSGCApiDoc::openapi())
+        .merge_from(ReleaseAPIDoc::openapi())
         .merge_from(RegionAPIDoc::openapi())
         .merge_from(ProvinceAPIDoc::openapi())
         .merge_from(MunicipalityAPIDoc::openapi())
         .merge_from(DistrictAPIDoc::openapi())
         .merge_from(CityAPIDoc::openapi())
-        .merge_from(BarangayAPIDoc::openapi());
+        .merge_from(BarangayAPIDoc::openapi())
+        .merge_from(OptionAPIDoc::openapi())
+        .merge_from(IslandGroupAPIDoc::openapi())
+        .merge_from(LineageAPIDoc::openapi())
+        .merge_from(CrosswalkAPIDoc::openapi())
+        .merge_from(ChangeAPIDoc::openapi())
+        .merge_from(DatasetAPIDoc::openapi())
+        .merge_from(StatsAPIDoc::openapi())
+        .merge_from(AdminAPIDoc::openapi())
+        .merge_from(HealthAPIDoc::openapi());
     docs.info.title = "PSGC-rs API Documentation".to_string();
     docs.info.description = Some("API documentation for the PSGC-rs API".to_string());
     docs.info.version = env!("CARGO_PKG_VERSION").to_string();
@@ -142,21 +165,32 @@ pub fn create_api_router<
     app
 }
 
+/// Probes and `/metrics` only, for binding on a separate admin port.
+pub fn create_admin_router(
+    state: APIState,
+) -> App<
+    impl ServiceFactory<
+        ServiceRequest,
+        Response = ServiceResponse<impl MessageBody>,
+        Config = (),
+        InitError = (),
+        Error = Error,
+    >,
+> {
+    App::new()
+        .wrap(NormalizePath::trim())
+        .wrap(from_fn(request_context))
+        .app_data(Data::new(state))
+        .configure(build_health_routes)
+        .service(web::resource("/metrics").route(get().to(metrics)))
+}
+
 async fn favicon() -> Result<HttpResponse, APIErr> {
     let svg = include_str!("../../../assets/favicon.svg");
     Ok(HttpResponse::Ok().content_type("image/svg+xml").body(svg))
 }
 
-async fn index<
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    B: BarangayRepository,
->(
-    state: Data<APIState<R, P, M, D, C, B>>,
-) -> Result<Json<APIOk<String>>, APIErr> {
+async fn index(state: Data<APIState>) -> Result<Json<APIOk<String>>, APIErr> {
     let origin = state
         .allowed_origins
         .first()
@@ -170,19 +204,9 @@ async fn index<
     Ok(Json(APIOk::builder().message(message).build()))
 }
 
-fn error_404_error_handler<B, R, P, M, D, C, Br>(
-    res: ServiceResponse<B>,
-) -> Result<ErrorHandlerResponse<B>>
-where
-    R: RegionRepository,
-    P: ProvinceRepository,
-    M: MunicipalityRepository,
-    D: DistrictRepository,
-    C: CityRepository,
-    Br: BarangayRepository,
-{
+fn error_404_error_handler<B>(res: ServiceResponse<B>) -> Result<ErrorHandlerResponse<B>> {
     let (req, _) = res.into_parts();
-    let state = req.app_data::<Data<APIState<R, P, M, D, C, Br>>>().unwrap();
+    let state = req.app_data::<Data<APIState>>().unwrap();
 
     let origin = state
         .allowed_origins
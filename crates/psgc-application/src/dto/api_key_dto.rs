@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ApiKeyDTO {
+    pub id: uuid::Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub rate_limit_per_minute: u32,
+    pub daily_quota: Option<u64>,
+    pub allowed_origins: Vec<String>,
+    pub admin: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub revoked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Returned once on creation; the plaintext key isn't stored anywhere.
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CreatedApiKeyDTO {
+    pub key: String,
+    pub api_key: ApiKeyDTO,
+}
+
+impl From<psgc_domain::models::api_key::ApiKey> for ApiKeyDTO {
+    fn from(api_key: psgc_domain::models::api_key::ApiKey) -> Self {
+        Self {
+            id: api_key.id,
+            name: api_key.name,
+            prefix: api_key.prefix,
+            rate_limit_per_minute: api_key.rate_limit_per_minute,
+            daily_quota: api_key.daily_quota,
+            allowed_origins: api_key.allowed_origins,
+            admin: api_key.admin,
+            created_at: api_key.created_at,
+            revoked_at: api_key.revoked_at,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct AuditEntryDTO {
+    pub id: uuid::Uuid,
+    pub actor: String,
+    pub entity: String,
+    pub entity_id: uuid::Uuid,
+    pub code: String,
+    /// `create`, `update` or `delete`
+    pub action: String,
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<serde_json::Value>,
+    pub reason: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Who is making a correction and why, recorded with it in the audit log.
+#[derive(Debug, Clone)]
+pub struct ChangeContextDTO {
+    /// The admin API key's name
+    pub actor: String,
+    pub reason: String,
+}
+
+impl From<psgc_domain::models::audit::AuditEntry> for AuditEntryDTO {
+    fn from(entry: psgc_domain::models::audit::AuditEntry) -> Self {
+        Self {
+            action: entry.action().to_string(),
+            id: entry.id,
+            actor: entry.actor,
+            entity: entry.entity,
+            entity_id: entry.entity_id,
+            code: entry.code,
+            before: entry.before,
+            after: entry.after,
+            reason: entry.reason,
+            created_at: entry.created_at,
+        }
+    }
+}
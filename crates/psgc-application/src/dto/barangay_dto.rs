@@ -6,11 +6,18 @@ pub struct BarangayDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before
+    pub former_names: Vec<String>,
     pub population: u64,
     pub city_id: Option<uuid::Uuid>,
     pub municipality_id: Option<uuid::Uuid>,
     pub district_id: Option<uuid::Uuid>,
     pub urban_rural: String,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -22,13 +29,35 @@ impl From<psgc_domain::models::barangay::Barangay> for BarangayDTO {
             code: barangay.code,
             correspondence_code: barangay.correspondence_code,
             name: barangay.name,
+            slug: barangay.slug,
+            former_names: barangay.former_names,
             population: barangay.population,
             city_id: barangay.city_id,
             municipality_id: barangay.municipality_id,
             district_id: barangay.district_id,
             urban_rural: barangay.urban_rural,
+            valid_from: barangay.valid_from,
+            valid_to: barangay.valid_to,
             created_at: barangay.created_at,
             updated_at: barangay.updated_at,
         }
     }
 }
+
+/// A barangay as written through the admin API, under exactly one city or municipality.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct BarangayInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    /// `U` for urban, `R` for rural
+    pub urban_rural: String,
+    pub city_code: Option<String>,
+    pub municipality_code: Option<String>,
+    pub district_code: Option<String>,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
@@ -0,0 +1,44 @@
+use psgc_domain::models::change::Change;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ChangeDTO {
+    /// Position of the change in the feed; usable as `since` to resume after it
+    pub token: String,
+    /// `insert`, `update` or `delete`
+    pub operation: String,
+    pub level: String,
+    pub unit_id: uuid::Uuid,
+    /// The unit's code; for a deletion, the code it had
+    pub code: String,
+    /// The unit as it is after the change, as served by its level's endpoints; missing for
+    /// a deletion. For an abolition it gives the `valid_to` the unit is gone from
+    #[schema(value_type = Option<Object>)]
+    pub unit: Option<serde_json::Value>,
+    pub changed_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ChangesDTO {
+    pub release: String,
+    /// Oldest first
+    pub changes: Vec<ChangeDTO>,
+    /// Pass as `since` to get the changes after these
+    pub next: String,
+    /// Whether more changes are waiting after `next`
+    pub has_more: bool,
+}
+
+impl From<Change> for ChangeDTO {
+    fn from(change: Change) -> Self {
+        Self {
+            token: change.position.to_string(),
+            operation: change.operation.as_str().to_string(),
+            level: change.level,
+            unit_id: change.unit_id,
+            code: change.code,
+            unit: change.unit,
+            changed_at: change.changed_at,
+        }
+    }
+}
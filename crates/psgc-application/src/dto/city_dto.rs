@@ -6,11 +6,18 @@ pub struct CityDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before
+    pub former_names: Vec<String>,
     pub population: u64,
     pub city_class: String,
     pub income_class: String,
     pub region_id: Option<uuid::Uuid>,
     pub province_id: Option<uuid::Uuid>,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -22,13 +29,34 @@ impl From<psgc_domain::models::city::City> for CityDTO {
             code: city.code,
             correspondence_code: city.correspondence_code,
             name: city.name,
+            slug: city.slug,
+            former_names: city.former_names,
             population: city.population,
             city_class: city.city_class,
             income_class: city.income_class,
             region_id: city.region_id,
             province_id: city.province_id,
+            valid_from: city.valid_from,
+            valid_to: city.valid_to,
             created_at: city.created_at,
             updated_at: city.updated_at,
         }
     }
 }
+
+/// A city as written through the admin API. Independent cities have no province.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CityInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    pub city_class: String,
+    pub income_class: String,
+    pub region_code: String,
+    pub province_code: Option<String>,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
@@ -0,0 +1,124 @@
+use psgc_domain::models::crosswalk::{CodeResolution, CrosswalkEntry};
+use serde::{Deserialize, Serialize};
+
+use crate::dto::release_dto::{ReleaseUnitDTO, csv_field};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CrosswalkRequestDTO {
+    /// Historical 9- or 10-digit codes, e.g. a column of a CSV import; at most 5000
+    pub codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CodeResolutionDTO {
+    pub code: String,
+    /// `identical`, `renamed`, `split` or `merged`; missing when nothing is known about the
+    /// code
+    pub mapping_type: Option<String>,
+    /// The units the code maps to in the release resolved against
+    pub current: Vec<ReleaseUnitDTO>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CrosswalkDTO {
+    /// The release the codes were resolved against
+    pub release: String,
+    /// How many codes resolved to nothing
+    pub unresolved: usize,
+    /// One per code asked about, in the same order
+    pub results: Vec<CodeResolutionDTO>,
+}
+
+impl CrosswalkDTO {
+    const CSV_HEADER: &str =
+        "code,mapping_type,current_level,current_code,current_name,current_parent_code";
+
+    /// One row per current unit a code maps to, or a single row with only the code when it
+    /// is unresolved.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(Self::CSV_HEADER);
+        csv.push('\n');
+
+        for result in &self.results {
+            let mapping_type = result.mapping_type.clone().unwrap_or_default();
+            let rows = match result.current.as_slice() {
+                [] => vec![vec![
+                    result.code.clone(),
+                    mapping_type,
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]],
+                current => current
+                    .iter()
+                    .map(|unit| {
+                        vec![
+                            result.code.clone(),
+                            mapping_type.clone(),
+                            unit.level.clone(),
+                            unit.code.clone(),
+                            unit.name.clone(),
+                            unit.parent_code.clone().unwrap_or_default(),
+                        ]
+                    })
+                    .collect(),
+            };
+
+            for fields in rows {
+                let row = fields
+                    .iter()
+                    .map(|field| csv_field(field))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                csv.push_str(&row);
+                csv.push('\n');
+            }
+        }
+
+        csv
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CrosswalkEntryDTO {
+    pub id: uuid::Uuid,
+    pub historical_code: String,
+    pub current_code: String,
+    /// `identical`, `renamed`, `split` or `merged`
+    pub mapping_type: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct CrosswalkEntryInputDTO {
+    pub historical_code: String,
+    /// A code of a unit in use now
+    pub current_code: String,
+    /// `identical`, `renamed`, `split` or `merged`
+    pub mapping_type: String,
+}
+
+impl From<CodeResolution> for CodeResolutionDTO {
+    fn from(resolution: CodeResolution) -> Self {
+        Self {
+            code: resolution.code,
+            mapping_type: resolution
+                .mapping_type
+                .map(|mapping_type| mapping_type.as_str().to_string()),
+            current: resolution.current.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<CrosswalkEntry> for CrosswalkEntryDTO {
+    fn from(entry: CrosswalkEntry) -> Self {
+        Self {
+            id: entry.id,
+            historical_code: entry.historical_code,
+            current_code: entry.current_code,
+            mapping_type: entry.mapping_type.as_str().to_string(),
+            created_at: entry.created_at,
+        }
+    }
+}
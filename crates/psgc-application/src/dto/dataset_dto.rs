@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct DatasetLevelManifestDTO {
+    pub level: String,
+    /// The table the level's units are in within the SQLite file
+    pub table: String,
+    pub rows: usize,
+    /// Hex SHA-256 of the level's lines in the NDJSON file, newlines included
+    pub sha256: String,
+}
+
+/// A file of the dataset as served for download.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct DatasetFileDTO {
+    /// `ndjson` or `sqlite`
+    pub format: String,
+    pub file_name: String,
+    /// Size of the gzipped file
+    pub bytes: usize,
+    /// Hex SHA-256 of the gzipped file, also its ETag
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct DatasetManifestDTO {
+    pub release: String,
+    /// Where to start following `/api/v1/changes` to keep the copy current; missing on the
+    /// in-memory backend, which has no changes feed
+    pub sync_token: Option<String>,
+    /// From the top level down
+    pub levels: Vec<DatasetLevelManifestDTO>,
+    /// The file described; only given by the manifest endpoint, as a file can't hold its
+    /// own checksum
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<DatasetFileDTO>,
+}
+
+/// The units of one level, each as served by the level's endpoints.
+#[derive(Debug, Clone)]
+pub struct DatasetLevelDTO {
+    pub level: String,
+    pub table: String,
+    pub units: Vec<serde_json::Value>,
+}
+
+impl DatasetLevelDTO {
+    /// The level's lines in the NDJSON file.
+    pub fn ndjson_lines(&self) -> impl Iterator<Item = String> {
+        self.units.iter().map(|unit| {
+            let mut line = serde_json::json!({"level": self.level, "unit": unit}).to_string();
+            line.push('\n');
+            line
+        })
+    }
+}
+
+/// Every current unit of a release, by level.
+#[derive(Debug, Clone)]
+pub struct DatasetDTO {
+    pub release: String,
+    /// Where a copy made from the dataset picks up the changes feed
+    pub sync_token: Option<String>,
+    pub levels: Vec<DatasetLevelDTO>,
+}
+
+impl DatasetDTO {
+    /// How many units each level has and their checksums. Serializes every unit, so build
+    /// it off the async workers, along with the file.
+    pub fn manifest(&self) -> DatasetManifestDTO {
+        DatasetManifestDTO {
+            release: self.release.clone(),
+            sync_token: self.sync_token.clone(),
+            levels: self
+                .levels
+                .iter()
+                .map(|level| {
+                    let mut sha256 = Sha256::new();
+                    level
+                        .ndjson_lines()
+                        .for_each(|line| sha256.update(line.as_bytes()));
+
+                    DatasetLevelManifestDTO {
+                        level: level.level.clone(),
+                        table: level.table.clone(),
+                        rows: level.units.len(),
+                        sha256: sha256
+                            .finalize()
+                            .iter()
+                            .map(|b| format!("{b:02x}"))
+                            .collect(),
+                    }
+                })
+                .collect(),
+            file: None,
+        }
+    }
+
+    /// `manifest` as `{"manifest": ...}` on the first line, then a
+    /// `{"level": ..., "unit": ...}` line per unit, level by level from the top.
+    pub fn to_ndjson(&self, manifest: &DatasetManifestDTO) -> Vec<u8> {
+        let mut ndjson = serde_json::json!({"manifest": manifest}).to_string();
+        ndjson.push('\n');
+        for level in &self.levels {
+            ndjson.extend(level.ndjson_lines());
+        }
+
+        ndjson.into_bytes()
+    }
+}
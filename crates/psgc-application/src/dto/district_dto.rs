@@ -6,8 +6,13 @@ pub struct DistrictDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
     pub population: u64,
     pub region_id: uuid::Uuid,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -19,10 +24,27 @@ impl From<psgc_domain::models::district::District> for DistrictDTO {
             code: district.code,
             correspondence_code: district.correspondence_code,
             name: district.name,
+            slug: district.slug,
             population: district.population,
             region_id: district.region_id,
+            valid_from: district.valid_from,
+            valid_to: district.valid_to,
             created_at: district.created_at,
             updated_at: district.updated_at,
         }
     }
 }
+
+/// A district as written through the admin API, linked to its region by code.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct DistrictInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    pub region_code: String,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
@@ -0,0 +1,17 @@
+use psgc_domain::models::island_group::IslandGroup;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct IslandGroupDTO {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<IslandGroup> for IslandGroupDTO {
+    fn from(island_group: IslandGroup) -> Self {
+        Self {
+            id: island_group.id().to_string(),
+            name: island_group.name().to_string(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::NaiveDate;
+use psgc_domain::models::lineage::{LineageEvent, LineageStep};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct LineageEventDTO {
+    pub id: uuid::Uuid,
+    pub predecessor_code: String,
+    /// The same as `predecessor_code` when the unit kept its code
+    pub successor_code: String,
+    /// `conversion`, `split`, `merge`, `rename` or `code_change`
+    pub event_type: String,
+    /// The day the change took effect
+    pub effective_on: NaiveDate,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// One predecessor and successor of an event; a split or merge is recorded once per pair.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct LineageEventInputDTO {
+    pub predecessor_code: String,
+    pub successor_code: String,
+    /// `conversion`, `split`, `merge`, `rename` or `code_change`
+    pub event_type: String,
+    pub effective_on: NaiveDate,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct LineageStepDTO {
+    /// 1 for the events of the code asked about, 2 for those of the codes they lead to, and
+    /// so on
+    pub depth: u32,
+    #[serde(flatten)]
+    pub event: LineageEventDTO,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct LineageDTO {
+    pub code: String,
+    /// Nearest first, then by date
+    pub events: Vec<LineageStepDTO>,
+}
+
+impl From<LineageEvent> for LineageEventDTO {
+    fn from(event: LineageEvent) -> Self {
+        Self {
+            id: event.id,
+            predecessor_code: event.predecessor_code,
+            successor_code: event.successor_code,
+            event_type: event.event_type.as_str().to_string(),
+            effective_on: event.effective_on,
+            created_at: event.created_at,
+        }
+    }
+}
+
+impl From<LineageStep> for LineageStepDTO {
+    fn from(step: LineageStep) -> Self {
+        Self {
+            depth: step.depth,
+            event: step.event.into(),
+        }
+    }
+}
@@ -1,12 +1,22 @@
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+pub mod api_key_dto;
+pub mod audit_dto;
 pub mod barangay_dto;
+pub mod change_dto;
 pub mod city_dto;
+pub mod crosswalk_dto;
+pub mod dataset_dto;
 pub mod district_dto;
+pub mod island_group_dto;
+pub mod lineage_dto;
 pub mod municipality_dto;
+pub mod option_dto;
 pub mod province_dto;
 pub mod region_dto;
+pub mod release_dto;
+pub mod stats_dto;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PaginateResponseDTO<T: Serialize + ToSchema> {
@@ -34,3 +44,23 @@ where
         }
     }
 }
+
+/// Result of a by-name lookup: the unit itself, or every candidate when the name is shared.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum NameLookupDTO<T: Serialize + ToSchema> {
+    Match(T),
+    Ambiguous(Vec<T>),
+}
+
+impl<T: Serialize + ToSchema> NameLookupDTO<T> {
+    pub fn from_matches<D: Into<T>>(matches: Vec<D>) -> Option<Self> {
+        let mut matches = matches.into_iter().map(Into::into).collect::<Vec<T>>();
+
+        match matches.len() {
+            0 => None,
+            1 => matches.pop().map(Self::Match),
+            _ => Some(Self::Ambiguous(matches)),
+        }
+    }
+}
@@ -6,6 +6,9 @@ pub struct MunicipalityDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before
+    pub former_names: Vec<String>,
     pub population: u64,
     pub income_class: String,
     pub region_id: Option<uuid::Uuid>,
@@ -13,6 +16,10 @@ pub struct MunicipalityDTO {
     pub district_id: Option<uuid::Uuid>,
     pub sub_municipality_id: Option<uuid::Uuid>,
     pub barangay_id: Option<uuid::Uuid>,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -24,6 +31,8 @@ impl From<psgc_domain::models::municipality::Municipality> for MunicipalityDTO {
             code: municipality.code,
             correspondence_code: municipality.correspondence_code,
             name: municipality.name,
+            slug: municipality.slug,
+            former_names: municipality.former_names,
             population: municipality.population,
             income_class: municipality.income_class,
             region_id: municipality.region_id,
@@ -31,8 +40,27 @@ impl From<psgc_domain::models::municipality::Municipality> for MunicipalityDTO {
             district_id: municipality.district_id,
             sub_municipality_id: municipality.sub_municipality_id,
             barangay_id: municipality.barangay_id,
+            valid_from: municipality.valid_from,
+            valid_to: municipality.valid_to,
             created_at: municipality.created_at,
             updated_at: municipality.updated_at,
         }
     }
 }
+
+/// A municipality as written through the admin API, linked to its parents by code.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct MunicipalityInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    pub income_class: String,
+    pub region_code: String,
+    pub province_code: Option<String>,
+    pub district_code: Option<String>,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
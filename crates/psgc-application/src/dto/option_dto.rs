@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct OptionDTO {
+    pub code: String,
+    pub name: String,
+}
+
+impl From<psgc_domain::models::province::Province> for OptionDTO {
+    fn from(province: psgc_domain::models::province::Province) -> Self {
+        Self {
+            code: province.code,
+            name: province.name,
+        }
+    }
+}
+
+impl From<psgc_domain::models::city::City> for OptionDTO {
+    fn from(city: psgc_domain::models::city::City) -> Self {
+        Self {
+            code: city.code,
+            name: city.name,
+        }
+    }
+}
+
+impl From<psgc_domain::models::municipality::Municipality> for OptionDTO {
+    fn from(municipality: psgc_domain::models::municipality::Municipality) -> Self {
+        Self {
+            code: municipality.code,
+            name: municipality.name,
+        }
+    }
+}
+
+impl From<psgc_domain::models::barangay::Barangay> for OptionDTO {
+    fn from(barangay: psgc_domain::models::barangay::Barangay) -> Self {
+        Self {
+            code: barangay.code,
+            name: barangay.name,
+        }
+    }
+}
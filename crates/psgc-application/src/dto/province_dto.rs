@@ -6,9 +6,16 @@ pub struct ProvinceDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before
+    pub former_names: Vec<String>,
     pub population: u64,
     pub income_class: String,
     pub region_id: uuid::Uuid,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -20,11 +27,30 @@ impl From<psgc_domain::models::province::Province> for ProvinceDTO {
             code: province.code,
             correspondence_code: province.correspondence_code,
             name: province.name,
+            slug: province.slug,
+            former_names: province.former_names,
             population: province.population,
             income_class: province.income_class,
             region_id: province.region_id,
+            valid_from: province.valid_from,
+            valid_to: province.valid_to,
             created_at: province.created_at,
             updated_at: province.updated_at,
         }
     }
 }
+
+/// A province as written through the admin API, linked to its region by code.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ProvinceInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    pub income_class: String,
+    pub region_code: String,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
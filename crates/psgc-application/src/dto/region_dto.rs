@@ -6,8 +6,14 @@ pub struct RegionDTO {
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
     pub designation: String,
+    pub island_group: Option<String>,
     pub population: u64,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -19,10 +25,28 @@ impl From<psgc_domain::models::region::Region> for RegionDTO {
             code: region.code,
             correspondence_code: region.correspondence_code,
             name: region.name,
+            slug: region.slug,
             designation: region.designation,
+            island_group: region.island_group.map(|g| g.id().to_string()),
             population: region.population,
+            valid_from: region.valid_from,
+            valid_to: region.valid_to,
             created_at: region.created_at,
             updated_at: region.updated_at,
         }
     }
 }
+
+/// A region as written through the admin API; its island group follows from the code.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct RegionInputDTO {
+    pub code: String,
+    pub correspondence_code: String,
+    pub name: String,
+    #[serde(default)]
+    pub population: u64,
+    pub designation: String,
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// Set to create a unit already abolished on this day; a replacement must repeat the unit's own
+    pub valid_to: Option<chrono::NaiveDate>,
+}
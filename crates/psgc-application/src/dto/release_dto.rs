@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ReleaseDTO {
+    /// Year and quarter of the PSGC publication, e.g. `2024Q3`
+    pub id: String,
+    pub loaded_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ReleaseListDTO {
+    /// The release served when a request doesn't name one; `None` until one is loaded
+    pub latest: Option<String>,
+    /// Newest first
+    pub releases: Vec<ReleaseDTO>,
+}
+
+impl From<psgc_domain::models::release::Release> for ReleaseDTO {
+    fn from(release: psgc_domain::models::release::Release) -> Self {
+        Self {
+            id: release.id,
+            loaded_at: release.loaded_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ReleaseUnitDTO {
+    /// `region`, `province`, `district`, `city`, `municipality` or `barangay`
+    pub level: String,
+    /// 10-digit PSGC code
+    pub code: String,
+    pub name: String,
+    pub parent_code: Option<String>,
+    /// City class for cities, `U` or `R` for barangays, empty otherwise
+    pub classification: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct UnitChangeDTO {
+    /// Any of `added`, `abolished`, `renamed`, `code_changed` and `reclassified`
+    pub changes: Vec<String>,
+    /// The unit in the older release; missing when it was added
+    pub before: Option<ReleaseUnitDTO>,
+    /// The unit in the newer release; missing when it was abolished
+    pub after: Option<ReleaseUnitDTO>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ReleaseDiffSummaryDTO {
+    pub added: usize,
+    pub abolished: usize,
+    pub renamed: usize,
+    pub code_changed: usize,
+    pub reclassified: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ReleaseDiffDTO {
+    pub from: String,
+    pub to: String,
+    pub summary: ReleaseDiffSummaryDTO,
+    /// Ordered by level, top down, then code
+    pub changes: Vec<UnitChangeDTO>,
+}
+
+impl ReleaseDiffDTO {
+    const CSV_HEADER: &str = "changes,before_level,before_code,before_name,before_parent_code,before_classification,after_level,after_code,after_name,after_parent_code,after_classification";
+
+    /// One row per changed unit; `changes` is joined with `;`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(Self::CSV_HEADER);
+        csv.push('\n');
+
+        for change in &self.changes {
+            let mut fields = vec![change.changes.join(";")];
+            for unit in [&change.before, &change.after] {
+                match unit {
+                    Some(unit) => fields.extend([
+                        unit.level.clone(),
+                        unit.code.clone(),
+                        unit.name.clone(),
+                        unit.parent_code.clone().unwrap_or_default(),
+                        unit.classification.clone(),
+                    ]),
+                    None => fields.extend(std::iter::repeat_n(String::new(), 5)),
+                }
+            }
+
+            let row = fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(",");
+            csv.push_str(&row);
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
+/// Quotes a field when it holds a comma, quote or line break.
+pub(crate) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl From<psgc_domain::models::release_diff::ReleaseUnit> for ReleaseUnitDTO {
+    fn from(unit: psgc_domain::models::release_diff::ReleaseUnit) -> Self {
+        Self {
+            level: unit.level,
+            code: unit.code,
+            name: unit.name,
+            parent_code: unit.parent_code,
+            classification: unit.classification,
+        }
+    }
+}
+
+impl From<psgc_domain::models::release_diff::ReleaseDiff> for ReleaseDiffDTO {
+    fn from(diff: psgc_domain::models::release_diff::ReleaseDiff) -> Self {
+        use psgc_domain::models::release_diff::UnitChangeKind;
+
+        let summary = ReleaseDiffSummaryDTO {
+            added: diff.count(UnitChangeKind::Added),
+            abolished: diff.count(UnitChangeKind::Abolished),
+            renamed: diff.count(UnitChangeKind::Renamed),
+            code_changed: diff.count(UnitChangeKind::CodeChanged),
+            reclassified: diff.count(UnitChangeKind::Reclassified),
+        };
+
+        Self {
+            from: diff.from,
+            to: diff.to,
+            summary,
+            changes: diff
+                .changes
+                .into_iter()
+                .map(|change| UnitChangeDTO {
+                    changes: change
+                        .kinds
+                        .iter()
+                        .map(|kind| kind.as_str().to_string())
+                        .collect(),
+                    before: change.before.map(|unit| unit.into()),
+                    after: change.after.map(|unit| unit.into()),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_diffs_as_csv() {
+        let diff = ReleaseDiffDTO {
+            from: "2024Q2".to_string(),
+            to: "2024Q3".to_string(),
+            summary: ReleaseDiffSummaryDTO {
+                added: 0,
+                abolished: 0,
+                renamed: 1,
+                code_changed: 0,
+                reclassified: 0,
+            },
+            changes: vec![UnitChangeDTO {
+                changes: vec!["renamed".to_string()],
+                before: Some(ReleaseUnitDTO {
+                    level: "municipality".to_string(),
+                    code: "1908802000".to_string(),
+                    name: "Nuling".to_string(),
+                    parent_code: Some("1908800000".to_string()),
+                    classification: String::new(),
+                }),
+                after: Some(ReleaseUnitDTO {
+                    level: "municipality".to_string(),
+                    code: "1908802000".to_string(),
+                    name: "Sultan Kudarat, \"Nuling\"".to_string(),
+                    parent_code: Some("1908800000".to_string()),
+                    classification: String::new(),
+                }),
+            }],
+        };
+
+        let csv = diff.to_csv();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[1],
+            "renamed,municipality,1908802000,Nuling,1908800000,,municipality,1908802000,\"Sultan Kudarat, \"\"Nuling\"\"\",1908800000,"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct RegionStatsDTO {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub province_count: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+impl From<psgc_domain::models::stats::RegionStats> for RegionStatsDTO {
+    fn from(stats: psgc_domain::models::stats::RegionStats) -> Self {
+        Self {
+            code: stats.code,
+            name: stats.name,
+            population: stats.population,
+            province_count: stats.province_count,
+            city_count: stats.city_count,
+            municipality_count: stats.municipality_count,
+            barangay_count: stats.barangay_count,
+            urban_barangay_count: stats.urban_barangay_count,
+            rural_barangay_count: stats.rural_barangay_count,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct ProvinceStatsDTO {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+impl From<psgc_domain::models::stats::ProvinceStats> for ProvinceStatsDTO {
+    fn from(stats: psgc_domain::models::stats::ProvinceStats) -> Self {
+        Self {
+            code: stats.code,
+            name: stats.name,
+            population: stats.population,
+            city_count: stats.city_count,
+            municipality_count: stats.municipality_count,
+            barangay_count: stats.barangay_count,
+            urban_barangay_count: stats.urban_barangay_count,
+            rural_barangay_count: stats.rural_barangay_count,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct GroupCountDTO {
+    pub group: String,
+    pub count: u64,
+}
+
+impl From<psgc_domain::models::stats::GroupCount> for GroupCountDTO {
+    fn from(group_count: psgc_domain::models::stats::GroupCount) -> Self {
+        Self {
+            group: group_count.group,
+            count: group_count.count,
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use psgc_domain::{
+    errors::RepositoryError, models::api_key::ApiKey,
+    repositories::api_key_repository::ApiKeyRepository,
+};
+use sha2::{Digest, Sha256};
+use tracing::instrument;
+
+use crate::{
+    dto::api_key_dto::{ApiKeyDTO, CreatedApiKeyDTO},
+    errors::UsecaseError,
+};
+
+const KEY_PREFIX: &str = "psgc_";
+/// `psgc_` plus the first few random characters, enough to tell keys apart in listings
+const DISPLAY_PREFIX_LEN: usize = 12;
+
+/// Keys are looked up by the hex SHA-256 of the plaintext.
+pub fn hash_api_key(key: &str) -> String {
+    Sha256::digest(key.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+fn generate_api_key() -> String {
+    format!(
+        "{KEY_PREFIX}{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateApiKeyUsecase<R: ApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: ApiKeyRepository> CreateApiKeyUsecase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn api_key_repository(&self) -> &R {
+        self.api_key_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateApiKeyUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        name: &str,
+        rate_limit_per_minute: u32,
+        daily_quota: Option<u64>,
+        allowed_origins: Vec<String>,
+        admin: bool,
+    ) -> Result<CreatedApiKeyDTO, UsecaseError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name must not be empty".to_string(),
+            ));
+        }
+        if rate_limit_per_minute == 0 {
+            return Err(UsecaseError::ValidationError(
+                "rate limit must be at least one request per minute".to_string(),
+            ));
+        }
+        if daily_quota == Some(0) {
+            return Err(UsecaseError::ValidationError(
+                "daily quota must be at least one request".to_string(),
+            ));
+        }
+
+        let key = generate_api_key();
+        let api_key = ApiKey::builder()
+            .id(uuid::Uuid::now_v7())
+            .name(name.to_string())
+            .prefix(key[..DISPLAY_PREFIX_LEN].to_string())
+            .key_hash(hash_api_key(&key))
+            .rate_limit_per_minute(rate_limit_per_minute)
+            .maybe_daily_quota(daily_quota)
+            .allowed_origins(allowed_origins)
+            .admin(admin)
+            .created_at(Utc::now())
+            .build();
+
+        self.api_key_repository().create(&api_key).await?;
+
+        Ok(CreatedApiKeyDTO {
+            key,
+            api_key: api_key.into(),
+        })
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListApiKeysUsecase<R: ApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: ApiKeyRepository> ListApiKeysUsecase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn api_key_repository(&self) -> &R {
+        self.api_key_repository.as_ref()
+    }
+
+    #[instrument(name = "ListApiKeysUsecase::execute", skip(self))]
+    pub async fn execute(&self) -> Result<Vec<ApiKeyDTO>, UsecaseError> {
+        let api_keys = self.api_key_repository().list_all().await?;
+
+        Ok(api_keys.into_iter().map(|k| k.into()).collect())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct RevokeApiKeyUsecase<R: ApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: ApiKeyRepository> RevokeApiKeyUsecase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn api_key_repository(&self) -> &R {
+        self.api_key_repository.as_ref()
+    }
+
+    #[instrument(name = "RevokeApiKeyUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<(), UsecaseError> {
+        self.api_key_repository().revoke(name).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AuthenticateApiKeyUsecase<R: ApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: ApiKeyRepository> AuthenticateApiKeyUsecase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn api_key_repository(&self) -> &R {
+        self.api_key_repository.as_ref()
+    }
+
+    /// Unknown and revoked keys are `Unauthorized`; a valid key used from an origin it
+    /// isn't allowed on is `Forbidden`.
+    #[instrument(name = "AuthenticateApiKeyUsecase::execute", skip_all, fields(origin))]
+    pub async fn execute(
+        &self,
+        key: &str,
+        origin: Option<&str>,
+    ) -> Result<ApiKeyDTO, UsecaseError> {
+        let api_key = match self
+            .api_key_repository()
+            .find_by_hash(&hash_api_key(key))
+            .await
+        {
+            Ok(api_key) if !api_key.is_revoked() => api_key,
+            Ok(_) | Err(RepositoryError::NotFound) => return Err(UsecaseError::Unauthorized),
+            Err(e) => return Err(e.into()),
+        };
+
+        if !api_key.allows_origin(origin) {
+            return Err(UsecaseError::Forbidden);
+        }
+
+        Ok(api_key.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct RecordApiKeyUsageUsecase<R: ApiKeyRepository> {
+    api_key_repository: Arc<R>,
+}
+
+impl<R: ApiKeyRepository> RecordApiKeyUsageUsecase<R> {
+    pub fn new(api_key_repository: Arc<R>) -> Self {
+        Self { api_key_repository }
+    }
+
+    fn api_key_repository(&self) -> &R {
+        self.api_key_repository.as_ref()
+    }
+
+    /// Counts a request against today's (UTC) quota and returns the requests made today.
+    #[instrument(name = "RecordApiKeyUsageUsecase::execute", skip(self))]
+    pub async fn execute(&self, id: uuid::Uuid) -> Result<u64, UsecaseError> {
+        let requests = self
+            .api_key_repository()
+            .record_usage(id, Utc::now().date_naive())
+            .await?;
+
+        Ok(requests)
+    }
+}
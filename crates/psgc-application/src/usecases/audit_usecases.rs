@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use psgc_domain::repositories::audit_repository::AuditLogRepository;
+use tracing::instrument;
+
+use crate::{
+    dto::{PaginateResponseDTO, audit_dto::AuditEntryDTO},
+    errors::UsecaseError,
+};
+
+/// Levels that have a history, as named in audit entries.
+const ENTITIES: [&str; 6] = [
+    "region",
+    "province",
+    "district",
+    "city",
+    "municipality",
+    "barangay",
+];
+
+/// Accepts a date (`2024-07-01`, from midnight UTC) or an RFC 3339 timestamp.
+pub(crate) fn parse_since(since: &str) -> Result<chrono::DateTime<Utc>, UsecaseError> {
+    if let Ok(date) = NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+
+    chrono::DateTime::parse_from_rfc3339(since)
+        .map(|since| since.with_timezone(&Utc))
+        .map_err(|_| {
+            UsecaseError::ValidationError(format!(
+                "since must be a date (YYYY-MM-DD) or an RFC 3339 timestamp, got {since}"
+            ))
+        })
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListAuditLogUsecase<A: AuditLogRepository> {
+    audit_repository: Arc<A>,
+}
+
+impl<A: AuditLogRepository> ListAuditLogUsecase<A> {
+    pub fn new(audit_repository: Arc<A>) -> Self {
+        Self { audit_repository }
+    }
+
+    fn audit_repository(&self) -> &A {
+        self.audit_repository.as_ref()
+    }
+
+    /// Newest first, optionally only for one code and from `since` onwards.
+    #[instrument(name = "ListAuditLogUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: Option<&str>,
+        since: Option<&str>,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<AuditEntryDTO>, UsecaseError> {
+        let since = since.map(parse_since).transpose()?;
+        let code = code.map(str::trim).filter(|code| !code.is_empty());
+        let entries = self
+            .audit_repository()
+            .list(code, since, page, limit)
+            .await?;
+
+        Ok(entries.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct GetAuditHistoryUsecase<A: AuditLogRepository> {
+    audit_repository: Arc<A>,
+}
+
+impl<A: AuditLogRepository> GetAuditHistoryUsecase<A> {
+    pub fn new(audit_repository: Arc<A>) -> Self {
+        Self { audit_repository }
+    }
+
+    fn audit_repository(&self) -> &A {
+        self.audit_repository.as_ref()
+    }
+
+    /// Every recorded change to the unit that has, or had, this code, oldest first; the
+    /// unit doesn't have to exist any more.
+    #[instrument(name = "GetAuditHistoryUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        entity: &str,
+        code: &str,
+    ) -> Result<Vec<AuditEntryDTO>, UsecaseError> {
+        if !ENTITIES.contains(&entity) {
+            return Err(UsecaseError::NotFound);
+        }
+
+        let entries = self.audit_repository().history(entity, code).await?;
+        if entries.is_empty() {
+            return Err(UsecaseError::NotFound);
+        }
+
+        Ok(entries.into_iter().map(|e| e.into()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dates_and_timestamps() {
+        assert_eq!(
+            parse_since("2024-07-01").unwrap().to_rfc3339(),
+            "2024-07-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2024-07-01T08:00:00+08:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2024-07-01T00:00:00+00:00"
+        );
+        assert!(parse_since("July 2024").is_err());
+    }
+}
@@ -1,10 +1,29 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::barangay_repository::BarangayRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::barangay::Barangay,
+    repositories::{
+        barangay_repository::BarangayRepository, city_repository::CityRepository,
+        district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
+    },
+};
+use tracing::instrument;
 
 use crate::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO},
+    dto::{
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        barangay_dto::{BarangayDTO, BarangayInputDTO},
+    },
     errors::UsecaseError,
+    usecases::{
+        names,
+        writes::{
+            audit_entry, ensure_can_abolish, ensure_code_free, ensure_same_region,
+            ensure_valid_to_kept, parent, pick_slug, validate_unit, validate_validity,
+        },
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -23,6 +42,7 @@ impl<B: BarangayRepository> GetBarangayByCodeUsecase<B> {
         self.barangay_repository.as_ref()
     }
 
+    #[instrument(name = "GetBarangayByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<BarangayDTO, UsecaseError> {
         let barangay_repository = self.barangay_repository();
         let barangay = barangay_repository.find_by_code(code).await?;
@@ -31,6 +51,65 @@ impl<B: BarangayRepository> GetBarangayByCodeUsecase<B> {
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct GetBarangayBySlugUsecase<B: BarangayRepository> {
+    barangay_repository: Arc<B>,
+}
+
+impl<B: BarangayRepository> GetBarangayBySlugUsecase<B> {
+    pub fn new(barangay_repository: Arc<B>) -> Self {
+        Self {
+            barangay_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    #[instrument(name = "GetBarangayBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<BarangayDTO, UsecaseError> {
+        let barangay_repository = self.barangay_repository();
+        let barangay = barangay_repository.find_by_slug(slug).await?;
+
+        Ok(barangay.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct FindBarangayByNameUsecase<B: BarangayRepository> {
+    barangay_repository: Arc<B>,
+}
+
+impl<B: BarangayRepository> FindBarangayByNameUsecase<B> {
+    pub fn new(barangay_repository: Arc<B>) -> Self {
+        Self {
+            barangay_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    #[instrument(name = "FindBarangayByNameUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<NameLookupDTO<BarangayDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let barangay_repository = self.barangay_repository();
+        let matches = names::list_by_name(name, async |name| {
+            barangay_repository.list_by_name(name).await
+        })
+        .await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListBarangaysUsecase<B: BarangayRepository> {
     barangay_repository: Arc<B>,
@@ -47,6 +126,7 @@ impl<B: BarangayRepository> ListBarangaysUsecase<B> {
         self.barangay_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -75,6 +155,7 @@ impl<B: BarangayRepository> ListBarangaysByCityUsecase<B> {
         self.barangay_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysByCityUsecase::execute", skip(self))]
     pub async fn execute(&self, city_code: &str) -> Result<Vec<BarangayDTO>, UsecaseError> {
         let barangay_repository = self.barangay_repository();
         let barangays = barangay_repository.list_by_city_code(city_code).await?;
@@ -100,6 +181,7 @@ impl<B: BarangayRepository> ListBarangaysByMunicipalityUsecase<B> {
         self.barangay_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysByMunicipalityUsecase::execute", skip(self))]
     pub async fn execute(&self, municipality_code: &str) -> Result<Vec<BarangayDTO>, UsecaseError> {
         let barangay_repository = self.barangay_repository();
         let barangays = barangay_repository
@@ -127,6 +209,7 @@ impl<B: BarangayRepository> ListBarangaysByDistrictUsecase<B> {
         self.barangay_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysByDistrictUsecase::execute", skip(self))]
     pub async fn execute(&self, district_code: &str) -> Result<Vec<BarangayDTO>, UsecaseError> {
         let barangay_repository = self.barangay_repository();
         let barangays = barangay_repository
@@ -137,3 +220,332 @@ impl<B: BarangayRepository> ListBarangaysByDistrictUsecase<B> {
         Ok(barangay_dtos)
     }
 }
+
+/// Builds the barangay to save from `input`, keeping the id, creation time and (unless the
+/// name changed) the slug of the `existing` barangay being updated.
+///
+/// A barangay sits under exactly one city or municipality, and its district, if any, has
+/// to be in the same region as that parent.
+async fn build_barangay<
+    B: BarangayRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+    D: DistrictRepository,
+>(
+    barangay_repository: &B,
+    city_repository: &C,
+    municipality_repository: &M,
+    district_repository: &D,
+    existing: Option<&Barangay>,
+    input: BarangayInputDTO,
+) -> Result<Barangay, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    if !matches!(input.urban_rural.as_str(), "U" | "R") {
+        return Err(UsecaseError::ValidationError(
+            "urban_rural must be U or R".to_string(),
+        ));
+    }
+    let name = input.name.trim().to_string();
+    let id = existing.map(|b| b.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        barangay_repository.find_by_code(&input.code).await,
+        id,
+        "barangay",
+        &input.code,
+    )?;
+
+    let (city, municipality) = match (
+        input.city_code.as_deref(),
+        input.municipality_code.as_deref(),
+    ) {
+        (Some(code), None) => (
+            Some(parent(
+                city_repository.find_by_code(code).await,
+                "city",
+                code,
+            )?),
+            None,
+        ),
+        (None, Some(code)) => (
+            None,
+            Some(parent(
+                municipality_repository.find_by_code(code).await,
+                "municipality",
+                code,
+            )?),
+        ),
+        _ => {
+            return Err(UsecaseError::ValidationError(
+                "a barangay needs exactly one of city_code or municipality_code".to_string(),
+            ));
+        }
+    };
+    let (parent_region_id, parent_label, parent_slug) = match (&city, &municipality) {
+        (Some(city), _) => (city.region_id, format!("city {}", city.code), &city.slug),
+        (_, Some(municipality)) => (
+            municipality.region_id,
+            format!("municipality {}", municipality.code),
+            &municipality.slug,
+        ),
+        (None, None) => unreachable!("a barangay always has a city or municipality here"),
+    };
+
+    let district = match input.district_code.as_deref() {
+        Some(code) => {
+            let district = parent(
+                district_repository.find_by_code(code).await,
+                "district",
+                code,
+            )?;
+            ensure_same_region(
+                "district",
+                code,
+                Some(district.region_id),
+                parent_region_id,
+                &format!("the region of {parent_label}"),
+            )?;
+            Some(district)
+        }
+        None => None,
+    };
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            pick_slug(
+                &name,
+                Some(parent_slug),
+                &input.code,
+                id,
+                async |slug: &str| barangay_repository.find_by_slug(slug).await,
+            )
+            .await?
+        }
+    };
+
+    Ok(Barangay::builder()
+        .id(id)
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .former_names(names::former_names(existing, &name))
+        .name(name)
+        .slug(slug)
+        .population(input.population)
+        .urban_rural(input.urban_rural)
+        .maybe_city_id(city.as_ref().map(|c| c.id))
+        .maybe_municipality_id(municipality.as_ref().map(|m| m.id))
+        .maybe_district_id(district.map(|d| d.id))
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|b| b.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateBarangayUsecase<
+    B: BarangayRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+    D: DistrictRepository,
+> {
+    barangay_repository: Arc<B>,
+    city_repository: Arc<C>,
+    municipality_repository: Arc<M>,
+    district_repository: Arc<D>,
+}
+
+impl<B: BarangayRepository, C: CityRepository, M: MunicipalityRepository, D: DistrictRepository>
+    CreateBarangayUsecase<B, C, M, D>
+{
+    pub fn new(
+        barangay_repository: Arc<B>,
+        city_repository: Arc<C>,
+        municipality_repository: Arc<M>,
+        district_repository: Arc<D>,
+    ) -> Self {
+        Self {
+            barangay_repository,
+            city_repository,
+            municipality_repository,
+            district_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateBarangayUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: BarangayInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<BarangayDTO, UsecaseError> {
+        let barangay = build_barangay(
+            self.barangay_repository(),
+            self.city_repository(),
+            self.municipality_repository(),
+            self.district_repository(),
+            None,
+            input,
+        )
+        .await?;
+
+        let created = BarangayDTO::from(barangay.clone());
+        let audit = audit_entry(
+            "barangay",
+            barangay.id,
+            &barangay.code,
+            None,
+            Some(&created),
+            change,
+        )?;
+        self.barangay_repository().create(&barangay, &audit).await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateBarangayUsecase<
+    B: BarangayRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+    D: DistrictRepository,
+> {
+    barangay_repository: Arc<B>,
+    city_repository: Arc<C>,
+    municipality_repository: Arc<M>,
+    district_repository: Arc<D>,
+}
+
+impl<B: BarangayRepository, C: CityRepository, M: MunicipalityRepository, D: DistrictRepository>
+    UpdateBarangayUsecase<B, C, M, D>
+{
+    pub fn new(
+        barangay_repository: Arc<B>,
+        city_repository: Arc<C>,
+        municipality_repository: Arc<M>,
+        district_repository: Arc<D>,
+    ) -> Self {
+        Self {
+            barangay_repository,
+            city_repository,
+            municipality_repository,
+            district_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateBarangayUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: BarangayInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<BarangayDTO, UsecaseError> {
+        let existing = self.barangay_repository().find_by_code(code).await?;
+        ensure_valid_to_kept("barangay", code, &existing, input.valid_to)?;
+        let barangay = build_barangay(
+            self.barangay_repository(),
+            self.city_repository(),
+            self.municipality_repository(),
+            self.district_repository(),
+            Some(&existing),
+            input,
+        )
+        .await?;
+
+        let updated = BarangayDTO::from(barangay.clone());
+        let audit = audit_entry(
+            "barangay",
+            barangay.id,
+            &barangay.code,
+            Some(&BarangayDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        self.barangay_repository().update(&barangay, &audit).await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishBarangayUsecase<B: BarangayRepository> {
+    barangay_repository: Arc<B>,
+}
+
+impl<B: BarangayRepository> AbolishBarangayUsecase<B> {
+    pub fn new(barangay_repository: Arc<B>) -> Self {
+        Self {
+            barangay_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    /// The barangay is kept, with `valid_to` as the day it was abolished, and returned as it
+    /// is now.
+    #[instrument(name = "AbolishBarangayUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<BarangayDTO, UsecaseError> {
+        let barangay_repository = self.barangay_repository();
+        let barangay = barangay_repository.find_by_code(code).await?;
+        ensure_can_abolish("barangay", &barangay.code, &barangay, valid_to)?;
+
+        let abolished = Barangay {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..barangay.clone()
+        };
+        let after = BarangayDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "barangay",
+            abolished.id,
+            &abolished.code,
+            Some(&BarangayDTO::from(barangay)),
+            Some(&after),
+            change,
+        )?;
+        barangay_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
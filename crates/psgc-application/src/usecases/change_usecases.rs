@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use psgc_domain::{
+    models::change::Change,
+    repositories::{audit_repository::AuditLogRepository, release_repository::ReleaseRepository},
+};
+use tracing::instrument;
+
+use crate::{
+    dto::change_dto::{ChangeDTO, ChangesDTO},
+    errors::UsecaseError,
+    usecases::audit_usecases::parse_since,
+};
+
+/// The most changes served in one response.
+pub const MAX_CHANGES: u64 = 1000;
+
+/// Where a client asks to sync from.
+#[derive(Debug, PartialEq)]
+enum Since {
+    /// The `next` token of an earlier response
+    Position(i64),
+    Time(chrono::DateTime<Utc>),
+}
+
+fn parse_position(since: &str) -> Result<Since, UsecaseError> {
+    match since.parse::<i64>() {
+        Ok(position) if position >= 0 => Ok(Since::Position(position)),
+        Ok(_) => Err(UsecaseError::ValidationError(format!(
+            "{since} is not a token from the changes feed"
+        ))),
+        Err(_) => parse_since(since).map(Since::Time),
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListChangesUsecase<A: AuditLogRepository, R: ReleaseRepository> {
+    audit_repository: Arc<A>,
+    release_repository: Arc<R>,
+}
+
+impl<A: AuditLogRepository, R: ReleaseRepository> ListChangesUsecase<A, R> {
+    pub fn new(audit_repository: Arc<A>, release_repository: Arc<R>) -> Self {
+        Self {
+            audit_repository,
+            release_repository,
+        }
+    }
+
+    fn audit_repository(&self) -> &A {
+        self.audit_repository.as_ref()
+    }
+
+    fn release_repository(&self) -> &R {
+        self.release_repository.as_ref()
+    }
+
+    /// Inserts, updates and deletions of the units of `release` since a token or a time,
+    /// oldest first.
+    ///
+    /// The feed starts when the release was loaded; a client asking from earlier has to
+    /// download the release first.
+    #[instrument(name = "ListChangesUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        release: &str,
+        since: &str,
+        limit: u64,
+    ) -> Result<ChangesDTO, UsecaseError> {
+        if !(1..=MAX_CHANGES).contains(&limit) {
+            return Err(UsecaseError::ValidationError(format!(
+                "limit must be between 1 and {MAX_CHANGES}, got {limit}"
+            )));
+        }
+
+        let loaded = self.release_repository().find(release).await?;
+        let after = match parse_position(since.trim())? {
+            Since::Position(position) => position,
+            Since::Time(time) => self.audit_repository().position_before(time).await?,
+        };
+        if after < loaded.position {
+            return Err(UsecaseError::Conflict(format!(
+                "release {} was loaded at {}, after {since}; download it and sync from then",
+                loaded.id,
+                loaded.loaded_at.to_rfc3339()
+            )));
+        }
+
+        let mut entries = self
+            .audit_repository()
+            .list_unit_changes(release, after, limit + 1)
+            .await?;
+        let has_more = entries.len() as u64 > limit;
+        entries.truncate(limit as usize);
+
+        let next = entries
+            .last()
+            .and_then(|last| last.position)
+            .unwrap_or(after);
+        let changes: Vec<ChangeDTO> = entries
+            .into_iter()
+            .map(|entry| Change::from(entry).into())
+            .collect();
+
+        Ok(ChangesDTO {
+            release: loaded.id,
+            changes,
+            next: next.to_string(),
+            has_more,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_tokens_and_times() {
+        assert_eq!(parse_position("42").unwrap(), Since::Position(42));
+
+        let Since::Time(since) = parse_position("2024-07-01").unwrap() else {
+            panic!("2024-07-01 is a date");
+        };
+        assert_eq!(since.to_rfc3339(), "2024-07-01T00:00:00+00:00");
+
+        assert!(parse_position("-1").is_err());
+        assert!(parse_position(&uuid::Uuid::now_v7().to_string()).is_err());
+        assert!(parse_position("yesterday").is_err());
+    }
+}
@@ -1,10 +1,31 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::city_repository::CityRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::city::City,
+    repositories::{
+        city_repository::CityRepository, province_repository::ProvinceRepository,
+        region_repository::RegionRepository,
+    },
+};
+use tracing::instrument;
 
 use crate::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO, city_dto::CityDTO},
+    dto::{
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        barangay_dto::BarangayDTO,
+        city_dto::{CityDTO, CityInputDTO},
+    },
     errors::UsecaseError,
+    usecases::{
+        names,
+        writes::{
+            audit_entry, ensure_can_abolish, ensure_code_free, ensure_no_children,
+            ensure_same_region, ensure_valid_to_kept, parent, pick_slug, validate_unit,
+            validate_validity,
+        },
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -21,6 +42,7 @@ impl<C: CityRepository> GetCityByCodeUsecase<C> {
         self.city_repository.as_ref()
     }
 
+    #[instrument(name = "GetCityByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<CityDTO, UsecaseError> {
         let city_repository = self.city_repository();
         let city = city_repository.find_by_code(code).await?;
@@ -29,6 +51,60 @@ impl<C: CityRepository> GetCityByCodeUsecase<C> {
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct GetCityBySlugUsecase<C: CityRepository> {
+    city_repository: Arc<C>,
+}
+
+impl<C: CityRepository> GetCityBySlugUsecase<C> {
+    pub fn new(city_repository: Arc<C>) -> Self {
+        Self { city_repository }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    #[instrument(name = "GetCityBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<CityDTO, UsecaseError> {
+        let city_repository = self.city_repository();
+        let city = city_repository.find_by_slug(slug).await?;
+
+        Ok(city.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct FindCityByNameUsecase<C: CityRepository> {
+    city_repository: Arc<C>,
+}
+
+impl<C: CityRepository> FindCityByNameUsecase<C> {
+    pub fn new(city_repository: Arc<C>) -> Self {
+        Self { city_repository }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    #[instrument(name = "FindCityByNameUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<NameLookupDTO<CityDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let city_repository = self.city_repository();
+        let matches =
+            names::list_by_name(name, async |name| city_repository.list_by_name(name).await)
+                .await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListCitiesUsecase<C: CityRepository> {
     city_repository: Arc<C>,
@@ -43,6 +119,7 @@ impl<C: CityRepository> ListCitiesUsecase<C> {
         self.city_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -69,6 +146,7 @@ impl<C: CityRepository> ListCitiesByRegionUsecase<C> {
         self.city_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, region_code: &str) -> Result<Vec<CityDTO>, UsecaseError> {
         let city_repository = self.city_repository();
         let cities = city_repository.list_by_region_code(region_code).await?;
@@ -92,6 +170,7 @@ impl<C: CityRepository> ListCitiesByProvinceUsecase<C> {
         self.city_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesByProvinceUsecase::execute", skip(self))]
     pub async fn execute(&self, province_code: &str) -> Result<Vec<CityDTO>, UsecaseError> {
         let city_repository = self.city_repository();
         let cities = city_repository.list_by_province_code(province_code).await?;
@@ -115,6 +194,7 @@ impl<C: CityRepository> ListBarangaysByCityUsecase<C> {
         self.city_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysByCityUsecase::execute", skip(self))]
     pub async fn execute(&self, city_code: &str) -> Result<Vec<BarangayDTO>, UsecaseError> {
         let city_repository = self.city_repository();
         let barangays = city_repository.list_barangays(city_code).await?;
@@ -123,3 +203,253 @@ impl<C: CityRepository> ListBarangaysByCityUsecase<C> {
         Ok(barangay_dtos)
     }
 }
+
+/// Builds the city to save from `input`, keeping the id, creation time and (unless the
+/// name changed) the slug of the `existing` city being updated. The province, if any,
+/// has to be in the city's region.
+async fn build_city<C: CityRepository, R: RegionRepository, P: ProvinceRepository>(
+    city_repository: &C,
+    region_repository: &R,
+    province_repository: &P,
+    existing: Option<&City>,
+    input: CityInputDTO,
+) -> Result<City, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    let name = input.name.trim().to_string();
+    let id = existing.map(|c| c.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        city_repository.find_by_code(&input.code).await,
+        id,
+        "city",
+        &input.code,
+    )?;
+
+    let region = parent(
+        region_repository.find_by_code(&input.region_code).await,
+        "region",
+        &input.region_code,
+    )?;
+    let province = match input.province_code.as_deref() {
+        Some(code) => {
+            let province = parent(
+                province_repository.find_by_code(code).await,
+                "province",
+                code,
+            )?;
+            ensure_same_region(
+                "province",
+                code,
+                Some(province.region_id),
+                Some(region.id),
+                &format!("region {}", region.code),
+            )?;
+            Some(province)
+        }
+        None => None,
+    };
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            let qualifier = province.as_ref().map(|p| &p.slug).unwrap_or(&region.slug);
+            pick_slug(
+                &name,
+                Some(qualifier),
+                &input.code,
+                id,
+                async |slug: &str| city_repository.find_by_slug(slug).await,
+            )
+            .await?
+        }
+    };
+
+    Ok(City::builder()
+        .id(id)
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .former_names(names::former_names(existing, &name))
+        .name(name)
+        .slug(slug)
+        .population(input.population)
+        .city_class(input.city_class)
+        .income_class(input.income_class)
+        .region_id(region.id)
+        .maybe_province_id(province.map(|p| p.id))
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|c| c.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateCityUsecase<C: CityRepository, R: RegionRepository, P: ProvinceRepository> {
+    city_repository: Arc<C>,
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+}
+
+impl<C: CityRepository, R: RegionRepository, P: ProvinceRepository> CreateCityUsecase<C, R, P> {
+    pub fn new(
+        city_repository: Arc<C>,
+        region_repository: Arc<R>,
+        province_repository: Arc<P>,
+    ) -> Self {
+        Self {
+            city_repository,
+            region_repository,
+            province_repository,
+        }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateCityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: CityInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<CityDTO, UsecaseError> {
+        let city = build_city(
+            self.city_repository(),
+            self.region_repository(),
+            self.province_repository(),
+            None,
+            input,
+        )
+        .await?;
+
+        let created = CityDTO::from(city.clone());
+        let audit = audit_entry("city", city.id, &city.code, None, Some(&created), change)?;
+        self.city_repository().create(&city, &audit).await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateCityUsecase<C: CityRepository, R: RegionRepository, P: ProvinceRepository> {
+    city_repository: Arc<C>,
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+}
+
+impl<C: CityRepository, R: RegionRepository, P: ProvinceRepository> UpdateCityUsecase<C, R, P> {
+    pub fn new(
+        city_repository: Arc<C>,
+        region_repository: Arc<R>,
+        province_repository: Arc<P>,
+    ) -> Self {
+        Self {
+            city_repository,
+            region_repository,
+            province_repository,
+        }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateCityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: CityInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<CityDTO, UsecaseError> {
+        let existing = self.city_repository().find_by_code(code).await?;
+        ensure_valid_to_kept("city", code, &existing, input.valid_to)?;
+        let city = build_city(
+            self.city_repository(),
+            self.region_repository(),
+            self.province_repository(),
+            Some(&existing),
+            input,
+        )
+        .await?;
+
+        let updated = CityDTO::from(city.clone());
+        let audit = audit_entry(
+            "city",
+            city.id,
+            &city.code,
+            Some(&CityDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        self.city_repository().update(&city, &audit).await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishCityUsecase<C: CityRepository> {
+    city_repository: Arc<C>,
+}
+
+impl<C: CityRepository> AbolishCityUsecase<C> {
+    pub fn new(city_repository: Arc<C>) -> Self {
+        Self { city_repository }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    /// Only cities without barangays can be abolished. The city is kept, with `valid_to`
+    /// as the day it was abolished, and returned as it is now.
+    #[instrument(name = "AbolishCityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<CityDTO, UsecaseError> {
+        let city_repository = self.city_repository();
+        let city = city_repository.find_by_code(code).await?;
+        ensure_can_abolish("city", &city.code, &city, valid_to)?;
+
+        let barangays = city_repository.list_barangays(&city.code).await?;
+        ensure_no_children("city", &city.code, &[("barangays", barangays.len())])?;
+
+        let abolished = City {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..city.clone()
+        };
+        let after = CityDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "city",
+            abolished.id,
+            &abolished.code,
+            Some(&CityDTO::from(city)),
+            Some(&after),
+            change,
+        )?;
+        city_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
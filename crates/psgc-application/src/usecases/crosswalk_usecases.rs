@@ -0,0 +1,473 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use chrono::Utc;
+use psgc_domain::{
+    models::{
+        crosswalk::{CodeResolution, CrosswalkEntry, MappingType},
+        lineage::LineageDirection,
+        release_diff::ReleaseUnit,
+    },
+    repositories::{
+        crosswalk_repository::CrosswalkRepository, lineage_repository::LineageRepository,
+    },
+};
+use tracing::instrument;
+
+use crate::{
+    dto::{
+        audit_dto::ChangeContextDTO,
+        crosswalk_dto::{CrosswalkDTO, CrosswalkEntryDTO, CrosswalkEntryInputDTO},
+    },
+    errors::UsecaseError,
+    usecases::{
+        lineage_usecases::walk_lineages,
+        writes::{audit_entry, validate_code},
+    },
+};
+
+/// Enough for a large CSV import in one request, while keeping a request bounded.
+const MAX_CODES: usize = 5000;
+
+/// Each unit once, in the order first seen.
+fn distinct_units<'a>(units: impl IntoIterator<Item = &'a ReleaseUnit>) -> Vec<ReleaseUnit> {
+    let mut seen = HashSet::new();
+
+    units
+        .into_iter()
+        .filter(|unit| seen.insert(unit.code.clone()))
+        .cloned()
+        .collect()
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ResolveCodesUsecase<C: CrosswalkRepository, L: LineageRepository> {
+    crosswalk_repository: Arc<C>,
+    lineage_repository: Arc<L>,
+}
+
+impl<C: CrosswalkRepository, L: LineageRepository> ResolveCodesUsecase<C, L> {
+    pub fn new(crosswalk_repository: Arc<C>, lineage_repository: Arc<L>) -> Self {
+        Self {
+            crosswalk_repository,
+            lineage_repository,
+        }
+    }
+
+    fn crosswalk_repository(&self) -> &C {
+        self.crosswalk_repository.as_ref()
+    }
+
+    fn lineage_repository(&self) -> &L {
+        self.lineage_repository.as_ref()
+    }
+
+    /// Resolves each code against `release`: a code still in use is identical; otherwise
+    /// the curated crosswalk is used, and failing that the code's recorded successors.
+    /// Codes that resolve to nothing, including ones that aren't PSGC codes, are reported
+    /// rather than failing the batch.
+    #[instrument(name = "ResolveCodesUsecase::execute", skip(self, codes), fields(codes = codes.len()))]
+    pub async fn execute(
+        &self,
+        release: &str,
+        codes: Vec<String>,
+    ) -> Result<CrosswalkDTO, UsecaseError> {
+        if codes.is_empty() || codes.len() > MAX_CODES {
+            return Err(UsecaseError::ValidationError(format!(
+                "give between 1 and {MAX_CODES} codes, got {}",
+                codes.len()
+            )));
+        }
+
+        let codes: Vec<String> = codes.iter().map(|code| code.trim().to_string()).collect();
+        let mut seen = HashSet::new();
+        let mut pending: Vec<String> = codes
+            .iter()
+            .filter(|code| validate_code("code", code).is_ok() && seen.insert(code.as_str()))
+            .cloned()
+            .collect();
+        let mut resolved: HashMap<String, (MappingType, Vec<ReleaseUnit>)> = HashMap::new();
+
+        let in_use = self
+            .crosswalk_repository()
+            .find_current_units(release, &pending)
+            .await?;
+        for (code, unit) in in_use {
+            resolved.insert(code, (MappingType::Identical, vec![unit]));
+        }
+        pending.retain(|code| !resolved.contains_key(code));
+
+        let entries = self
+            .crosswalk_repository()
+            .list_by_historical_codes(&pending)
+            .await?;
+        let targets: Vec<String> = entries
+            .iter()
+            .map(|entry| entry.current_code.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let target_units = self
+            .crosswalk_repository()
+            .find_current_units(release, &targets)
+            .await?;
+        for entry in &entries {
+            let Some(unit) = target_units.get(&entry.current_code) else {
+                continue;
+            };
+            let (mapping_type, units) = resolved
+                .entry(entry.historical_code.clone())
+                .or_insert((entry.mapping_type, vec![]));
+            *mapping_type = (*mapping_type).max(entry.mapping_type);
+            units.push(unit.clone());
+        }
+        pending.retain(|code| !resolved.contains_key(code));
+
+        // Every remaining code's lineage at once, then the units all of it leads to
+        let walks = walk_lineages(
+            self.lineage_repository(),
+            &pending,
+            LineageDirection::Successors,
+        )
+        .await?;
+        let successors: Vec<String> = walks
+            .iter()
+            .flatten()
+            .map(|step| step.event.successor_code.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let successor_units = self
+            .crosswalk_repository()
+            .find_current_units(release, &successors)
+            .await?;
+
+        for (code, steps) in pending.into_iter().zip(walks) {
+            let mut units = distinct_units(
+                steps
+                    .iter()
+                    .filter_map(|step| successor_units.get(&step.event.successor_code)),
+            );
+            if units.is_empty() {
+                continue;
+            }
+
+            units.sort_by(|a, b| a.code.cmp(&b.code));
+            let events: Vec<_> = steps.into_iter().map(|step| step.event).collect();
+            resolved.insert(code, (MappingType::of_lineage(&events, units.len()), units));
+        }
+
+        let results: Vec<CodeResolution> = codes
+            .into_iter()
+            .map(|code| match resolved.get(&code) {
+                Some((mapping_type, units)) => CodeResolution {
+                    mapping_type: Some(*mapping_type),
+                    current: distinct_units(units),
+                    code,
+                },
+                None => CodeResolution {
+                    code,
+                    mapping_type: None,
+                    current: vec![],
+                },
+            })
+            .collect();
+
+        Ok(CrosswalkDTO {
+            release: release.to_string(),
+            unresolved: results.iter().filter(|r| r.mapping_type.is_none()).count(),
+            results: results.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct RecordCrosswalkEntryUsecase<C: CrosswalkRepository> {
+    crosswalk_repository: Arc<C>,
+}
+
+impl<C: CrosswalkRepository> RecordCrosswalkEntryUsecase<C> {
+    pub fn new(crosswalk_repository: Arc<C>) -> Self {
+        Self {
+            crosswalk_repository,
+        }
+    }
+
+    fn crosswalk_repository(&self) -> &C {
+        self.crosswalk_repository.as_ref()
+    }
+
+    /// The current code must belong to a unit of `release` that hasn't been abolished.
+    #[instrument(name = "RecordCrosswalkEntryUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        release: &str,
+        input: CrosswalkEntryInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<CrosswalkEntryDTO, UsecaseError> {
+        let crosswalk_repository = self.crosswalk_repository();
+        let historical_code = input.historical_code.trim().to_string();
+        let current_code = input.current_code.trim().to_string();
+        validate_code("historical code", &historical_code)?;
+        validate_code("current code", &current_code)?;
+        if historical_code == current_code {
+            return Err(UsecaseError::ValidationError(format!(
+                "{historical_code} can't be mapped to itself"
+            )));
+        }
+
+        let mapping_type: MappingType = input
+            .mapping_type
+            .trim()
+            .parse()
+            .map_err(UsecaseError::ValidationError)?;
+        let current = crosswalk_repository
+            .find_current_units(release, std::slice::from_ref(&current_code))
+            .await?;
+        if current.is_empty() {
+            return Err(UsecaseError::ValidationError(format!(
+                "current code {current_code} is not a unit in use in release {release}"
+            )));
+        }
+
+        let entry = CrosswalkEntry::builder()
+            .id(uuid::Uuid::now_v7())
+            .historical_code(historical_code)
+            .current_code(current_code)
+            .mapping_type(mapping_type)
+            .created_at(Utc::now())
+            .build();
+
+        let recorded = CrosswalkEntryDTO::from(entry.clone());
+        let audit = audit_entry(
+            "crosswalk",
+            entry.id,
+            &entry.historical_code,
+            None,
+            Some(&recorded),
+            change,
+        )?;
+        crosswalk_repository.create(&entry, &audit).await?;
+
+        Ok(recorded)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct DeleteCrosswalkEntryUsecase<C: CrosswalkRepository> {
+    crosswalk_repository: Arc<C>,
+}
+
+impl<C: CrosswalkRepository> DeleteCrosswalkEntryUsecase<C> {
+    pub fn new(crosswalk_repository: Arc<C>) -> Self {
+        Self {
+            crosswalk_repository,
+        }
+    }
+
+    fn crosswalk_repository(&self) -> &C {
+        self.crosswalk_repository.as_ref()
+    }
+
+    #[instrument(name = "DeleteCrosswalkEntryUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        id: uuid::Uuid,
+        change: &ChangeContextDTO,
+    ) -> Result<CrosswalkEntryDTO, UsecaseError> {
+        let crosswalk_repository = self.crosswalk_repository();
+        let entry = crosswalk_repository.find_by_id(id).await?;
+
+        let deleted = CrosswalkEntryDTO::from(entry.clone());
+        let audit = audit_entry(
+            "crosswalk",
+            entry.id,
+            &entry.historical_code,
+            Some(&deleted),
+            None,
+            change,
+        )?;
+        crosswalk_repository.delete(entry.id, &audit).await?;
+
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use psgc_domain::{
+        errors::RepositoryError,
+        models::{audit::AuditEntry, lineage::LineageEvent, lineage::LineageEventType},
+    };
+
+    use super::*;
+
+    /// Units in use and lineage events, counting the queries made of them.
+    #[derive(Default)]
+    struct Recorded {
+        current: Vec<&'static str>,
+        events: Vec<(&'static str, &'static str, LineageEventType)>,
+        unit_queries: AtomicUsize,
+        lineage_queries: AtomicUsize,
+    }
+
+    impl CrosswalkRepository for Recorded {
+        async fn find_by_id(&self, _id: uuid::Uuid) -> Result<CrosswalkEntry, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_historical_codes(
+            &self,
+            _codes: &[String],
+        ) -> Result<Vec<CrosswalkEntry>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn find_current_units(
+            &self,
+            _release: &str,
+            codes: &[String],
+        ) -> Result<HashMap<String, ReleaseUnit>, RepositoryError> {
+            self.unit_queries.fetch_add(1, Ordering::SeqCst);
+
+            Ok(codes
+                .iter()
+                .filter(|code| self.current.contains(&code.as_str()))
+                .map(|code| {
+                    let unit = ReleaseUnit::builder()
+                        .level("municipality".to_string())
+                        .code(code.clone())
+                        .name(code.clone())
+                        .build();
+                    (code.clone(), unit)
+                })
+                .collect())
+        }
+
+        async fn create(
+            &self,
+            _entry: &CrosswalkEntry,
+            _audit: &AuditEntry,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            _id: uuid::Uuid,
+            _audit: &AuditEntry,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    impl LineageRepository for Recorded {
+        async fn find_by_id(&self, _id: uuid::Uuid) -> Result<LineageEvent, RepositoryError> {
+            Err(RepositoryError::NotFound)
+        }
+
+        async fn list_by_predecessors(
+            &self,
+            codes: &[String],
+        ) -> Result<Vec<LineageEvent>, RepositoryError> {
+            self.lineage_queries.fetch_add(1, Ordering::SeqCst);
+
+            Ok(self
+                .events
+                .iter()
+                .filter(|(predecessor, _, _)| codes.iter().any(|code| code == predecessor))
+                .map(|(predecessor, successor, event_type)| {
+                    LineageEvent::builder()
+                        .id(uuid::Uuid::now_v7())
+                        .predecessor_code(predecessor.to_string())
+                        .successor_code(successor.to_string())
+                        .event_type(*event_type)
+                        .effective_on(chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap())
+                        .created_at(Utc::now())
+                        .build()
+                })
+                .collect())
+        }
+
+        async fn list_by_successors(
+            &self,
+            _codes: &[String],
+        ) -> Result<Vec<LineageEvent>, RepositoryError> {
+            Ok(vec![])
+        }
+
+        async fn create(
+            &self,
+            _event: &LineageEvent,
+            _audit: &AuditEntry,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            _id: uuid::Uuid,
+            _audit: &AuditEntry,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_a_batch_in_one_query_per_generation() {
+        let repository = Arc::new(Recorded {
+            current: vec![
+                "1900000003",
+                "1900000005",
+                "1900000007",
+                "1900000008",
+                "1900000009",
+            ],
+            events: vec![
+                ("1900000001", "1900000002", LineageEventType::CodeChange),
+                ("1900000002", "1900000003", LineageEventType::Rename),
+                ("1900000004", "1900000005", LineageEventType::Merge),
+                ("1900000006", "1900000007", LineageEventType::Split),
+                ("1900000006", "1900000008", LineageEventType::Split),
+            ],
+            ..Default::default()
+        });
+        let codes = [
+            "1900000001",
+            "1900000004",
+            "1900000006",
+            "1900000009",
+            "1999999999",
+        ];
+
+        let resolved = ResolveCodesUsecase::new(repository.clone(), repository.clone())
+            .execute(
+                "2024Q3",
+                codes.iter().map(|code| code.to_string()).collect(),
+            )
+            .await
+            .unwrap();
+
+        let current = |i: usize| -> Vec<String> {
+            resolved.results[i]
+                .current
+                .iter()
+                .map(|unit| unit.code.clone())
+                .collect()
+        };
+        assert_eq!(current(0), vec!["1900000003"]);
+        assert_eq!(current(1), vec!["1900000005"]);
+        assert_eq!(current(2), vec!["1900000007", "1900000008"]);
+        assert_eq!(current(3), vec!["1900000009"]);
+        assert_eq!(resolved.unresolved, 1);
+
+        // The codes in use, the crosswalk's targets and every successor: one query each.
+        // The lineage is two events deep, and a third query finds nothing past it
+        assert_eq!(repository.unit_queries.load(Ordering::SeqCst), 3);
+        assert_eq!(repository.lineage_queries.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::PaginateResult,
+    repositories::{
+        audit_repository::AuditLogRepository, barangay_repository::BarangayRepository,
+        city_repository::CityRepository, district_repository::DistrictRepository,
+        municipality_repository::MunicipalityRepository, province_repository::ProvinceRepository,
+        region_repository::RegionRepository, release_repository::ReleaseRepository,
+    },
+};
+use serde::Serialize;
+use tracing::instrument;
+
+use crate::{
+    dto::{
+        barangay_dto::BarangayDTO,
+        city_dto::CityDTO,
+        dataset_dto::{DatasetDTO, DatasetLevelDTO},
+        district_dto::DistrictDTO,
+        municipality_dto::MunicipalityDTO,
+        province_dto::ProvinceDTO,
+        region_dto::RegionDTO,
+    },
+    errors::UsecaseError,
+};
+
+/// Units read per query while exporting.
+const PAGE_SIZE: u64 = 5000;
+
+/// Every page of `list_all`, as the level's DTOs, ordered by code so an export of the same
+/// data is the same byte for byte.
+async fn all_units<T, D, F>(
+    list_all: impl Fn(u64, u64) -> F,
+) -> Result<Vec<serde_json::Value>, UsecaseError>
+where
+    T: Into<D>,
+    D: Serialize,
+    F: Future<Output = Result<PaginateResult<T>, RepositoryError>>,
+{
+    let mut units = Vec::new();
+    for page in 1.. {
+        let result = list_all(page, PAGE_SIZE).await?;
+        let done = result.records.len() < PAGE_SIZE as usize;
+        for unit in result.records {
+            let unit = serde_json::to_value(unit.into())
+                .map_err(|e| UsecaseError::UnexpectedError(e.to_string()))?;
+            units.push(unit);
+        }
+
+        if done {
+            break;
+        }
+    }
+    units.sort_by(|a, b| a["code"].as_str().cmp(&b["code"].as_str()));
+
+    Ok(units)
+}
+
+fn level(level: &str, table: &str, units: Vec<serde_json::Value>) -> DatasetLevelDTO {
+    DatasetLevelDTO {
+        level: level.to_string(),
+        table: table.to_string(),
+        units,
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ExportDatasetUsecase<R, P, D, C, M, B>
+where
+    R: RegionRepository,
+    P: ProvinceRepository,
+    D: DistrictRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+    B: BarangayRepository,
+{
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+    district_repository: Arc<D>,
+    city_repository: Arc<C>,
+    municipality_repository: Arc<M>,
+    barangay_repository: Arc<B>,
+}
+
+impl<R, P, D, C, M, B> ExportDatasetUsecase<R, P, D, C, M, B>
+where
+    R: RegionRepository,
+    P: ProvinceRepository,
+    D: DistrictRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+    B: BarangayRepository,
+{
+    /// Every current unit of `release` at every level. `sync_token` is where a copy made
+    /// from the export picks up the changes feed.
+    #[instrument(name = "ExportDatasetUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        release: &str,
+        sync_token: Option<String>,
+    ) -> Result<DatasetDTO, UsecaseError> {
+        let levels = vec![
+            level(
+                "region",
+                "regions",
+                all_units::<_, RegionDTO, _>(|page, limit| {
+                    self.region_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+            level(
+                "province",
+                "provinces",
+                all_units::<_, ProvinceDTO, _>(|page, limit| {
+                    self.province_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+            level(
+                "district",
+                "districts",
+                all_units::<_, DistrictDTO, _>(|page, limit| {
+                    self.district_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+            level(
+                "city",
+                "cities",
+                all_units::<_, CityDTO, _>(|page, limit| {
+                    self.city_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+            level(
+                "municipality",
+                "municipalities",
+                all_units::<_, MunicipalityDTO, _>(|page, limit| {
+                    self.municipality_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+            level(
+                "barangay",
+                "barangays",
+                all_units::<_, BarangayDTO, _>(|page, limit| {
+                    self.barangay_repository.list_all(page, limit)
+                })
+                .await?,
+            ),
+        ];
+
+        Ok(DatasetDTO {
+            release: release.to_string(),
+            sync_token,
+            levels,
+        })
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct GetSyncTokenUsecase<A: AuditLogRepository, R: ReleaseRepository> {
+    audit_repository: Arc<A>,
+    release_repository: Arc<R>,
+}
+
+impl<A: AuditLogRepository, R: ReleaseRepository> GetSyncTokenUsecase<A, R> {
+    pub fn new(audit_repository: Arc<A>, release_repository: Arc<R>) -> Self {
+        Self {
+            audit_repository,
+            release_repository,
+        }
+    }
+
+    fn audit_repository(&self) -> &A {
+        self.audit_repository.as_ref()
+    }
+
+    fn release_repository(&self) -> &R {
+        self.release_repository.as_ref()
+    }
+
+    /// The position of the last change to the units of `release`, or of its loading when
+    /// they haven't changed. It moves whenever the release's data does, and a copy of the
+    /// data as it is now can follow the changes feed from it.
+    #[instrument(name = "GetSyncTokenUsecase::execute", skip(self))]
+    pub async fn execute(&self, release: &str) -> Result<String, UsecaseError> {
+        let position = match self.audit_repository().last_unit_change(release).await? {
+            Some(change) => change.position.unwrap_or_default(),
+            None => self.release_repository().find(release).await?.position,
+        };
+
+        Ok(position.to_string())
+    }
+}
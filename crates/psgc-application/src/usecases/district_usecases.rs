@@ -1,13 +1,28 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::district_repository::DistrictRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::district::District,
+    repositories::{
+        barangay_repository::BarangayRepository, district_repository::DistrictRepository,
+        region_repository::RegionRepository,
+    },
+};
+use tracing::instrument;
 
 use crate::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, district_dto::DistrictDTO,
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        city_dto::CityDTO,
+        district_dto::{DistrictDTO, DistrictInputDTO},
         municipality_dto::MunicipalityDTO,
     },
     errors::UsecaseError,
+    usecases::writes::{
+        audit_entry, ensure_can_abolish, ensure_code_free, ensure_no_children,
+        ensure_valid_to_kept, parent, pick_slug, validate_unit, validate_validity,
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -26,6 +41,7 @@ impl<D: DistrictRepository> GetDistrictByCodeUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "GetDistrictByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<DistrictDTO, UsecaseError> {
         let district_repository = self.district_repository();
         let district = district_repository.find_by_code(code).await?;
@@ -34,6 +50,62 @@ impl<D: DistrictRepository> GetDistrictByCodeUsecase<D> {
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct GetDistrictBySlugUsecase<D: DistrictRepository> {
+    district_repository: Arc<D>,
+}
+
+impl<D: DistrictRepository> GetDistrictBySlugUsecase<D> {
+    pub fn new(district_repository: Arc<D>) -> Self {
+        Self {
+            district_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "GetDistrictBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<DistrictDTO, UsecaseError> {
+        let district_repository = self.district_repository();
+        let district = district_repository.find_by_slug(slug).await?;
+
+        Ok(district.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct FindDistrictByNameUsecase<D: DistrictRepository> {
+    district_repository: Arc<D>,
+}
+
+impl<D: DistrictRepository> FindDistrictByNameUsecase<D> {
+    pub fn new(district_repository: Arc<D>) -> Self {
+        Self {
+            district_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "FindDistrictByNameUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<NameLookupDTO<DistrictDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let district_repository = self.district_repository();
+        let matches = district_repository.list_by_name(name).await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListDistrictsUsecase<D: DistrictRepository> {
     district_repository: Arc<D>,
@@ -50,6 +122,7 @@ impl<D: DistrictRepository> ListDistrictsUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "ListDistrictsUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -78,6 +151,7 @@ impl<D: DistrictRepository> ListDistrictsByRegionUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "ListDistrictsByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, region_code: &str) -> Result<Vec<DistrictDTO>, UsecaseError> {
         let district_repository = self.district_repository();
         let districts = district_repository.list_by_region_code(region_code).await?;
@@ -103,6 +177,7 @@ impl<D: DistrictRepository> ListDistrictsByProvinceUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "ListDistrictsByProvinceUsecase::execute", skip(self))]
     pub async fn execute(&self, province_code: &str) -> Result<Vec<DistrictDTO>, UsecaseError> {
         let district_repository = self.district_repository();
         let districts = district_repository
@@ -130,6 +205,7 @@ impl<D: DistrictRepository> ListCitiesByDistrictUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesByDistrictUsecase::execute", skip(self))]
     pub async fn execute(&self, district_code: &str) -> Result<Vec<CityDTO>, UsecaseError> {
         let district_repository = self.district_repository();
         let cities = district_repository.list_cities(district_code).await?;
@@ -155,6 +231,7 @@ impl<D: DistrictRepository> ListMunicipalitiesByDistrictUsecase<D> {
         self.district_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByDistrictUsecase::execute", skip(self))]
     pub async fn execute(&self, district_code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let district_repository = self.district_repository();
         let municipalities = district_repository
@@ -165,3 +242,254 @@ impl<D: DistrictRepository> ListMunicipalitiesByDistrictUsecase<D> {
         Ok(municipality_dtos)
     }
 }
+
+/// Builds the district to save from `input`, keeping the id, creation time and (unless the
+/// name changed) the slug of the `existing` district being updated.
+///
+/// A district with cities or municipalities can't move to another region, since they
+/// carry the region too.
+async fn build_district<D: DistrictRepository, R: RegionRepository>(
+    district_repository: &D,
+    region_repository: &R,
+    existing: Option<&District>,
+    input: DistrictInputDTO,
+) -> Result<District, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    let name = input.name.trim().to_string();
+    let id = existing.map(|d| d.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        district_repository.find_by_code(&input.code).await,
+        id,
+        "district",
+        &input.code,
+    )?;
+
+    let region = parent(
+        region_repository.find_by_code(&input.region_code).await,
+        "region",
+        &input.region_code,
+    )?;
+
+    if let Some(existing) = existing.filter(|d| d.region_id != region.id) {
+        let cities = district_repository.list_cities(&existing.code).await?;
+        let municipalities = district_repository
+            .list_municipalities(&existing.code)
+            .await?;
+        ensure_no_children(
+            "district",
+            &existing.code,
+            &[
+                ("cities", cities.len()),
+                ("municipalities", municipalities.len()),
+            ],
+        )?;
+    }
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            pick_slug(
+                &name,
+                Some(&region.slug),
+                &input.code,
+                id,
+                async |slug: &str| district_repository.find_by_slug(slug).await,
+            )
+            .await?
+        }
+    };
+
+    Ok(District::builder()
+        .id(id)
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .name(name)
+        .slug(slug)
+        .population(input.population)
+        .region_id(region.id)
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|d| d.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateDistrictUsecase<D: DistrictRepository, R: RegionRepository> {
+    district_repository: Arc<D>,
+    region_repository: Arc<R>,
+}
+
+impl<D: DistrictRepository, R: RegionRepository> CreateDistrictUsecase<D, R> {
+    pub fn new(district_repository: Arc<D>, region_repository: Arc<R>) -> Self {
+        Self {
+            district_repository,
+            region_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateDistrictUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: DistrictInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<DistrictDTO, UsecaseError> {
+        let district = build_district(
+            self.district_repository(),
+            self.region_repository(),
+            None,
+            input,
+        )
+        .await?;
+
+        let created = DistrictDTO::from(district.clone());
+        let audit = audit_entry(
+            "district",
+            district.id,
+            &district.code,
+            None,
+            Some(&created),
+            change,
+        )?;
+        self.district_repository().create(&district, &audit).await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateDistrictUsecase<D: DistrictRepository, R: RegionRepository> {
+    district_repository: Arc<D>,
+    region_repository: Arc<R>,
+}
+
+impl<D: DistrictRepository, R: RegionRepository> UpdateDistrictUsecase<D, R> {
+    pub fn new(district_repository: Arc<D>, region_repository: Arc<R>) -> Self {
+        Self {
+            district_repository,
+            region_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateDistrictUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: DistrictInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<DistrictDTO, UsecaseError> {
+        let existing = self.district_repository().find_by_code(code).await?;
+        ensure_valid_to_kept("district", code, &existing, input.valid_to)?;
+        let district = build_district(
+            self.district_repository(),
+            self.region_repository(),
+            Some(&existing),
+            input,
+        )
+        .await?;
+
+        let updated = DistrictDTO::from(district.clone());
+        let audit = audit_entry(
+            "district",
+            district.id,
+            &district.code,
+            Some(&DistrictDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        self.district_repository().update(&district, &audit).await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishDistrictUsecase<D: DistrictRepository, B: BarangayRepository> {
+    district_repository: Arc<D>,
+    barangay_repository: Arc<B>,
+}
+
+impl<D: DistrictRepository, B: BarangayRepository> AbolishDistrictUsecase<D, B> {
+    pub fn new(district_repository: Arc<D>, barangay_repository: Arc<B>) -> Self {
+        Self {
+            district_repository,
+            barangay_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    /// Only districts without cities, municipalities or barangays can be abolished. The district is kept, with `valid_to`
+    /// as the day it was abolished, and returned as it is now.
+    #[instrument(name = "AbolishDistrictUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<DistrictDTO, UsecaseError> {
+        let district_repository = self.district_repository();
+        let district = district_repository.find_by_code(code).await?;
+        ensure_can_abolish("district", &district.code, &district, valid_to)?;
+
+        let cities = district_repository.list_cities(&district.code).await?;
+        let municipalities = district_repository
+            .list_municipalities(&district.code)
+            .await?;
+        let barangays = self
+            .barangay_repository()
+            .list_by_district_code(&district.code)
+            .await?;
+        ensure_no_children(
+            "district",
+            &district.code,
+            &[
+                ("cities", cities.len()),
+                ("municipalities", municipalities.len()),
+                ("barangays", barangays.len()),
+            ],
+        )?;
+
+        let abolished = District {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..district.clone()
+        };
+        let after = DistrictDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "district",
+            abolished.id,
+            &abolished.code,
+            Some(&DistrictDTO::from(district)),
+            Some(&after),
+            change,
+        )?;
+        district_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    models::island_group::IslandGroup,
+    repositories::{
+        barangay_repository::BarangayRepository, city_repository::CityRepository,
+        district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
+        province_repository::ProvinceRepository, region_repository::RegionRepository,
+    },
+};
+use tracing::instrument;
+
+use crate::{
+    dto::{
+        PaginateResponseDTO, barangay_dto::BarangayDTO, city_dto::CityDTO,
+        district_dto::DistrictDTO, island_group_dto::IslandGroupDTO,
+        municipality_dto::MunicipalityDTO, province_dto::ProvinceDTO, region_dto::RegionDTO,
+    },
+    errors::UsecaseError,
+};
+
+pub fn parse_island_group(id: &str) -> Result<IslandGroup, UsecaseError> {
+    id.parse::<IslandGroup>()
+        .map_err(|e| UsecaseError::ValidationError(e.to_string()))
+}
+
+#[derive(Debug, Default)]
+pub struct ListIslandGroupsUsecase;
+
+impl ListIslandGroupsUsecase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self) -> Vec<IslandGroupDTO> {
+        IslandGroup::ALL.into_iter().map(|g| g.into()).collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GetIslandGroupUsecase;
+
+impl GetIslandGroupUsecase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn execute(&self, id: &str) -> Result<IslandGroupDTO, UsecaseError> {
+        IslandGroup::ALL
+            .into_iter()
+            .find(|g| g.id().eq_ignore_ascii_case(id.trim()))
+            .map(|g| g.into())
+            .ok_or(UsecaseError::NotFound)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListRegionsByIslandGroupUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> ListRegionsByIslandGroupUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "ListRegionsByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<RegionDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let region_repository = self.region_repository();
+        let records = region_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListProvincesByIslandGroupUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> ListProvincesByIslandGroupUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "ListProvincesByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<ProvinceDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let province_repository = self.province_repository();
+        let records = province_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListDistrictsByIslandGroupUsecase<D: DistrictRepository> {
+    district_repository: Arc<D>,
+}
+
+impl<D: DistrictRepository> ListDistrictsByIslandGroupUsecase<D> {
+    pub fn new(district_repository: Arc<D>) -> Self {
+        Self {
+            district_repository,
+        }
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "ListDistrictsByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<DistrictDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let district_repository = self.district_repository();
+        let records = district_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListCitiesByIslandGroupUsecase<C: CityRepository> {
+    city_repository: Arc<C>,
+}
+
+impl<C: CityRepository> ListCitiesByIslandGroupUsecase<C> {
+    pub fn new(city_repository: Arc<C>) -> Self {
+        Self { city_repository }
+    }
+
+    fn city_repository(&self) -> &C {
+        self.city_repository.as_ref()
+    }
+
+    #[instrument(name = "ListCitiesByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<CityDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let city_repository = self.city_repository();
+        let records = city_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListMunicipalitiesByIslandGroupUsecase<M: MunicipalityRepository> {
+    municipality_repository: Arc<M>,
+}
+
+impl<M: MunicipalityRepository> ListMunicipalitiesByIslandGroupUsecase<M> {
+    pub fn new(municipality_repository: Arc<M>) -> Self {
+        Self {
+            municipality_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    #[instrument(name = "ListMunicipalitiesByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<MunicipalityDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let municipality_repository = self.municipality_repository();
+        let records = municipality_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListBarangaysByIslandGroupUsecase<B: BarangayRepository> {
+    barangay_repository: Arc<B>,
+}
+
+impl<B: BarangayRepository> ListBarangaysByIslandGroupUsecase<B> {
+    pub fn new(barangay_repository: Arc<B>) -> Self {
+        Self {
+            barangay_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    #[instrument(name = "ListBarangaysByIslandGroupUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        island_group: &str,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResponseDTO<BarangayDTO>, UsecaseError> {
+        let island_group = parse_island_group(island_group)?;
+        let barangay_repository = self.barangay_repository();
+        let records = barangay_repository
+            .list_by_island_group(island_group, page, limit)
+            .await?;
+
+        Ok(records.into())
+    }
+}
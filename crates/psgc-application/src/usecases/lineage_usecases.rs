@@ -0,0 +1,225 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use chrono::Utc;
+use psgc_domain::{
+    models::lineage::{LineageDirection, LineageEvent, LineageEventType, LineageStep, LineageWalk},
+    repositories::lineage_repository::LineageRepository,
+};
+use tracing::instrument;
+
+use crate::{
+    dto::{
+        audit_dto::ChangeContextDTO,
+        lineage_dto::{LineageDTO, LineageEventDTO, LineageEventInputDTO},
+    },
+    errors::UsecaseError,
+    usecases::writes::{audit_entry, validate_code},
+};
+
+/// Follows the lineage of `code` in `direction` to the end.
+pub(crate) async fn walk_lineage<L: LineageRepository>(
+    lineage_repository: &L,
+    code: &str,
+    direction: LineageDirection,
+) -> Result<Vec<LineageStep>, UsecaseError> {
+    let mut steps = walk_lineages(lineage_repository, &[code.to_string()], direction).await?;
+
+    Ok(steps.pop().unwrap_or_default())
+}
+
+/// Follows the lineage of each of `codes` in `direction` to the end, in the order given.
+/// The walks advance together, so a batch takes one query per generation rather than a
+/// walk of queries per code.
+pub(crate) async fn walk_lineages<L: LineageRepository>(
+    lineage_repository: &L,
+    codes: &[String],
+    direction: LineageDirection,
+) -> Result<Vec<Vec<LineageStep>>, UsecaseError> {
+    let mut walks: Vec<LineageWalk> = codes
+        .iter()
+        .map(|code| LineageWalk::new(code, direction))
+        .collect();
+
+    loop {
+        let frontier: Vec<String> = walks
+            .iter()
+            .flat_map(|walk| walk.frontier())
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if frontier.is_empty() {
+            break;
+        }
+
+        let events = match direction {
+            LineageDirection::Successors => {
+                lineage_repository.list_by_predecessors(&frontier).await?
+            }
+            LineageDirection::Predecessors => {
+                lineage_repository.list_by_successors(&frontier).await?
+            }
+        };
+        let mut by_code: HashMap<&str, Vec<&LineageEvent>> = HashMap::new();
+        for event in &events {
+            let from = match direction {
+                LineageDirection::Successors => &event.predecessor_code,
+                LineageDirection::Predecessors => &event.successor_code,
+            };
+            by_code.entry(from.as_str()).or_default().push(event);
+        }
+
+        for walk in walks.iter_mut().filter(|walk| !walk.frontier().is_empty()) {
+            let found = walk
+                .frontier()
+                .iter()
+                .filter_map(|code| by_code.get(code.as_str()))
+                .flatten()
+                .map(|event| (*event).clone())
+                .collect();
+            walk.advance(found);
+        }
+    }
+
+    Ok(walks.into_iter().map(LineageWalk::into_steps).collect())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct TraceLineageUsecase<L: LineageRepository> {
+    lineage_repository: Arc<L>,
+}
+
+impl<L: LineageRepository> TraceLineageUsecase<L> {
+    pub fn new(lineage_repository: Arc<L>) -> Self {
+        Self { lineage_repository }
+    }
+
+    fn lineage_repository(&self) -> &L {
+        self.lineage_repository.as_ref()
+    }
+
+    /// Every event that led away from `code`, or to it, followed through the codes those
+    /// events lead to. A code with no recorded lineage has no events.
+    #[instrument(name = "TraceLineageUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        direction: LineageDirection,
+    ) -> Result<LineageDTO, UsecaseError> {
+        let code = code.trim();
+        let steps = walk_lineage(self.lineage_repository(), code, direction).await?;
+
+        Ok(LineageDTO {
+            code: code.to_string(),
+            events: steps.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct RecordLineageEventUsecase<L: LineageRepository> {
+    lineage_repository: Arc<L>,
+}
+
+impl<L: LineageRepository> RecordLineageEventUsecase<L> {
+    pub fn new(lineage_repository: Arc<L>) -> Self {
+        Self { lineage_repository }
+    }
+
+    fn lineage_repository(&self) -> &L {
+        self.lineage_repository.as_ref()
+    }
+
+    /// Only a rename or a conversion can keep the code.
+    #[instrument(name = "RecordLineageEventUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: LineageEventInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<LineageEventDTO, UsecaseError> {
+        let predecessor_code = input.predecessor_code.trim().to_string();
+        let successor_code = input.successor_code.trim().to_string();
+        validate_code("predecessor code", &predecessor_code)?;
+        validate_code("successor code", &successor_code)?;
+
+        let event_type: LineageEventType = input
+            .event_type
+            .trim()
+            .parse()
+            .map_err(UsecaseError::ValidationError)?;
+        let keeps_code = matches!(
+            event_type,
+            LineageEventType::Rename | LineageEventType::Conversion
+        );
+        if predecessor_code == successor_code && !keeps_code {
+            return Err(UsecaseError::ValidationError(format!(
+                "a {} needs different predecessor and successor codes",
+                event_type.as_str()
+            )));
+        }
+
+        let event = LineageEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .predecessor_code(predecessor_code)
+            .successor_code(successor_code)
+            .event_type(event_type)
+            .effective_on(input.effective_on)
+            .created_at(Utc::now())
+            .build();
+
+        let recorded = LineageEventDTO::from(event.clone());
+        let audit = audit_entry(
+            "lineage",
+            event.id,
+            &event.predecessor_code,
+            None,
+            Some(&recorded),
+            change,
+        )?;
+        self.lineage_repository().create(&event, &audit).await?;
+
+        Ok(recorded)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct DeleteLineageEventUsecase<L: LineageRepository> {
+    lineage_repository: Arc<L>,
+}
+
+impl<L: LineageRepository> DeleteLineageEventUsecase<L> {
+    pub fn new(lineage_repository: Arc<L>) -> Self {
+        Self { lineage_repository }
+    }
+
+    fn lineage_repository(&self) -> &L {
+        self.lineage_repository.as_ref()
+    }
+
+    /// Removes an event recorded by mistake.
+    #[instrument(name = "DeleteLineageEventUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        id: uuid::Uuid,
+        change: &ChangeContextDTO,
+    ) -> Result<LineageEventDTO, UsecaseError> {
+        let lineage_repository = self.lineage_repository();
+        let event = lineage_repository.find_by_id(id).await?;
+
+        let deleted = LineageEventDTO::from(event.clone());
+        let audit = audit_entry(
+            "lineage",
+            event.id,
+            &event.predecessor_code,
+            Some(&deleted),
+            None,
+            change,
+        )?;
+        lineage_repository.delete(event.id, &audit).await?;
+
+        Ok(deleted)
+    }
+}
@@ -1,6 +1,18 @@
+pub mod api_key_usecases;
+pub mod audit_usecases;
 pub mod barangay_usecases;
+pub mod change_usecases;
 pub mod city_usecases;
+pub mod crosswalk_usecases;
+pub mod dataset_usecases;
 pub mod district_usecases;
+pub mod island_group_usecases;
+pub mod lineage_usecases;
 pub mod municipality_usecases;
+mod names;
+pub mod option_usecases;
 pub mod province_usecases;
 pub mod region_usecases;
+pub mod release_usecases;
+pub mod stats_usecases;
+mod writes;
@@ -1,10 +1,31 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::municipality_repository::MunicipalityRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::municipality::Municipality,
+    repositories::{
+        district_repository::DistrictRepository, municipality_repository::MunicipalityRepository,
+        province_repository::ProvinceRepository, region_repository::RegionRepository,
+    },
+};
+use tracing::instrument;
 
 use crate::{
-    dto::{PaginateResponseDTO, barangay_dto::BarangayDTO, municipality_dto::MunicipalityDTO},
+    dto::{
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        barangay_dto::BarangayDTO,
+        municipality_dto::{MunicipalityDTO, MunicipalityInputDTO},
+    },
     errors::UsecaseError,
+    usecases::{
+        names,
+        writes::{
+            audit_entry, ensure_can_abolish, ensure_code_free, ensure_no_children,
+            ensure_same_region, ensure_valid_to_kept, parent, pick_slug, validate_unit,
+            validate_validity,
+        },
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -23,6 +44,7 @@ impl<M: MunicipalityRepository> GetMunicipalityByCodeUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "GetMunicipalityByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<MunicipalityDTO, UsecaseError> {
         let municipality_repository = self.municipality_repository();
         let municipality = municipality_repository.find_by_code(code).await?;
@@ -31,6 +53,68 @@ impl<M: MunicipalityRepository> GetMunicipalityByCodeUsecase<M> {
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct GetMunicipalityBySlugUsecase<M: MunicipalityRepository> {
+    municipality_repository: Arc<M>,
+}
+
+impl<M: MunicipalityRepository> GetMunicipalityBySlugUsecase<M> {
+    pub fn new(municipality_repository: Arc<M>) -> Self {
+        Self {
+            municipality_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    #[instrument(name = "GetMunicipalityBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<MunicipalityDTO, UsecaseError> {
+        let municipality_repository = self.municipality_repository();
+        let municipality = municipality_repository.find_by_slug(slug).await?;
+
+        Ok(municipality.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct FindMunicipalityByNameUsecase<M: MunicipalityRepository> {
+    municipality_repository: Arc<M>,
+}
+
+impl<M: MunicipalityRepository> FindMunicipalityByNameUsecase<M> {
+    pub fn new(municipality_repository: Arc<M>) -> Self {
+        Self {
+            municipality_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    #[instrument(name = "FindMunicipalityByNameUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        name: &str,
+    ) -> Result<NameLookupDTO<MunicipalityDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let municipality_repository = self.municipality_repository();
+        let matches = names::list_by_name(name, async |name| {
+            municipality_repository.list_by_name(name).await
+        })
+        .await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListMunicipalitiesUsecase<M: MunicipalityRepository> {
     municipality_repository: Arc<M>,
@@ -47,6 +131,7 @@ impl<M: MunicipalityRepository> ListMunicipalitiesUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -75,6 +160,7 @@ impl<M: MunicipalityRepository> ListMunicipalitiesByRegionUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, region_code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let municipality_repository = self.municipality_repository();
         let municipalities = municipality_repository
@@ -102,6 +188,7 @@ impl<M: MunicipalityRepository> ListMunicipalitiesByProvinceUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByProvinceUsecase::execute", skip(self))]
     pub async fn execute(&self, province_code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let municipality_repository = self.municipality_repository();
         let municipalities = municipality_repository
@@ -129,6 +216,7 @@ impl<M: MunicipalityRepository> ListMunicipalitiesByDistrictUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByDistrictUsecase::execute", skip(self))]
     pub async fn execute(&self, district_code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let municipality_repository = self.municipality_repository();
         let municipalities = municipality_repository
@@ -156,6 +244,7 @@ impl<M: MunicipalityRepository> ListBarangaysByMunicipalityUsecase<M> {
         self.municipality_repository.as_ref()
     }
 
+    #[instrument(name = "ListBarangaysByMunicipalityUsecase::execute", skip(self))]
     pub async fn execute(&self, municipality_code: &str) -> Result<Vec<BarangayDTO>, UsecaseError> {
         let municipality_repository = self.municipality_repository();
         let barangays = municipality_repository
@@ -166,3 +255,329 @@ impl<M: MunicipalityRepository> ListBarangaysByMunicipalityUsecase<M> {
         Ok(barangay_dtos)
     }
 }
+
+/// Builds the municipality to save from `input`, keeping the id, creation time, links to
+/// sub-municipalities and (unless the name changed) the slug of the `existing` municipality
+/// being updated. The province and district, if any, have to be in its region.
+async fn build_municipality<
+    M: MunicipalityRepository,
+    R: RegionRepository,
+    P: ProvinceRepository,
+    D: DistrictRepository,
+>(
+    municipality_repository: &M,
+    region_repository: &R,
+    province_repository: &P,
+    district_repository: &D,
+    existing: Option<&Municipality>,
+    input: MunicipalityInputDTO,
+) -> Result<Municipality, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    let name = input.name.trim().to_string();
+    let id = existing.map(|m| m.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        municipality_repository.find_by_code(&input.code).await,
+        id,
+        "municipality",
+        &input.code,
+    )?;
+
+    let region = parent(
+        region_repository.find_by_code(&input.region_code).await,
+        "region",
+        &input.region_code,
+    )?;
+    let in_region = format!("region {}", region.code);
+    let province = match input.province_code.as_deref() {
+        Some(code) => {
+            let province = parent(
+                province_repository.find_by_code(code).await,
+                "province",
+                code,
+            )?;
+            ensure_same_region(
+                "province",
+                code,
+                Some(province.region_id),
+                Some(region.id),
+                &in_region,
+            )?;
+            Some(province)
+        }
+        None => None,
+    };
+    let district = match input.district_code.as_deref() {
+        Some(code) => {
+            let district = parent(
+                district_repository.find_by_code(code).await,
+                "district",
+                code,
+            )?;
+            ensure_same_region(
+                "district",
+                code,
+                Some(district.region_id),
+                Some(region.id),
+                &in_region,
+            )?;
+            Some(district)
+        }
+        None => None,
+    };
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            let qualifier = province.as_ref().map(|p| &p.slug).unwrap_or(&region.slug);
+            pick_slug(
+                &name,
+                Some(qualifier),
+                &input.code,
+                id,
+                async |slug: &str| municipality_repository.find_by_slug(slug).await,
+            )
+            .await?
+        }
+    };
+
+    Ok(Municipality::builder()
+        .id(id)
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .former_names(names::former_names(existing, &name))
+        .name(name)
+        .slug(slug)
+        .population(input.population)
+        .income_class(input.income_class)
+        .region_id(region.id)
+        .maybe_province_id(province.map(|p| p.id))
+        .maybe_district_id(district.map(|d| d.id))
+        .maybe_sub_municipality_id(existing.and_then(|m| m.sub_municipality_id))
+        .maybe_barangay_id(existing.and_then(|m| m.barangay_id))
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|m| m.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateMunicipalityUsecase<
+    M: MunicipalityRepository,
+    R: RegionRepository,
+    P: ProvinceRepository,
+    D: DistrictRepository,
+> {
+    municipality_repository: Arc<M>,
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+    district_repository: Arc<D>,
+}
+
+impl<M: MunicipalityRepository, R: RegionRepository, P: ProvinceRepository, D: DistrictRepository>
+    CreateMunicipalityUsecase<M, R, P, D>
+{
+    pub fn new(
+        municipality_repository: Arc<M>,
+        region_repository: Arc<R>,
+        province_repository: Arc<P>,
+        district_repository: Arc<D>,
+    ) -> Self {
+        Self {
+            municipality_repository,
+            region_repository,
+            province_repository,
+            district_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateMunicipalityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: MunicipalityInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<MunicipalityDTO, UsecaseError> {
+        let municipality = build_municipality(
+            self.municipality_repository(),
+            self.region_repository(),
+            self.province_repository(),
+            self.district_repository(),
+            None,
+            input,
+        )
+        .await?;
+
+        let created = MunicipalityDTO::from(municipality.clone());
+        let audit = audit_entry(
+            "municipality",
+            municipality.id,
+            &municipality.code,
+            None,
+            Some(&created),
+            change,
+        )?;
+        self.municipality_repository()
+            .create(&municipality, &audit)
+            .await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateMunicipalityUsecase<
+    M: MunicipalityRepository,
+    R: RegionRepository,
+    P: ProvinceRepository,
+    D: DistrictRepository,
+> {
+    municipality_repository: Arc<M>,
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+    district_repository: Arc<D>,
+}
+
+impl<M: MunicipalityRepository, R: RegionRepository, P: ProvinceRepository, D: DistrictRepository>
+    UpdateMunicipalityUsecase<M, R, P, D>
+{
+    pub fn new(
+        municipality_repository: Arc<M>,
+        region_repository: Arc<R>,
+        province_repository: Arc<P>,
+        district_repository: Arc<D>,
+    ) -> Self {
+        Self {
+            municipality_repository,
+            region_repository,
+            province_repository,
+            district_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateMunicipalityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: MunicipalityInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<MunicipalityDTO, UsecaseError> {
+        let existing = self.municipality_repository().find_by_code(code).await?;
+        ensure_valid_to_kept("municipality", code, &existing, input.valid_to)?;
+        let municipality = build_municipality(
+            self.municipality_repository(),
+            self.region_repository(),
+            self.province_repository(),
+            self.district_repository(),
+            Some(&existing),
+            input,
+        )
+        .await?;
+
+        let updated = MunicipalityDTO::from(municipality.clone());
+        let audit = audit_entry(
+            "municipality",
+            municipality.id,
+            &municipality.code,
+            Some(&MunicipalityDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        self.municipality_repository()
+            .update(&municipality, &audit)
+            .await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishMunicipalityUsecase<M: MunicipalityRepository> {
+    municipality_repository: Arc<M>,
+}
+
+impl<M: MunicipalityRepository> AbolishMunicipalityUsecase<M> {
+    pub fn new(municipality_repository: Arc<M>) -> Self {
+        Self {
+            municipality_repository,
+        }
+    }
+
+    fn municipality_repository(&self) -> &M {
+        self.municipality_repository.as_ref()
+    }
+
+    /// Only municipalities without barangays can be abolished. The municipality is kept, with `valid_to`
+    /// as the day it was abolished, and returned as it is now.
+    #[instrument(name = "AbolishMunicipalityUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<MunicipalityDTO, UsecaseError> {
+        let municipality_repository = self.municipality_repository();
+        let municipality = municipality_repository.find_by_code(code).await?;
+        ensure_can_abolish("municipality", &municipality.code, &municipality, valid_to)?;
+
+        let barangays = municipality_repository
+            .list_barangays(&municipality.code)
+            .await?;
+        ensure_no_children(
+            "municipality",
+            &municipality.code,
+            &[("barangays", barangays.len())],
+        )?;
+
+        let abolished = Municipality {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..municipality.clone()
+        };
+        let after = MunicipalityDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "municipality",
+            abolished.id,
+            &abolished.code,
+            Some(&MunicipalityDTO::from(municipality)),
+            Some(&after),
+            change,
+        )?;
+        municipality_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
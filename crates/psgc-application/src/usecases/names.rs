@@ -0,0 +1,116 @@
+//! Name lookup shared by the by-name usecases of units that can be renamed.
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{barangay::Barangay, city::City, municipality::Municipality, province::Province},
+};
+use psgc_shared::text::{fold_name, split_parenthetical};
+
+/// A unit that keeps the names it had before.
+pub(crate) trait Renamed {
+    fn name(&self) -> &str;
+    fn former_names(&self) -> &[String];
+
+    fn is_known_as(&self, name: &str) -> bool {
+        let name = fold_name(name);
+
+        fold_name(self.name()) == name || self.former_names().iter().any(|n| fold_name(n) == name)
+    }
+}
+
+macro_rules! impl_renamed {
+    ($($model:ty),*) => {
+        $(impl Renamed for $model {
+            fn name(&self) -> &str {
+                &self.name
+            }
+
+            fn former_names(&self) -> &[String] {
+                &self.former_names
+            }
+        })*
+    };
+}
+
+impl_renamed!(Province, City, Municipality, Barangay);
+
+/// The former names of a unit written with `name`: those it had, plus the name it had
+/// until now when this renames it.
+pub(crate) fn former_names<T: Renamed>(existing: Option<&T>, name: &str) -> Vec<String> {
+    let Some(existing) = existing else {
+        return Vec::new();
+    };
+
+    let mut names = existing.former_names().to_vec();
+    let previous = existing.name();
+    if fold_name(previous) != fold_name(name) && !names.iter().any(|n| n == previous) {
+        names.push(previous.to_string());
+    }
+
+    names
+}
+
+/// Units with `name` as their current or former name, through `list_by_name`.
+///
+/// A name written as `"Current (Former)"` that matches nothing as a whole is looked up by
+/// its first part, narrowed to the units also known by the part in brackets when any are,
+/// and failing that by the part in brackets alone.
+pub(crate) async fn list_by_name<T: Renamed>(
+    name: &str,
+    list_by_name: impl AsyncFn(&str) -> Result<Vec<T>, RepositoryError>,
+) -> Result<Vec<T>, RepositoryError> {
+    let matches = list_by_name(name).await?;
+    let Some((current, former)) = split_parenthetical(name).filter(|_| matches.is_empty()) else {
+        return Ok(matches);
+    };
+
+    let matches = list_by_name(current).await?;
+    if matches.is_empty() {
+        return list_by_name(former).await;
+    }
+
+    if !matches.iter().any(|m| m.is_known_as(former)) {
+        return Ok(matches);
+    }
+
+    Ok(matches
+        .into_iter()
+        .filter(|m| m.is_known_as(former))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn barangay(name: &str, former_names: &[&str]) -> Barangay {
+        Barangay::builder()
+            .id(uuid::Uuid::nil())
+            .code("012801001".to_string())
+            .correspondence_code("012801001".to_string())
+            .name(name.to_string())
+            .slug("adams-poblacion".to_string())
+            .population(0)
+            .urban_rural("R".to_string())
+            .former_names(former_names.iter().map(|n| n.to_string()).collect())
+            .created_at(chrono::Utc::now())
+            .updated_at(chrono::Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn keeps_the_previous_name_on_rename() {
+        let existing = barangay("Poblacion", &["Centro"]);
+
+        assert_eq!(
+            former_names(Some(&existing), "Adams Proper"),
+            vec!["Centro".to_string(), "Poblacion".to_string()]
+        );
+        // A change of case or accents isn't a rename
+        assert_eq!(
+            former_names(Some(&existing), "POBLACIÓN"),
+            vec!["Centro".to_string()]
+        );
+        assert!(former_names(None::<&Barangay>, "Poblacion").is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    repositories::{
+        city_repository::CityRepository, municipality_repository::MunicipalityRepository,
+        province_repository::ProvinceRepository, region_repository::RegionRepository,
+    },
+};
+use tracing::instrument;
+
+use crate::{dto::option_dto::OptionDTO, errors::UsecaseError};
+
+/// Lists the direct children of any parent code as compact `{code, name}` pairs,
+/// sorted by name, for cascading address dropdowns.
+///
+/// * region: provinces plus cities without a province (NCR cities, HUCs)
+/// * province: component cities and municipalities
+/// * city / municipality: barangays
+#[derive(Debug, bon::Builder)]
+pub struct ListOptionsByParentUsecase<
+    R: RegionRepository,
+    P: ProvinceRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+> {
+    region_repository: Arc<R>,
+    province_repository: Arc<P>,
+    city_repository: Arc<C>,
+    municipality_repository: Arc<M>,
+}
+
+impl<R, P, C, M> ListOptionsByParentUsecase<R, P, C, M>
+where
+    R: RegionRepository,
+    P: ProvinceRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+{
+    pub fn new(
+        region_repository: Arc<R>,
+        province_repository: Arc<P>,
+        city_repository: Arc<C>,
+        municipality_repository: Arc<M>,
+    ) -> Self {
+        Self {
+            region_repository,
+            province_repository,
+            city_repository,
+            municipality_repository,
+        }
+    }
+
+    #[instrument(name = "ListOptionsByParentUsecase::execute", skip(self))]
+    pub async fn execute(&self, parent: &str) -> Result<Vec<OptionDTO>, UsecaseError> {
+        let parent = parent.trim();
+
+        if parent.is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "parent code is required".to_string(),
+            ));
+        }
+
+        let mut options = match self.list_children(parent).await? {
+            Some(options) => options,
+            None => return Err(UsecaseError::NotFound),
+        };
+
+        options.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.code.cmp(&b.code)));
+
+        Ok(options)
+    }
+
+    async fn list_children(&self, parent: &str) -> Result<Option<Vec<OptionDTO>>, UsecaseError> {
+        // Children are listed by the region's own code, as the parent may be an alias like `NCR`
+        if let Some(region) = found(self.region_repository.find_by_code_or_alias(parent).await)? {
+            let provinces = self.region_repository.list_provinces(&region.code).await?;
+            let cities = self.region_repository.list_cities(&region.code).await?;
+
+            let options = provinces
+                .into_iter()
+                .map(OptionDTO::from)
+                .chain(
+                    cities
+                        .into_iter()
+                        .filter(|c| c.province_id.is_none())
+                        .map(OptionDTO::from),
+                )
+                .collect();
+
+            return Ok(Some(options));
+        }
+
+        if found(self.province_repository.find_by_code(parent).await)?.is_some() {
+            let cities = self.province_repository.list_cities(parent).await?;
+            let municipalities = self.province_repository.list_municipalities(parent).await?;
+
+            let options = cities
+                .into_iter()
+                .map(OptionDTO::from)
+                .chain(municipalities.into_iter().map(OptionDTO::from))
+                .collect();
+
+            return Ok(Some(options));
+        }
+
+        if found(self.city_repository.find_by_code(parent).await)?.is_some() {
+            let barangays = self.city_repository.list_barangays(parent).await?;
+
+            return Ok(Some(barangays.into_iter().map(OptionDTO::from).collect()));
+        }
+
+        if found(self.municipality_repository.find_by_code(parent).await)?.is_some() {
+            let barangays = self.municipality_repository.list_barangays(parent).await?;
+
+            return Ok(Some(barangays.into_iter().map(OptionDTO::from).collect()));
+        }
+
+        Ok(None)
+    }
+}
+
+fn found<T>(result: Result<T, RepositoryError>) -> Result<Option<T>, UsecaseError> {
+    match result {
+        Ok(unit) => Ok(Some(unit)),
+        Err(RepositoryError::NotFound) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -1,13 +1,28 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::province_repository::ProvinceRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::province::Province,
+    repositories::{province_repository::ProvinceRepository, region_repository::RegionRepository},
+};
+use tracing::instrument;
 
 use crate::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
-        province_dto::ProvinceDTO,
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        city_dto::CityDTO,
+        municipality_dto::MunicipalityDTO,
+        province_dto::{ProvinceDTO, ProvinceInputDTO},
     },
     errors::UsecaseError,
+    usecases::{
+        names,
+        writes::{
+            audit_entry, ensure_can_abolish, ensure_code_free, ensure_no_children,
+            ensure_valid_to_kept, parent, pick_slug, validate_unit, validate_validity,
+        },
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -26,6 +41,7 @@ impl<P: ProvinceRepository> GetProvinceByCodeUsecase<P> {
         self.province_repository.as_ref()
     }
 
+    #[instrument(name = "GetProvinceByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<ProvinceDTO, UsecaseError> {
         let province_repository = self.province_repository();
         let province = province_repository.find_by_code(code).await?;
@@ -34,6 +50,65 @@ impl<P: ProvinceRepository> GetProvinceByCodeUsecase<P> {
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct GetProvinceBySlugUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> GetProvinceBySlugUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "GetProvinceBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<ProvinceDTO, UsecaseError> {
+        let province_repository = self.province_repository();
+        let province = province_repository.find_by_slug(slug).await?;
+
+        Ok(province.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct FindProvinceByNameUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> FindProvinceByNameUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "FindProvinceByNameUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<NameLookupDTO<ProvinceDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let province_repository = self.province_repository();
+        let matches = names::list_by_name(name, async |name| {
+            province_repository.list_by_name(name).await
+        })
+        .await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListProvincesUsecase<P: ProvinceRepository> {
     province_repository: Arc<P>,
@@ -50,6 +125,7 @@ impl<P: ProvinceRepository> ListProvincesUsecase<P> {
         self.province_repository.as_ref()
     }
 
+    #[instrument(name = "ListProvincesUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -78,6 +154,7 @@ impl<P: ProvinceRepository> ListProvincesByRegionUsecase<P> {
         self.province_repository.as_ref()
     }
 
+    #[instrument(name = "ListProvincesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, region_code: &str) -> Result<Vec<ProvinceDTO>, UsecaseError> {
         let province_repository = self.province_repository();
         let provinces = province_repository.list_by_region_code(region_code).await?;
@@ -103,6 +180,7 @@ impl<P: ProvinceRepository> ListCitiesByProvinceUsecase<P> {
         self.province_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesByProvinceUsecase::execute", skip(self))]
     pub async fn execute(&self, province_code: &str) -> Result<Vec<CityDTO>, UsecaseError> {
         let province_repository = self.province_repository();
         let cities = province_repository.list_cities(province_code).await?;
@@ -128,6 +206,7 @@ impl<P: ProvinceRepository> ListMunicipalitiesByProvinceUsecase<P> {
         self.province_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByProvinceUsecase::execute", skip(self))]
     pub async fn execute(&self, province_code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let province_repository = self.province_repository();
         let municipalities = province_repository
@@ -138,3 +217,245 @@ impl<P: ProvinceRepository> ListMunicipalitiesByProvinceUsecase<P> {
         Ok(municipality_dtos)
     }
 }
+
+/// Builds the province to save from `input`, keeping the id, creation time and (unless the
+/// name changed) the slug of the `existing` province being updated.
+///
+/// A province with cities or municipalities can't move to another region, since they
+/// carry the region too.
+async fn build_province<P: ProvinceRepository, R: RegionRepository>(
+    province_repository: &P,
+    region_repository: &R,
+    existing: Option<&Province>,
+    input: ProvinceInputDTO,
+) -> Result<Province, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    let name = input.name.trim().to_string();
+    let id = existing.map(|p| p.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        province_repository.find_by_code(&input.code).await,
+        id,
+        "province",
+        &input.code,
+    )?;
+
+    let region = parent(
+        region_repository.find_by_code(&input.region_code).await,
+        "region",
+        &input.region_code,
+    )?;
+
+    if let Some(existing) = existing.filter(|p| p.region_id != region.id) {
+        let cities = province_repository.list_cities(&existing.code).await?;
+        let municipalities = province_repository
+            .list_municipalities(&existing.code)
+            .await?;
+        ensure_no_children(
+            "province",
+            &existing.code,
+            &[
+                ("cities", cities.len()),
+                ("municipalities", municipalities.len()),
+            ],
+        )?;
+    }
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            pick_slug(
+                &name,
+                Some(&region.slug),
+                &input.code,
+                id,
+                async |slug: &str| province_repository.find_by_slug(slug).await,
+            )
+            .await?
+        }
+    };
+
+    Ok(Province::builder()
+        .id(id)
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .former_names(names::former_names(existing, &name))
+        .name(name)
+        .slug(slug)
+        .population(input.population)
+        .income_class(input.income_class)
+        .region_id(region.id)
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|p| p.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateProvinceUsecase<P: ProvinceRepository, R: RegionRepository> {
+    province_repository: Arc<P>,
+    region_repository: Arc<R>,
+}
+
+impl<P: ProvinceRepository, R: RegionRepository> CreateProvinceUsecase<P, R> {
+    pub fn new(province_repository: Arc<P>, region_repository: Arc<R>) -> Self {
+        Self {
+            province_repository,
+            region_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateProvinceUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: ProvinceInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<ProvinceDTO, UsecaseError> {
+        let province = build_province(
+            self.province_repository(),
+            self.region_repository(),
+            None,
+            input,
+        )
+        .await?;
+
+        let created = ProvinceDTO::from(province.clone());
+        let audit = audit_entry(
+            "province",
+            province.id,
+            &province.code,
+            None,
+            Some(&created),
+            change,
+        )?;
+        self.province_repository().create(&province, &audit).await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateProvinceUsecase<P: ProvinceRepository, R: RegionRepository> {
+    province_repository: Arc<P>,
+    region_repository: Arc<R>,
+}
+
+impl<P: ProvinceRepository, R: RegionRepository> UpdateProvinceUsecase<P, R> {
+    pub fn new(province_repository: Arc<P>, region_repository: Arc<R>) -> Self {
+        Self {
+            province_repository,
+            region_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateProvinceUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: ProvinceInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<ProvinceDTO, UsecaseError> {
+        let existing = self.province_repository().find_by_code(code).await?;
+        ensure_valid_to_kept("province", code, &existing, input.valid_to)?;
+        let province = build_province(
+            self.province_repository(),
+            self.region_repository(),
+            Some(&existing),
+            input,
+        )
+        .await?;
+
+        let updated = ProvinceDTO::from(province.clone());
+        let audit = audit_entry(
+            "province",
+            province.id,
+            &province.code,
+            Some(&ProvinceDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        self.province_repository().update(&province, &audit).await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishProvinceUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> AbolishProvinceUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    /// Only provinces without cities or municipalities can be abolished. The province is kept, with `valid_to`
+    /// as the day it was abolished, and returned as it is now.
+    #[instrument(name = "AbolishProvinceUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<ProvinceDTO, UsecaseError> {
+        let province_repository = self.province_repository();
+        let province = province_repository.find_by_code(code).await?;
+        ensure_can_abolish("province", &province.code, &province, valid_to)?;
+
+        let cities = province_repository.list_cities(&province.code).await?;
+        let municipalities = province_repository
+            .list_municipalities(&province.code)
+            .await?;
+        ensure_no_children(
+            "province",
+            &province.code,
+            &[
+                ("cities", cities.len()),
+                ("municipalities", municipalities.len()),
+            ],
+        )?;
+
+        let abolished = Province {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..province.clone()
+        };
+        let after = ProvinceDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "province",
+            abolished.id,
+            &abolished.code,
+            Some(&ProvinceDTO::from(province)),
+            Some(&after),
+            change,
+        )?;
+        province_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
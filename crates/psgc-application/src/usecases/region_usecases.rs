@@ -1,13 +1,26 @@
 use std::sync::Arc;
 
-use psgc_domain::repositories::region_repository::RegionRepository;
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    models::{island_group::IslandGroup, region::Region},
+    repositories::{district_repository::DistrictRepository, region_repository::RegionRepository},
+};
+use tracing::instrument;
 
 use crate::{
     dto::{
-        PaginateResponseDTO, city_dto::CityDTO, municipality_dto::MunicipalityDTO,
-        province_dto::ProvinceDTO, region_dto::RegionDTO,
+        NameLookupDTO, PaginateResponseDTO,
+        audit_dto::ChangeContextDTO,
+        city_dto::CityDTO,
+        municipality_dto::MunicipalityDTO,
+        province_dto::ProvinceDTO,
+        region_dto::{RegionDTO, RegionInputDTO},
     },
     errors::UsecaseError,
+    usecases::writes::{
+        audit_entry, ensure_can_abolish, ensure_code_free, ensure_no_children,
+        ensure_valid_to_kept, pick_slug, validate_unit, validate_validity,
+    },
 };
 
 #[derive(Debug, bon::Builder)]
@@ -24,14 +37,67 @@ impl<R: RegionRepository> GetRegionByCodeUsecase<R> {
         self.region_repository.as_ref()
     }
 
+    #[instrument(name = "GetRegionByCodeUsecase::execute", skip(self))]
     pub async fn execute(&self, codename: &str) -> Result<RegionDTO, UsecaseError> {
         let region_repository = self.region_repository();
-        let region = region_repository.find_by_code(codename).await?;
+        let region = region_repository.find_by_code_or_alias(codename).await?;
+
+        Ok(region.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct GetRegionBySlugUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> GetRegionBySlugUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "GetRegionBySlugUsecase::execute", skip(self))]
+    pub async fn execute(&self, slug: &str) -> Result<RegionDTO, UsecaseError> {
+        let region_repository = self.region_repository();
+        let region = region_repository.find_by_slug(slug).await?;
 
         Ok(region.into())
     }
 }
 
+#[derive(Debug, bon::Builder)]
+pub struct FindRegionByNameUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> FindRegionByNameUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "FindRegionByNameUsecase::execute", skip(self))]
+    pub async fn execute(&self, name: &str) -> Result<NameLookupDTO<RegionDTO>, UsecaseError> {
+        if name.trim().is_empty() {
+            return Err(UsecaseError::ValidationError(
+                "name is required".to_string(),
+            ));
+        }
+
+        let region_repository = self.region_repository();
+        let matches = region_repository.list_by_name(name).await?;
+
+        NameLookupDTO::from_matches(matches).ok_or(UsecaseError::NotFound)
+    }
+}
+
 #[derive(Debug, bon::Builder)]
 pub struct ListRegionsUsecase<R: RegionRepository> {
     region_repository: Arc<R>,
@@ -46,6 +112,7 @@ impl<R: RegionRepository> ListRegionsUsecase<R> {
         self.region_repository.as_ref()
     }
 
+    #[instrument(name = "ListRegionsUsecase::execute", skip(self))]
     pub async fn execute(
         &self,
         page: u64,
@@ -72,6 +139,7 @@ impl<R: RegionRepository> ListProvincesByRegionUsecase<R> {
         self.region_repository.as_ref()
     }
 
+    #[instrument(name = "ListProvincesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<Vec<ProvinceDTO>, UsecaseError> {
         let region_repository = self.region_repository();
         let provinces = region_repository.list_provinces(code).await?;
@@ -95,6 +163,7 @@ impl<R: RegionRepository> ListCitiesByRegionUsecase<R> {
         self.region_repository.as_ref()
     }
 
+    #[instrument(name = "ListCitiesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<Vec<CityDTO>, UsecaseError> {
         let region_repository = self.region_repository();
         let cities = region_repository.list_cities(code).await?;
@@ -118,6 +187,7 @@ impl<R: RegionRepository> ListMunicipalitiesByRegionUsecase<R> {
         self.region_repository.as_ref()
     }
 
+    #[instrument(name = "ListMunicipalitiesByRegionUsecase::execute", skip(self))]
     pub async fn execute(&self, code: &str) -> Result<Vec<MunicipalityDTO>, UsecaseError> {
         let region_repository = self.region_repository();
         let municipalities = region_repository.list_municipalities(code).await?;
@@ -126,3 +196,200 @@ impl<R: RegionRepository> ListMunicipalitiesByRegionUsecase<R> {
         Ok(municipality_dtos)
     }
 }
+
+/// Builds the region to save from `input`, keeping the id, creation time and (unless the
+/// name changed) the slug of the `existing` region being updated.
+async fn build_region<R: RegionRepository>(
+    region_repository: &R,
+    existing: Option<&Region>,
+    input: RegionInputDTO,
+) -> Result<Region, UsecaseError> {
+    validate_unit(&input.code, &input.correspondence_code, &input.name)?;
+    validate_validity(input.valid_from, input.valid_to)?;
+    let name = input.name.trim().to_string();
+    let id = existing.map(|r| r.id).unwrap_or_else(uuid::Uuid::now_v7);
+
+    ensure_code_free(
+        region_repository.find_by_code(&input.code).await,
+        id,
+        "region",
+        &input.code,
+    )?;
+
+    let slug = match existing {
+        Some(existing) if existing.name == name => existing.slug.clone(),
+        _ => {
+            pick_slug(&name, None, &input.code, id, async |slug: &str| {
+                region_repository.find_by_slug(slug).await
+            })
+            .await?
+        }
+    };
+
+    Ok(Region::builder()
+        .id(id)
+        .maybe_island_group(IslandGroup::from_region_code(&input.code))
+        .code(input.code)
+        .correspondence_code(input.correspondence_code)
+        .name(name)
+        .slug(slug)
+        .designation(input.designation)
+        .population(input.population)
+        .maybe_valid_from(input.valid_from)
+        .maybe_valid_to(input.valid_to)
+        .created_at(existing.map(|r| r.created_at).unwrap_or_else(Utc::now))
+        .updated_at(Utc::now())
+        .build())
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CreateRegionUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> CreateRegionUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "CreateRegionUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        input: RegionInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<RegionDTO, UsecaseError> {
+        let region_repository = self.region_repository();
+        let region = build_region(region_repository, None, input).await?;
+
+        let created = RegionDTO::from(region.clone());
+        let audit = audit_entry(
+            "region",
+            region.id,
+            &region.code,
+            None,
+            Some(&created),
+            change,
+        )?;
+        region_repository.create(&region, &audit).await?;
+
+        Ok(created)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct UpdateRegionUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> UpdateRegionUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "UpdateRegionUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        input: RegionInputDTO,
+        change: &ChangeContextDTO,
+    ) -> Result<RegionDTO, UsecaseError> {
+        let region_repository = self.region_repository();
+        let existing = region_repository.find_by_code(code).await?;
+        ensure_valid_to_kept("region", code, &existing, input.valid_to)?;
+        let region = build_region(region_repository, Some(&existing), input).await?;
+
+        let updated = RegionDTO::from(region.clone());
+        let audit = audit_entry(
+            "region",
+            region.id,
+            &region.code,
+            Some(&RegionDTO::from(existing)),
+            Some(&updated),
+            change,
+        )?;
+        region_repository.update(&region, &audit).await?;
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct AbolishRegionUsecase<R: RegionRepository, D: DistrictRepository> {
+    region_repository: Arc<R>,
+    district_repository: Arc<D>,
+}
+
+impl<R: RegionRepository, D: DistrictRepository> AbolishRegionUsecase<R, D> {
+    pub fn new(region_repository: Arc<R>, district_repository: Arc<D>) -> Self {
+        Self {
+            region_repository,
+            district_repository,
+        }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    fn district_repository(&self) -> &D {
+        self.district_repository.as_ref()
+    }
+
+    /// Only empty regions can be abolished. The region is kept, with `valid_to`
+    /// as the day it was abolished, and returned as it is now.
+    #[instrument(name = "AbolishRegionUsecase::execute", skip(self))]
+    pub async fn execute(
+        &self,
+        code: &str,
+        valid_to: NaiveDate,
+        change: &ChangeContextDTO,
+    ) -> Result<RegionDTO, UsecaseError> {
+        let region_repository = self.region_repository();
+        let region = region_repository.find_by_code(code).await?;
+        ensure_can_abolish("region", &region.code, &region, valid_to)?;
+
+        let provinces = region_repository.list_provinces(&region.code).await?;
+        let districts = self
+            .district_repository()
+            .list_by_region_code(&region.code)
+            .await?;
+        let cities = region_repository.list_cities(&region.code).await?;
+        let municipalities = region_repository.list_municipalities(&region.code).await?;
+        ensure_no_children(
+            "region",
+            &region.code,
+            &[
+                ("provinces", provinces.len()),
+                ("districts", districts.len()),
+                ("cities", cities.len()),
+                ("municipalities", municipalities.len()),
+            ],
+        )?;
+
+        let abolished = Region {
+            valid_to: Some(valid_to),
+            updated_at: Utc::now(),
+            ..region.clone()
+        };
+        let after = RegionDTO::from(abolished.clone());
+        let audit = audit_entry(
+            "region",
+            abolished.id,
+            &abolished.code,
+            Some(&RegionDTO::from(region)),
+            Some(&after),
+            change,
+        )?;
+        region_repository.update(&abolished, &audit).await?;
+
+        Ok(after)
+    }
+}
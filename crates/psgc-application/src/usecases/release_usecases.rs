@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    models::{release::Release, release_diff::ReleaseDiff},
+    repositories::release_repository::ReleaseRepository,
+};
+use tracing::instrument;
+
+use crate::{
+    dto::release_dto::{ReleaseDTO, ReleaseDiffDTO, ReleaseListDTO},
+    errors::UsecaseError,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct ListReleasesUsecase<R: ReleaseRepository> {
+    release_repository: Arc<R>,
+}
+
+impl<R: ReleaseRepository> ListReleasesUsecase<R> {
+    pub fn new(release_repository: Arc<R>) -> Self {
+        Self { release_repository }
+    }
+
+    fn release_repository(&self) -> &R {
+        self.release_repository.as_ref()
+    }
+
+    #[instrument(name = "ListReleasesUsecase::execute", skip(self))]
+    pub async fn execute(&self) -> Result<ReleaseListDTO, UsecaseError> {
+        let release_repository = self.release_repository();
+        let releases = release_repository.list().await?;
+
+        Ok(ReleaseListDTO {
+            latest: releases.first().map(|r| r.id.clone()),
+            releases: releases.into_iter().map(|r| r.into()).collect(),
+        })
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ResolveReleaseUsecase<R: ReleaseRepository> {
+    release_repository: Arc<R>,
+}
+
+impl<R: ReleaseRepository> ResolveReleaseUsecase<R> {
+    pub fn new(release_repository: Arc<R>) -> Self {
+        Self { release_repository }
+    }
+
+    fn release_repository(&self) -> &R {
+        self.release_repository.as_ref()
+    }
+
+    /// The release a request asked for, or the latest one when it didn't ask.
+    #[instrument(name = "ResolveReleaseUsecase::execute", skip(self))]
+    pub async fn execute(&self, requested: Option<&str>) -> Result<ReleaseDTO, UsecaseError> {
+        let release_repository = self.release_repository();
+
+        let release = match requested {
+            Some(id) if !Release::is_valid_id(id) => {
+                return Err(UsecaseError::ValidationError(format!(
+                    "{id} is not a release id; use the year and quarter, e.g. 2024Q3"
+                )));
+            }
+            Some(id) => release_repository.find(id).await?,
+            None => release_repository.latest().await?,
+        };
+
+        Ok(release.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct DiffReleasesUsecase<R: ReleaseRepository> {
+    release_repository: Arc<R>,
+}
+
+impl<R: ReleaseRepository> DiffReleasesUsecase<R> {
+    pub fn new(release_repository: Arc<R>) -> Self {
+        Self { release_repository }
+    }
+
+    fn release_repository(&self) -> &R {
+        self.release_repository.as_ref()
+    }
+
+    /// What changed from release `from` to release `to`; see [`ReleaseDiff::between`] for
+    /// how units are matched.
+    #[instrument(name = "DiffReleasesUsecase::execute", skip(self))]
+    pub async fn execute(&self, from: &str, to: &str) -> Result<ReleaseDiffDTO, UsecaseError> {
+        let release_repository = self.release_repository();
+        let resolve = ResolveReleaseUsecase::new(self.release_repository.clone());
+
+        let from = resolve.execute(Some(from)).await?.id;
+        let to = resolve.execute(Some(to)).await?.id;
+
+        let before = release_repository.units(&from).await?;
+        let after = release_repository.units(&to).await?;
+
+        Ok(ReleaseDiff::between(from, to, before, after).into())
+    }
+}
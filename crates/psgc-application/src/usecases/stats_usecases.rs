@@ -0,0 +1,181 @@
+use std::sync::Arc;
+
+use psgc_domain::repositories::{
+    barangay_repository::BarangayRepository, city_repository::CityRepository,
+    municipality_repository::MunicipalityRepository, province_repository::ProvinceRepository,
+    region_repository::RegionRepository,
+};
+use tracing::instrument;
+
+use crate::{
+    dto::stats_dto::{GroupCountDTO, ProvinceStatsDTO, RegionStatsDTO},
+    errors::UsecaseError,
+};
+
+#[derive(Debug, bon::Builder)]
+pub struct ListRegionStatsUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> ListRegionStatsUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "ListRegionStatsUsecase::execute", skip(self))]
+    pub async fn execute(&self) -> Result<Vec<RegionStatsDTO>, UsecaseError> {
+        let region_repository = self.region_repository();
+        let stats = region_repository.list_stats().await?;
+
+        Ok(stats.into_iter().map(|s| s.into()).collect())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct GetRegionStatsUsecase<R: RegionRepository> {
+    region_repository: Arc<R>,
+}
+
+impl<R: RegionRepository> GetRegionStatsUsecase<R> {
+    pub fn new(region_repository: Arc<R>) -> Self {
+        Self { region_repository }
+    }
+
+    fn region_repository(&self) -> &R {
+        self.region_repository.as_ref()
+    }
+
+    #[instrument(name = "GetRegionStatsUsecase::execute", skip(self))]
+    pub async fn execute(&self, code: &str) -> Result<RegionStatsDTO, UsecaseError> {
+        let region_repository = self.region_repository();
+        let stats = region_repository.get_stats(code).await?;
+
+        Ok(stats.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct ListProvinceStatsUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> ListProvinceStatsUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "ListProvinceStatsUsecase::execute", skip(self))]
+    pub async fn execute(&self) -> Result<Vec<ProvinceStatsDTO>, UsecaseError> {
+        let province_repository = self.province_repository();
+        let stats = province_repository.list_stats().await?;
+
+        Ok(stats.into_iter().map(|s| s.into()).collect())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct GetProvinceStatsUsecase<P: ProvinceRepository> {
+    province_repository: Arc<P>,
+}
+
+impl<P: ProvinceRepository> GetProvinceStatsUsecase<P> {
+    pub fn new(province_repository: Arc<P>) -> Self {
+        Self {
+            province_repository,
+        }
+    }
+
+    fn province_repository(&self) -> &P {
+        self.province_repository.as_ref()
+    }
+
+    #[instrument(name = "GetProvinceStatsUsecase::execute", skip(self))]
+    pub async fn execute(&self, code: &str) -> Result<ProvinceStatsDTO, UsecaseError> {
+        let province_repository = self.province_repository();
+        let stats = province_repository.get_stats(code).await?;
+
+        Ok(stats.into())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CountByIncomeClassUsecase<
+    P: ProvinceRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+> {
+    province_repository: Arc<P>,
+    city_repository: Arc<C>,
+    municipality_repository: Arc<M>,
+}
+
+impl<P, C, M> CountByIncomeClassUsecase<P, C, M>
+where
+    P: ProvinceRepository,
+    C: CityRepository,
+    M: MunicipalityRepository,
+{
+    pub fn new(
+        province_repository: Arc<P>,
+        city_repository: Arc<C>,
+        municipality_repository: Arc<M>,
+    ) -> Self {
+        Self {
+            province_repository,
+            city_repository,
+            municipality_repository,
+        }
+    }
+
+    /// `level` is one of `province`, `city` or `municipality`.
+    #[instrument(name = "CountByIncomeClassUsecase::execute", skip(self))]
+    pub async fn execute(&self, level: &str) -> Result<Vec<GroupCountDTO>, UsecaseError> {
+        let counts = match level.trim().to_ascii_lowercase().as_str() {
+            "province" => self.province_repository.count_by_income_class().await?,
+            "city" => self.city_repository.count_by_income_class().await?,
+            "municipality" => self.municipality_repository.count_by_income_class().await?,
+            other => {
+                return Err(UsecaseError::ValidationError(format!(
+                    "unsupported level {other}, expected province, city or municipality"
+                )));
+            }
+        };
+
+        Ok(counts.into_iter().map(|c| c.into()).collect())
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct CountBarangaysByUrbanRuralUsecase<B: BarangayRepository> {
+    barangay_repository: Arc<B>,
+}
+
+impl<B: BarangayRepository> CountBarangaysByUrbanRuralUsecase<B> {
+    pub fn new(barangay_repository: Arc<B>) -> Self {
+        Self {
+            barangay_repository,
+        }
+    }
+
+    fn barangay_repository(&self) -> &B {
+        self.barangay_repository.as_ref()
+    }
+
+    #[instrument(name = "CountBarangaysByUrbanRuralUsecase::execute", skip(self))]
+    pub async fn execute(&self) -> Result<Vec<GroupCountDTO>, UsecaseError> {
+        let barangay_repository = self.barangay_repository();
+        let counts = barangay_repository.count_by_urban_rural().await?;
+
+        Ok(counts.into_iter().map(|c| c.into()).collect())
+    }
+}
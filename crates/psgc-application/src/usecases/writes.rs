@@ -0,0 +1,305 @@
+//! Checks shared by the admin create, update and delete usecases.
+
+use chrono::{NaiveDate, Utc};
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        audit::AuditEntry, barangay::Barangay, city::City, district::District,
+        municipality::Municipality, province::Province, region::Region, validity::Validity,
+    },
+};
+use psgc_shared::text::slugify;
+use serde::Serialize;
+
+use crate::{dto::audit_dto::ChangeContextDTO, errors::UsecaseError};
+
+/// A unit at any PSGC level, as far as the write checks are concerned.
+pub(crate) trait Unit {
+    fn id(&self) -> uuid::Uuid;
+}
+
+macro_rules! impl_unit {
+    ($($model:ty),*) => {
+        $(impl Unit for $model {
+            fn id(&self) -> uuid::Uuid {
+                self.id
+            }
+        })*
+    };
+}
+
+impl_unit!(Region, Province, District, City, Municipality, Barangay);
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// The PSA files use 9- or 10-digit PSGC codes depending on the level.
+pub(crate) fn validate_code(field: &str, value: &str) -> Result<(), UsecaseError> {
+    if !(9..=10).contains(&value.len()) || !is_digits(value) {
+        return Err(UsecaseError::ValidationError(format!(
+            "{field} {value} is not a 9- or 10-digit PSGC code"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Both codes are PSGC codes, and a unit needs a name.
+pub(crate) fn validate_unit(
+    code: &str,
+    correspondence_code: &str,
+    name: &str,
+) -> Result<(), UsecaseError> {
+    validate_code("code", code)?;
+    validate_code("correspondence code", correspondence_code)?;
+    if name.trim().is_empty() {
+        return Err(UsecaseError::ValidationError(
+            "name must not be empty".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// A unit can't be abolished before it came into existence.
+pub(crate) fn validate_validity(
+    valid_from: Option<NaiveDate>,
+    valid_to: Option<NaiveDate>,
+) -> Result<(), UsecaseError> {
+    match (valid_from, valid_to) {
+        (Some(from), Some(to)) if to <= from => Err(UsecaseError::ValidationError(format!(
+            "valid_to {to} must be after valid_from {from}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// A unit is abolished once, and not before it came into existence.
+pub(crate) fn ensure_can_abolish<T: Validity>(
+    level: &str,
+    code: &str,
+    unit: &T,
+    valid_to: NaiveDate,
+) -> Result<(), UsecaseError> {
+    if let Some(abolished) = unit.valid_to() {
+        return Err(UsecaseError::Conflict(format!(
+            "{level} {code} was already abolished on {abolished}"
+        )));
+    }
+
+    validate_validity(unit.valid_from(), Some(valid_to))
+}
+
+/// A replacement keeps `valid_to` as it is: abolishing goes through `DELETE`, which checks
+/// the unit can be abolished, and an abolition isn't undone.
+pub(crate) fn ensure_valid_to_kept<T: Validity>(
+    level: &str,
+    code: &str,
+    existing: &T,
+    valid_to: Option<NaiveDate>,
+) -> Result<(), UsecaseError> {
+    if existing.valid_to() == valid_to {
+        return Ok(());
+    }
+
+    Err(UsecaseError::ValidationError(format!(
+        "valid_to of {level} {code} can't be changed by an update; abolish it with DELETE instead"
+    )))
+}
+
+/// Fails with a conflict when `existing`, the result of looking the code up, is a unit
+/// other than the one with `id`.
+pub(crate) fn ensure_code_free<T: Unit>(
+    existing: Result<T, RepositoryError>,
+    id: uuid::Uuid,
+    level: &str,
+    code: &str,
+) -> Result<(), UsecaseError> {
+    match existing {
+        Ok(unit) if unit.id() != id => Err(UsecaseError::Conflict(format!(
+            "a {level} with code {code} already exists"
+        ))),
+        Ok(_) | Err(RepositoryError::NotFound) => Ok(()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// A parent looked up by the code given in the request; an unknown code is a bad request
+/// rather than a missing resource.
+pub(crate) fn parent<T>(
+    found: Result<T, RepositoryError>,
+    level: &str,
+    code: &str,
+) -> Result<T, UsecaseError> {
+    match found {
+        Ok(parent) => Ok(parent),
+        Err(RepositoryError::NotFound) => Err(UsecaseError::ValidationError(format!(
+            "unknown {level} code {code}"
+        ))),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Fails when a parent is in a different region than the unit's other parent, described
+/// by `expected`, e.g. `region 1300000000` or `the region of city 1380600000`.
+pub(crate) fn ensure_same_region(
+    level: &str,
+    code: &str,
+    region_id: Option<uuid::Uuid>,
+    expected_region_id: Option<uuid::Uuid>,
+    expected: &str,
+) -> Result<(), UsecaseError> {
+    match (region_id, expected_region_id) {
+        (Some(region_id), Some(expected_region_id)) if region_id != expected_region_id => Err(
+            UsecaseError::ValidationError(format!("{level} {code} is not in {expected}")),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// Fails with a conflict while the unit still has children, which would otherwise be
+/// deleted along with it or left pointing at the wrong parent.
+pub(crate) fn ensure_no_children(
+    level: &str,
+    code: &str,
+    children: &[(&str, usize)],
+) -> Result<(), UsecaseError> {
+    let remaining = children
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(children, count)| format!("{count} {children}"))
+        .collect::<Vec<_>>();
+
+    if remaining.is_empty() {
+        return Ok(());
+    }
+
+    Err(UsecaseError::Conflict(format!(
+        "{level} {code} still has {}; move or abolish them first",
+        remaining.join(" and ")
+    )))
+}
+
+/// The first slug not taken by another unit: the plain name, then the name qualified with
+/// the parent's slug, then with the code, the same order the seeder assigns them in.
+pub(crate) async fn pick_slug<T: Unit>(
+    name: &str,
+    qualifier: Option<&str>,
+    code: &str,
+    id: uuid::Uuid,
+    find_by_slug: impl AsyncFn(&str) -> Result<T, RepositoryError>,
+) -> Result<String, UsecaseError> {
+    let base = slugify(name);
+    let qualified = match qualifier {
+        Some(qualifier) if !qualifier.is_empty() => format!("{base}-{qualifier}"),
+        _ => base.clone(),
+    };
+
+    for slug in [&base, &qualified] {
+        match find_by_slug(slug).await {
+            Ok(unit) if unit.id() != id => {}
+            Ok(_) | Err(RepositoryError::NotFound) => return Ok(slug.clone()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    Ok(format!("{qualified}-{code}"))
+}
+
+/// The audit log entry for a change to a unit, with the unit as it was and as it will be,
+/// both as they're returned by the API. A change has to say why it's being made.
+pub(crate) fn audit_entry<T: Serialize>(
+    entity: &str,
+    entity_id: uuid::Uuid,
+    code: &str,
+    before: Option<&T>,
+    after: Option<&T>,
+    change: &ChangeContextDTO,
+) -> Result<AuditEntry, UsecaseError> {
+    let reason = change.reason.trim();
+    if reason.is_empty() {
+        return Err(UsecaseError::ValidationError(
+            "a reason for the change is required".to_string(),
+        ));
+    }
+
+    let to_json = |unit: Option<&T>| {
+        unit.map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| UsecaseError::UnexpectedError(e.to_string()))
+    };
+
+    Ok(AuditEntry::builder()
+        .id(uuid::Uuid::now_v7())
+        .actor(change.actor.clone())
+        .entity(entity.to_string())
+        .entity_id(entity_id)
+        .code(code.to_string())
+        .maybe_before(to_json(before)?)
+        .maybe_after(to_json(after)?)
+        .reason(reason.to_string())
+        .created_at(Utc::now())
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_codes_and_names() {
+        assert!(validate_unit("1380600000", "133900000", "City of Manila").is_ok());
+        assert!(validate_unit("012800000", "0102800000", "Ilocos Norte").is_ok());
+        assert!(validate_unit("13806000", "133900000", "City of Manila").is_err());
+        assert!(validate_unit("13806000AB", "133900000", "City of Manila").is_err());
+        assert!(validate_unit("1380600000", "", "City of Manila").is_err());
+        assert!(validate_unit("1380600000", "133900000", "  ").is_err());
+    }
+
+    #[test]
+    fn reports_remaining_children() {
+        assert!(ensure_no_children("city", "1380600000", &[("barangays", 0)]).is_ok());
+
+        match ensure_no_children(
+            "province",
+            "0102800000",
+            &[("cities", 1), ("municipalities", 12)],
+        ) {
+            Err(UsecaseError::Conflict(message)) => assert_eq!(
+                message,
+                "province 0102800000 still has 1 cities and 12 municipalities; move or abolish them first"
+            ),
+            other => panic!("expected a conflict, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn keeps_valid_to_on_update() {
+        let today = Utc::now().date_naive();
+        let region = Region::builder()
+            .id(uuid::Uuid::new_v4())
+            .code("1300000000".to_string())
+            .correspondence_code("130000000".to_string())
+            .name("National Capital Region".to_string())
+            .slug("national-capital-region".to_string())
+            .designation("NCR".to_string())
+            .population(13_484_462)
+            .created_at(Utc::now())
+            .updated_at(Utc::now())
+            .build();
+
+        assert!(ensure_valid_to_kept("region", "1300000000", &region, None).is_ok());
+        assert!(matches!(
+            ensure_valid_to_kept("region", "1300000000", &region, Some(today)),
+            Err(UsecaseError::ValidationError(_))
+        ));
+
+        let abolished = Region {
+            valid_to: Some(today),
+            ..region
+        };
+        assert!(ensure_valid_to_kept("region", "1300000000", &abolished, Some(today)).is_ok());
+        assert!(ensure_valid_to_kept("region", "1300000000", &abolished, None).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// A client allowed to call the API. Only the SHA-256 digest of the key is stored; the
+/// key itself is shown once, when it's created.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct ApiKey {
+    pub id: uuid::Uuid,
+    pub name: String,
+    /// First characters of the key, so it can be recognised without being stored
+    pub prefix: String,
+    pub key_hash: String,
+    pub rate_limit_per_minute: u32,
+    /// Requests allowed per UTC day; unlimited when `None`
+    pub daily_quota: Option<u64>,
+    /// Origins the key may be used from; any origin when empty
+    #[builder(default)]
+    pub allowed_origins: Vec<String>,
+    /// Admin keys may also make corrections through `/api/v1/admin`
+    #[builder(default)]
+    pub admin: bool,
+    pub created_at: chrono::DateTime<Utc>,
+    pub revoked_at: Option<chrono::DateTime<Utc>>,
+}
+
+impl ApiKey {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Requests without an `Origin` header, i.e. not from a browser, are always allowed.
+    pub fn allows_origin(&self, origin: Option<&str>) -> bool {
+        match origin {
+            Some(origin) if !self.allowed_origins.is_empty() => self
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed.trim_end_matches('/') == origin.trim_end_matches('/')),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_key(allowed_origins: &[&str]) -> ApiKey {
+        ApiKey::builder()
+            .id(uuid::Uuid::nil())
+            .name("test".to_string())
+            .prefix("psgc_0000".to_string())
+            .key_hash(String::new())
+            .rate_limit_per_minute(60)
+            .allowed_origins(allowed_origins.iter().map(|o| o.to_string()).collect())
+            .created_at(Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn restricts_browser_origins_only_when_configured() {
+        let open = api_key(&[]);
+        assert!(open.allows_origin(Some("https://example.com")));
+        assert!(open.allows_origin(None));
+
+        let restricted = api_key(&["https://psgc.example.com/"]);
+        assert!(restricted.allows_origin(Some("https://psgc.example.com")));
+        assert!(!restricted.allows_origin(Some("https://example.com")));
+        assert!(restricted.allows_origin(None));
+    }
+}
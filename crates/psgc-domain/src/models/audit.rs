@@ -0,0 +1,35 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// One change to the data, written in the same transaction as the change itself.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct AuditEntry {
+    pub id: uuid::Uuid,
+    /// Who made the change: the admin API key's name, or `seeder`
+    pub actor: String,
+    /// What was changed: a PSGC level such as `province`, `lineage` for a lineage event, or
+    /// `dataset` for a seed run
+    pub entity: String,
+    pub entity_id: uuid::Uuid,
+    /// The unit's code after the change, or before it for a deletion
+    pub code: String,
+    /// The unit as it was; `None` when it was created
+    pub before: Option<serde_json::Value>,
+    /// The unit as it is now; `None` when it was deleted
+    pub after: Option<serde_json::Value>,
+    pub reason: Option<String>,
+    /// Where the entry stands in the feed of changes, handed out by the database in the
+    /// order entries commit; `None` until it's stored
+    pub position: Option<i64>,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+impl AuditEntry {
+    pub fn action(&self) -> &'static str {
+        match (&self.before, &self.after) {
+            (None, _) => "create",
+            (Some(_), Some(_)) => "update",
+            (Some(_), None) => "delete",
+        }
+    }
+}
@@ -1,14 +1,20 @@
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 use crate::models::{city::City, district::District, municipality::Municipality};
 
 #[allow(dead_code)]
-#[derive(Debug, bon::Builder)]
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
 pub struct Barangay {
     pub id: uuid::Uuid,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before, from the PSA's `oldName` and renames through the admin API
+    #[builder(default)]
+    #[serde(default)]
+    pub former_names: Vec<String>,
     pub population: u64,
     pub city_id: Option<uuid::Uuid>,
     pub municipality_id: Option<uuid::Uuid>,
@@ -17,6 +23,10 @@ pub struct Barangay {
     pub city: Option<City>,
     pub municipality: Option<Municipality>,
     pub district: Option<District>,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<Utc>,
     pub updated_at: chrono::DateTime<Utc>,
 }
@@ -0,0 +1,126 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::models::audit::AuditEntry;
+
+/// What a client keeping a copy of the data does with a [`Change`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOperation {
+    Insert,
+    Update,
+    /// The unit was abolished or deleted; drop it, on its `valid_to` if it has one
+    Delete,
+}
+
+impl ChangeOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Insert => "insert",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// One write to a unit, read from the audit entry recorded with it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Change {
+    pub id: uuid::Uuid,
+    /// The entry's position in the feed
+    pub position: i64,
+    pub operation: ChangeOperation,
+    /// The PSGC level, e.g. `province`
+    pub level: String,
+    pub unit_id: uuid::Uuid,
+    pub code: String,
+    /// The unit after the change; `None` for a deletion, which is only a tombstone. An
+    /// abolished unit is kept for its `valid_to`, which may be a later day
+    pub unit: Option<serde_json::Value>,
+    pub changed_at: chrono::DateTime<Utc>,
+}
+
+impl From<AuditEntry> for Change {
+    fn from(entry: AuditEntry) -> Self {
+        // Decided by the entry alone, so a change reads the same on every sync whether or
+        // not its `valid_to` has come
+        let abolished = entry
+            .after
+            .as_ref()
+            .and_then(|after| after.get("valid_to"))
+            .is_some_and(|valid_to| !valid_to.is_null());
+        let operation = match (&entry.before, &entry.after) {
+            (_, None) => ChangeOperation::Delete,
+            _ if abolished => ChangeOperation::Delete,
+            (None, Some(_)) => ChangeOperation::Insert,
+            (Some(_), Some(_)) => ChangeOperation::Update,
+        };
+
+        Self {
+            id: entry.id,
+            position: entry.position.unwrap_or_default(),
+            operation,
+            level: entry.entity,
+            unit_id: entry.entity_id,
+            code: entry.code,
+            unit: entry.after,
+            changed_at: entry.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(before: Option<serde_json::Value>, after: Option<serde_json::Value>) -> AuditEntry {
+        AuditEntry::builder()
+            .id(uuid::Uuid::now_v7())
+            .actor("admin".to_string())
+            .entity("municipality".to_string())
+            .entity_id(uuid::Uuid::now_v7())
+            .code("0102801000".to_string())
+            .maybe_before(before)
+            .maybe_after(after)
+            .created_at(Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn reads_changes_from_audit_entries() {
+        let unit = serde_json::json!({"name": "Adams", "valid_to": null});
+        let abolished = serde_json::json!({"name": "Adams", "valid_to": "2024-07-01"});
+
+        let inserted = Change::from(entry(None, Some(unit.clone())));
+        assert_eq!(inserted.operation, ChangeOperation::Insert);
+        assert_eq!(inserted.unit, Some(unit.clone()));
+
+        let updated = Change::from(entry(Some(unit.clone()), Some(unit.clone())));
+        assert_eq!(updated.operation, ChangeOperation::Update);
+
+        let abolition = Change::from(entry(Some(unit.clone()), Some(abolished.clone())));
+        assert_eq!(abolition.operation, ChangeOperation::Delete);
+        assert_eq!(abolition.unit, Some(abolished));
+        assert_eq!(abolition.code, "0102801000");
+
+        let deleted = Change::from(entry(Some(unit), None));
+        assert_eq!(deleted.operation, ChangeOperation::Delete);
+        assert_eq!(deleted.unit, None);
+    }
+
+    #[test]
+    fn reads_an_abolition_the_same_before_and_after_its_day() {
+        let today = Utc::now().date_naive();
+        let unit = serde_json::json!({"name": "Adams", "valid_to": null});
+
+        for valid_to in [today + chrono::Days::new(1), today - chrono::Days::new(1)] {
+            let abolished = serde_json::json!({"name": "Adams", "valid_to": valid_to});
+            let entry = entry(Some(unit.clone()), Some(abolished.clone()));
+
+            let change = Change::from(entry.clone());
+            assert_eq!(change.operation, ChangeOperation::Delete);
+            assert_eq!(change.unit, Some(abolished));
+            assert_eq!(Change::from(entry).operation, change.operation);
+        }
+    }
+}
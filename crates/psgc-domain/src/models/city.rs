@@ -1,14 +1,20 @@
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 use crate::models::{province::Province, region::Region};
 
 #[allow(dead_code)]
-#[derive(Debug, bon::Builder)]
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
 pub struct City {
     pub id: uuid::Uuid,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
+    /// Names the unit had before, from the PSA's `oldName` and renames through the admin API
+    #[builder(default)]
+    #[serde(default)]
+    pub former_names: Vec<String>,
     pub population: u64,
     pub city_class: String,
     pub income_class: String,
@@ -16,6 +22,10 @@ pub struct City {
     pub province_id: Option<uuid::Uuid>,
     pub region: Option<Region>,
     pub province: Option<Province>,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<Utc>,
     pub updated_at: chrono::DateTime<Utc>,
 }
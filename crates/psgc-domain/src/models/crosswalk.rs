@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    lineage::{LineageEvent, LineageEventType},
+    release_diff::ReleaseUnit,
+};
+
+/// How a historical code relates to the current code(s) it resolves to, from the least to
+/// the most disruptive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MappingType {
+    /// The code is still in use
+    Identical,
+    /// The unit carries on under another code or name, or as another kind of unit
+    Renamed,
+    /// The unit became part of another
+    Merged,
+    /// The unit became several
+    Split,
+}
+
+impl MappingType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Identical => "identical",
+            Self::Renamed => "renamed",
+            Self::Merged => "merged",
+            Self::Split => "split",
+        }
+    }
+
+    /// The mapping made by following `events` to `targets` current codes: a split when the
+    /// unit was split or ended up as several, a merge when it was merged, and a rename
+    /// otherwise.
+    pub fn of_lineage(events: &[LineageEvent], targets: usize) -> Self {
+        let happened = |event_type| events.iter().any(|e| e.event_type == event_type);
+
+        if happened(LineageEventType::Split) || targets > 1 {
+            Self::Split
+        } else if happened(LineageEventType::Merge) {
+            Self::Merged
+        } else {
+            Self::Renamed
+        }
+    }
+}
+
+impl FromStr for MappingType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "identical" => Ok(Self::Identical),
+            "renamed" => Ok(Self::Renamed),
+            "split" => Ok(Self::Split),
+            "merged" => Ok(Self::Merged),
+            _ => Err(format!(
+                "{value} is not a mapping type; use identical, renamed, split or merged"
+            )),
+        }
+    }
+}
+
+/// A curated mapping from a code of an earlier PSGC edition to a code in use now. A code
+/// that maps to several has one per current code.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct CrosswalkEntry {
+    pub id: uuid::Uuid,
+    pub historical_code: String,
+    pub current_code: String,
+    pub mapping_type: MappingType,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+/// What a historical code resolves to. `mapping_type` is `None`, and `current` empty, when
+/// nothing is known about the code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeResolution {
+    pub code: String,
+    pub mapping_type: Option<MappingType>,
+    pub current: Vec<ReleaseUnit>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn event(event_type: LineageEventType) -> LineageEvent {
+        LineageEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .predecessor_code("1903800000".to_string())
+            .successor_code("1908800000".to_string())
+            .event_type(event_type)
+            .effective_on(NaiveDate::from_ymd_opt(2022, 9, 18).unwrap())
+            .created_at(Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn types_mappings_by_lineage() {
+        let rename = event(LineageEventType::Rename);
+        let code_change = event(LineageEventType::CodeChange);
+        let split = event(LineageEventType::Split);
+        let merge = event(LineageEventType::Merge);
+
+        assert_eq!(
+            MappingType::of_lineage(&[rename.clone(), code_change], 1),
+            MappingType::Renamed
+        );
+        assert_eq!(
+            MappingType::of_lineage(&[rename.clone(), merge.clone()], 1),
+            MappingType::Merged
+        );
+        assert_eq!(
+            MappingType::of_lineage(&[merge, split], 1),
+            MappingType::Split
+        );
+        assert_eq!(MappingType::of_lineage(&[rename], 2), MappingType::Split);
+    }
+}
@@ -1,17 +1,23 @@
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
 
 use crate::models::region::Region;
 
 #[allow(dead_code)]
-#[derive(Debug, bon::Builder)]
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
 pub struct District {
     pub id: uuid::Uuid,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
     pub population: u64,
     pub region_id: uuid::Uuid,
     pub region: Option<Region>,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<Utc>,
     pub updated_at: chrono::DateTime<Utc>,
 }
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// The three island groups PSGC places above regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IslandGroup {
+    Luzon,
+    Visayas,
+    Mindanao,
+}
+
+impl IslandGroup {
+    pub const ALL: [IslandGroup; 3] = [Self::Luzon, Self::Visayas, Self::Mindanao];
+
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Luzon => "luzon",
+            Self::Visayas => "visayas",
+            Self::Mindanao => "mindanao",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Luzon => "Luzon",
+            Self::Visayas => "Visayas",
+            Self::Mindanao => "Mindanao",
+        }
+    }
+
+    /// Derives the island group from the two-digit region prefix of a PSGC code.
+    pub fn from_region_code(code: &str) -> Option<Self> {
+        match code.get(0..2)? {
+            "01" | "02" | "03" | "04" | "05" | "13" | "14" | "17" => Some(Self::Luzon),
+            "06" | "07" | "08" | "18" => Some(Self::Visayas),
+            "09" | "10" | "11" | "12" | "16" | "19" => Some(Self::Mindanao),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IslandGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown island group {0}")]
+pub struct UnknownIslandGroup(pub String);
+
+impl FromStr for IslandGroup {
+    type Err = UnknownIslandGroup;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|group| group.id().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| UnknownIslandGroup(s.to_string()))
+    }
+}
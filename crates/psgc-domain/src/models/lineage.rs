@@ -0,0 +1,219 @@
+use std::{collections::HashSet, str::FromStr};
+
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineageEventType {
+    /// A municipality became a city, or the reverse
+    Conversion,
+    Split,
+    Merge,
+    Rename,
+    CodeChange,
+}
+
+impl LineageEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Conversion => "conversion",
+            Self::Split => "split",
+            Self::Merge => "merge",
+            Self::Rename => "rename",
+            Self::CodeChange => "code_change",
+        }
+    }
+}
+
+impl FromStr for LineageEventType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "conversion" => Ok(Self::Conversion),
+            "split" => Ok(Self::Split),
+            "merge" => Ok(Self::Merge),
+            "rename" => Ok(Self::Rename),
+            "code_change" => Ok(Self::CodeChange),
+            _ => Err(format!(
+                "{value} is not a lineage event; use conversion, split, merge, rename or code_change"
+            )),
+        }
+    }
+}
+
+/// One link between a unit and a unit that replaced it. A split has one per successor and
+/// a merge one per predecessor.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct LineageEvent {
+    pub id: uuid::Uuid,
+    pub predecessor_code: String,
+    /// The same as `predecessor_code` when the unit kept its code, e.g. on a rename
+    pub successor_code: String,
+    pub event_type: LineageEventType,
+    /// The day the change took effect
+    pub effective_on: NaiveDate,
+    pub created_at: chrono::DateTime<Utc>,
+}
+
+/// Which way a [`LineageWalk`] follows events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineageDirection {
+    Successors,
+    Predecessors,
+}
+
+/// An event reached by a [`LineageWalk`], `depth` events away from where it started.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageStep {
+    pub depth: u32,
+    pub event: LineageEvent,
+}
+
+/// Follows lineage from one code, a generation at a time, until no events are left. Each
+/// code is followed once, so a code that comes back, such as one kept through a rename,
+/// doesn't loop.
+#[derive(Debug)]
+pub struct LineageWalk {
+    direction: LineageDirection,
+    seen_codes: HashSet<String>,
+    seen_events: HashSet<uuid::Uuid>,
+    frontier: Vec<String>,
+    depth: u32,
+    steps: Vec<LineageStep>,
+}
+
+impl LineageWalk {
+    pub fn new(code: &str, direction: LineageDirection) -> Self {
+        Self {
+            direction,
+            seen_codes: HashSet::from([code.to_string()]),
+            seen_events: HashSet::new(),
+            frontier: vec![code.to_string()],
+            depth: 0,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn direction(&self) -> LineageDirection {
+        self.direction
+    }
+
+    /// The codes whose events come next; empty once the walk is over.
+    pub fn frontier(&self) -> &[String] {
+        &self.frontier
+    }
+
+    /// Takes the events of the current frontier and moves on to the codes they lead to.
+    pub fn advance(&mut self, events: Vec<LineageEvent>) {
+        self.depth += 1;
+        let mut frontier = Vec::new();
+
+        for event in events {
+            if !self.seen_events.insert(event.id) {
+                continue;
+            }
+
+            let next = match self.direction {
+                LineageDirection::Successors => &event.successor_code,
+                LineageDirection::Predecessors => &event.predecessor_code,
+            };
+            if self.seen_codes.insert(next.clone()) {
+                frontier.push(next.clone());
+            }
+            self.steps.push(LineageStep {
+                depth: self.depth,
+                event,
+            });
+        }
+
+        self.frontier = frontier;
+    }
+
+    /// Nearest first, then by date and codes.
+    pub fn into_steps(mut self) -> Vec<LineageStep> {
+        self.steps.sort_by(|a, b| {
+            (
+                a.depth,
+                a.event.effective_on,
+                &a.event.predecessor_code,
+                &a.event.successor_code,
+            )
+                .cmp(&(
+                    b.depth,
+                    b.event.effective_on,
+                    &b.event.predecessor_code,
+                    &b.event.successor_code,
+                ))
+        });
+
+        self.steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(from: &str, to: &str, event_type: LineageEventType, day: u32) -> LineageEvent {
+        LineageEvent::builder()
+            .id(uuid::Uuid::now_v7())
+            .predecessor_code(from.to_string())
+            .successor_code(to.to_string())
+            .event_type(event_type)
+            .effective_on(NaiveDate::from_ymd_opt(2022, 9, day).unwrap())
+            .created_at(Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn follows_lineage_transitively() {
+        let events = [
+            event("1903800000", "1908700000", LineageEventType::Split, 1),
+            event("1903800000", "1908800000", LineageEventType::Split, 1),
+            event("1908800000", "1908800000", LineageEventType::Rename, 2),
+            event("1908801000", "1908800000", LineageEventType::Merge, 3),
+        ];
+        let walk = |code: &str, direction| {
+            let mut walk = LineageWalk::new(code, direction);
+            while !walk.frontier().is_empty() {
+                let found = events
+                    .iter()
+                    .filter(|e| match walk.direction() {
+                        LineageDirection::Successors => {
+                            walk.frontier().contains(&e.predecessor_code)
+                        }
+                        LineageDirection::Predecessors => {
+                            walk.frontier().contains(&e.successor_code)
+                        }
+                    })
+                    .cloned()
+                    .collect();
+                walk.advance(found);
+            }
+            walk.into_steps()
+                .into_iter()
+                .map(|step| (step.depth, step.event.event_type))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            walk("1903800000", LineageDirection::Successors),
+            vec![
+                (1, LineageEventType::Split),
+                (1, LineageEventType::Split),
+                (2, LineageEventType::Rename),
+            ]
+        );
+        assert_eq!(
+            walk("1908800000", LineageDirection::Predecessors),
+            vec![
+                (1, LineageEventType::Split),
+                (1, LineageEventType::Rename),
+                (1, LineageEventType::Merge),
+            ]
+        );
+        assert!(walk("0100000000", LineageDirection::Successors).is_empty());
+    }
+}
@@ -1,11 +1,22 @@
+use serde::{Deserialize, Serialize};
+pub mod api_key;
+pub mod audit;
 pub mod barangay;
+pub mod change;
 pub mod city;
+pub mod crosswalk;
 pub mod district;
+pub mod island_group;
+pub mod lineage;
 pub mod municipality;
 pub mod province;
 pub mod region;
+pub mod release;
+pub mod release_diff;
+pub mod stats;
+pub mod validity;
 
-#[derive(Debug, bon::Builder)]
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
 pub struct PaginateResult<T> {
     pub records: Vec<T>,
     /// total num
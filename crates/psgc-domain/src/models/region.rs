@@ -1,14 +1,136 @@
 use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::models::island_group::IslandGroup;
 
 #[allow(dead_code)]
-#[derive(Debug, bon::Builder)]
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
 pub struct Region {
     pub id: uuid::Uuid,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: String,
     pub designation: String,
+    pub island_group: Option<IslandGroup>,
     pub population: u64,
+    /// First day the unit existed, unset when that predates the records
+    pub valid_from: Option<chrono::NaiveDate>,
+    /// The day the unit was abolished, unset while it still exists
+    pub valid_to: Option<chrono::NaiveDate>,
     pub created_at: chrono::DateTime<Utc>,
     pub updated_at: chrono::DateTime<Utc>,
 }
+
+/// Aliases that can't be derived from a region's designation or name, keyed like [`alias_key`].
+const EXTRA_ALIASES: &[(&str, &str)] = &[
+    ("metromanila", "ncr"),
+    ("cordillera", "car"),
+    ("ivb", "mimaropa"),
+    ("xviii", "nir"),
+    ("negros", "nir"),
+    ("armm", "barmm"),
+    ("bangsamoro", "barmm"),
+];
+
+impl Region {
+    /// Whether `query` refers to this region by designation (`IV-A`), Roman or Arabic
+    /// numeral (`Region 4A`), common name (`CALABARZON`) or a well-known alias (`BARMM`).
+    pub fn is_known_as(&self, query: &str) -> bool {
+        let query = alias_key(query);
+
+        if query.is_empty() {
+            return false;
+        }
+
+        let designation = alias_key(&self.designation);
+
+        query == designation
+            || query == alias_key(&self.name)
+            || EXTRA_ALIASES
+                .iter()
+                .any(|(alias, target)| query == *alias && designation == *target)
+    }
+}
+
+/// Reduces a region reference to a comparable key: folded, without the word "region",
+/// without separators, and with Arabic numerals spelled as Roman (`"Region 4-A"` -> `"iva"`).
+fn alias_key(value: &str) -> String {
+    let folded = psgc_shared::text::fold_name(value);
+    let folded = folded.strip_prefix("region ").unwrap_or(&folded);
+    let folded = folded.strip_suffix(" region").unwrap_or(folded);
+
+    let key = folded
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>();
+
+    let digits = key.chars().take_while(char::is_ascii_digit).count();
+    let suffix = &key[digits..];
+
+    match key[..digits].parse::<u32>() {
+        Ok(number) if number <= 20 && suffix.len() <= 1 => format!("{}{suffix}", to_roman(number)),
+        _ => key,
+    }
+}
+
+fn to_roman(mut number: u32) -> String {
+    const NUMERALS: &[(u32, &str)] = &[(10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i")];
+
+    let mut roman = String::new();
+    for (value, numeral) in NUMERALS {
+        while number >= *value {
+            roman.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    roman
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(name: &str, designation: &str) -> Region {
+        Region::builder()
+            .id(uuid::Uuid::nil())
+            .code("0400000000".to_string())
+            .correspondence_code("040000000".to_string())
+            .name(name.to_string())
+            .slug(String::new())
+            .designation(designation.to_string())
+            .population(0)
+            .created_at(Utc::now())
+            .updated_at(Utc::now())
+            .build()
+    }
+
+    #[test]
+    fn resolves_designations_numerals_and_names() {
+        let calabarzon = region("CALABARZON", "Region IV-A");
+
+        for query in [
+            "Region IV-A",
+            "IV-A",
+            "region 4a",
+            "Region 4-A",
+            "calabarzon",
+        ] {
+            assert!(calabarzon.is_known_as(query), "{query}");
+        }
+        assert!(!calabarzon.is_known_as("Region IV-B"));
+        assert!(!calabarzon.is_known_as("4"));
+    }
+
+    #[test]
+    fn resolves_extra_aliases() {
+        assert!(region("Southwestern Tagalog Region", "MIMAROPA").is_known_as("Region 4B"));
+        assert!(region("Caraga Region", "Region XIII").is_known_as("Caraga"));
+        assert!(region("Caraga Region", "Region XIII").is_known_as("Region 13"));
+        assert!(region("National Capital Region", "NCR").is_known_as("Metro Manila"));
+        assert!(
+            region("Bangsamoro Autonomous Region in Muslim Mindanao", "BARMM").is_known_as("ARMM")
+        );
+    }
+}
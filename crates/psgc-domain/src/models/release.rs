@@ -0,0 +1,42 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// A quarterly PSGC publication loaded by the seeder, stored alongside the others.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct Release {
+    /// Year and quarter, e.g. `2024Q3`; ids sort in publication order
+    pub id: String,
+    pub loaded_at: chrono::DateTime<Utc>,
+    /// Where the feed of changes stood once the release was loaded: the position of the
+    /// seed run's audit entry
+    #[builder(default)]
+    #[serde(default)]
+    pub position: i64,
+}
+
+impl Release {
+    /// Whether `id` is a year and quarter such as `2024Q3`.
+    pub fn is_valid_id(id: &str) -> bool {
+        match id.as_bytes() {
+            [year @ .., b'Q', quarter] if year.len() == 4 => {
+                year.iter().all(u8::is_ascii_digit) && (b'1'..=b'4').contains(quarter)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_release_ids() {
+        assert!(Release::is_valid_id("2024Q3"));
+        assert!(Release::is_valid_id("2023Q4"));
+        assert!(!Release::is_valid_id("2024Q5"));
+        assert!(!Release::is_valid_id("2024q3"));
+        assert!(!Release::is_valid_id("24Q3"));
+        assert!(!Release::is_valid_id("latest"));
+    }
+}
@@ -0,0 +1,337 @@
+use std::collections::{HashMap, HashSet};
+
+use psgc_shared::text::fold_name;
+use serde::{Deserialize, Serialize};
+
+/// A unit as it appears in one release, reduced to what a diff between releases compares.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, bon::Builder)]
+pub struct ReleaseUnit {
+    /// `region`, `province`, `district`, `city`, `municipality` or `barangay`
+    pub level: String,
+    /// The 10-digit PSGC code, whichever of the unit's two codes has that form
+    pub code: String,
+    pub name: String,
+    /// Names the unit had before, so a unit renamed and recoded at once is still matched
+    #[builder(default)]
+    #[serde(default)]
+    pub former_names: Vec<String>,
+    /// The parent's 10-digit code; a region has none
+    pub parent_code: Option<String>,
+    /// City class for cities, `U` or `R` for barangays, empty otherwise
+    #[builder(default)]
+    pub classification: String,
+}
+
+impl ReleaseUnit {
+    /// Cities and municipalities convert into one another and keep their code, so they
+    /// are matched as one level.
+    fn group(&self) -> u8 {
+        match self.level.as_str() {
+            "region" => 0,
+            "province" => 1,
+            "district" => 2,
+            "city" | "municipality" => 3,
+            _ => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnitChangeKind {
+    Added,
+    Abolished,
+    Renamed,
+    CodeChanged,
+    /// Changed level (a municipality became a city) or classification
+    Reclassified,
+}
+
+impl UnitChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Abolished => "abolished",
+            Self::Renamed => "renamed",
+            Self::CodeChanged => "code_changed",
+            Self::Reclassified => "reclassified",
+        }
+    }
+}
+
+/// One unit that differs between two releases. `before` is missing for added units and
+/// `after` for abolished ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitChange {
+    pub kinds: Vec<UnitChangeKind>,
+    pub before: Option<ReleaseUnit>,
+    pub after: Option<ReleaseUnit>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseDiff {
+    pub from: String,
+    pub to: String,
+    /// Ordered by level, top down, then code
+    pub changes: Vec<UnitChange>,
+}
+
+impl ReleaseDiff {
+    /// Compares the units of two releases.
+    ///
+    /// Units are first matched by code. A unit left unmatched on both sides is then paired
+    /// with the only unmatched unit of the same level, name and (matched) parent on the
+    /// other side, which is reported as a code change, and failing that with the only one
+    /// that lists its name among its former names, a rename and code change. Whatever is
+    /// still unmatched was abolished or added.
+    pub fn between(
+        from: String,
+        to: String,
+        before: Vec<ReleaseUnit>,
+        after: Vec<ReleaseUnit>,
+    ) -> Self {
+        let after_by_code: HashMap<(u8, &str), usize> = after
+            .iter()
+            .enumerate()
+            .map(|(i, unit)| ((unit.group(), unit.code.as_str()), i))
+            .collect();
+
+        // Index in `after` of the unit each unit of `before` became
+        let mut matched: Vec<Option<usize>> = before
+            .iter()
+            .map(|unit| {
+                after_by_code
+                    .get(&(unit.group(), unit.code.as_str()))
+                    .copied()
+            })
+            .collect();
+        let mut claimed: HashSet<usize> = matched.iter().flatten().copied().collect();
+        let mut new_codes: HashMap<&str, &str> = before
+            .iter()
+            .zip(&matched)
+            .filter_map(|(unit, matched)| {
+                matched.map(|j| (unit.code.as_str(), after[j].code.as_str()))
+            })
+            .collect();
+
+        let mut unmatched_after: HashMap<(u8, String, Option<&str>), Vec<usize>> = HashMap::new();
+        for (i, unit) in after.iter().enumerate() {
+            if !claimed.contains(&i) {
+                let key = (
+                    unit.group(),
+                    fold_name(&unit.name),
+                    unit.parent_code.as_deref(),
+                );
+                unmatched_after.entry(key).or_default().push(i);
+            }
+        }
+        let mut renamed_after: HashMap<(u8, String, Option<&str>), Vec<usize>> = HashMap::new();
+        for (i, unit) in after.iter().enumerate() {
+            if !claimed.contains(&i) {
+                for former in &unit.former_names {
+                    let key = (unit.group(), fold_name(former), unit.parent_code.as_deref());
+                    renamed_after.entry(key).or_default().push(i);
+                }
+            }
+        }
+
+        // Level by level from the top, so a child is compared against its parent's new code
+        for group in 0..=4 {
+            // By the name in `after`, then by its former names
+            for candidates_after in [&unmatched_after, &renamed_after] {
+                let mut unmatched_before: HashMap<(u8, String, Option<&str>), Vec<usize>> =
+                    HashMap::new();
+                for (i, unit) in before.iter().enumerate() {
+                    if unit.group() == group && matched[i].is_none() {
+                        let parent = unit
+                            .parent_code
+                            .as_deref()
+                            .map(|code| new_codes.get(code).copied().unwrap_or(code));
+                        let key = (group, fold_name(&unit.name), parent);
+                        unmatched_before.entry(key).or_default().push(i);
+                    }
+                }
+
+                for (key, candidates) in unmatched_before {
+                    let unclaimed: Vec<usize> = candidates_after
+                        .get(&key)
+                        .into_iter()
+                        .flatten()
+                        .filter(|j| !claimed.contains(j))
+                        .copied()
+                        .collect();
+
+                    if let ([i], [j]) = (candidates.as_slice(), unclaimed.as_slice()) {
+                        matched[*i] = Some(*j);
+                        claimed.insert(*j);
+                        new_codes.insert(before[*i].code.as_str(), after[*j].code.as_str());
+                    }
+                }
+            }
+        }
+
+        let mut changes: Vec<UnitChange> = before
+            .iter()
+            .zip(&matched)
+            .filter_map(|(unit, matched)| match matched {
+                Some(j) => {
+                    let other = &after[*j];
+                    let mut kinds = Vec::new();
+                    if unit.name != other.name {
+                        kinds.push(UnitChangeKind::Renamed);
+                    }
+                    if unit.code != other.code {
+                        kinds.push(UnitChangeKind::CodeChanged);
+                    }
+                    if unit.level != other.level || unit.classification != other.classification {
+                        kinds.push(UnitChangeKind::Reclassified);
+                    }
+
+                    (!kinds.is_empty()).then(|| UnitChange {
+                        kinds,
+                        before: Some(unit.clone()),
+                        after: Some(other.clone()),
+                    })
+                }
+                None => Some(UnitChange {
+                    kinds: vec![UnitChangeKind::Abolished],
+                    before: Some(unit.clone()),
+                    after: None,
+                }),
+            })
+            .collect();
+
+        changes.extend(
+            after
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !claimed.contains(i))
+                .map(|(_, unit)| UnitChange {
+                    kinds: vec![UnitChangeKind::Added],
+                    before: None,
+                    after: Some(unit.clone()),
+                }),
+        );
+
+        changes.sort_by_cached_key(|change| {
+            let unit = change.after.as_ref().or(change.before.as_ref());
+            unit.map(|unit| (unit.group(), unit.code.clone()))
+        });
+
+        Self { from, to, changes }
+    }
+
+    /// How many changes of `kind` the diff has.
+    pub fn count(&self, kind: UnitChangeKind) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.kinds.contains(&kind))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(level: &str, code: &str, name: &str, parent: Option<&str>) -> ReleaseUnit {
+        ReleaseUnit::builder()
+            .level(level.to_string())
+            .code(code.to_string())
+            .name(name.to_string())
+            .maybe_parent_code(parent.map(str::to_string))
+            .build()
+    }
+
+    #[test]
+    fn matches_units_across_releases() {
+        let before = vec![
+            unit("province", "1903800000", "Maguindanao", None),
+            unit("municipality", "1903801000", "Ampatuan", Some("1903800000")),
+            unit("municipality", "1903802000", "Nuling", Some("1903800000")),
+            unit("municipality", "1903803000", "Gone", Some("1903800000")),
+            unit("municipality", "1903804000", "Cotabato", Some("1903800000")),
+        ];
+        let after = vec![
+            unit("province", "1908800000", "Maguindanao del Norte", None),
+            unit("municipality", "1908801000", "Ampatuan", Some("1908800000")),
+            unit(
+                "municipality",
+                "1903802000",
+                "Sultan Kudarat",
+                Some("1908800000"),
+            ),
+            unit("city", "1903804000", "Cotabato", Some("1908800000")),
+            unit("municipality", "1908805000", "New Town", Some("1908800000")),
+        ];
+
+        let diff = ReleaseDiff::between("2022Q4".into(), "2023Q1".into(), before, after);
+        let kinds = |code: &str| {
+            diff.changes
+                .iter()
+                .find(|c| c.after.as_ref().or(c.before.as_ref()).unwrap().code == code)
+                .map(|c| c.kinds.clone())
+                .unwrap_or_default()
+        };
+
+        // The province matches neither by code nor by name, so its children only pair up
+        // because they keep their codes or names
+        assert_eq!(kinds("1903800000"), vec![UnitChangeKind::Abolished]);
+        assert_eq!(kinds("1908800000"), vec![UnitChangeKind::Added]);
+        assert_eq!(kinds("1903802000"), vec![UnitChangeKind::Renamed]);
+        assert_eq!(kinds("1903803000"), vec![UnitChangeKind::Abolished]);
+        assert_eq!(kinds("1903804000"), vec![UnitChangeKind::Reclassified]);
+        assert_eq!(kinds("1908805000"), vec![UnitChangeKind::Added]);
+        // Same name, but under a parent that didn't carry over
+        assert_eq!(kinds("1908801000"), vec![UnitChangeKind::Added]);
+
+        let recoded = ReleaseDiff::between(
+            "2022Q4".into(),
+            "2023Q1".into(),
+            vec![
+                unit("province", "1903800000", "Maguindanao", None),
+                unit("municipality", "1903801000", "Ampatuan", Some("1903800000")),
+            ],
+            vec![
+                unit("province", "1908800000", "Maguindanao", None),
+                unit("municipality", "1908801000", "Ampatuan", Some("1908800000")),
+            ],
+        );
+        assert_eq!(recoded.count(UnitChangeKind::CodeChanged), 2);
+        assert_eq!(recoded.changes.len(), 2);
+    }
+
+    #[test]
+    fn matches_renamed_and_recoded_units_by_former_name() {
+        let renamed = ReleaseUnit {
+            former_names: vec!["Nuling".to_string()],
+            ..unit(
+                "municipality",
+                "1908802000",
+                "Sultan Kudarat",
+                Some("1908800000"),
+            )
+        };
+        let diff = ReleaseDiff::between(
+            "2022Q4".into(),
+            "2023Q1".into(),
+            vec![
+                unit("province", "1903800000", "Maguindanao", None),
+                unit("municipality", "1903802000", "Nuling", Some("1903800000")),
+            ],
+            vec![
+                unit("province", "1908800000", "Maguindanao", None),
+                renamed.clone(),
+            ],
+        );
+
+        assert_eq!(diff.changes.len(), 2);
+        let change = &diff.changes[1];
+        assert_eq!(
+            change.kinds,
+            vec![UnitChangeKind::Renamed, UnitChangeKind::CodeChanged]
+        );
+        assert_eq!(change.before.as_ref().unwrap().name, "Nuling");
+        assert_eq!(change.after.as_ref(), Some(&renamed));
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct RegionStats {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub province_count: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct ProvinceStats {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+/// Number of units sharing the same value of a classification, e.g. an income class.
+#[derive(Debug, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct GroupCount {
+    pub group: String,
+    pub count: u64,
+}
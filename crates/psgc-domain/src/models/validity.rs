@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+
+use crate::models::{
+    barangay::Barangay, city::City, district::District, municipality::Municipality,
+    province::Province, region::Region,
+};
+
+/// When a unit existed: from `valid_from` until the day before `valid_to`. An unset
+/// `valid_from` means since before the records, an unset `valid_to` that it still exists.
+pub trait Validity {
+    fn valid_from(&self) -> Option<NaiveDate>;
+    fn valid_to(&self) -> Option<NaiveDate>;
+
+    fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.valid_from().is_none_or(|from| from <= date)
+            && self.valid_to().is_none_or(|to| date < to)
+    }
+}
+
+macro_rules! impl_validity {
+    ($($model:ty),*) => {
+        $(impl Validity for $model {
+            fn valid_from(&self) -> Option<NaiveDate> {
+                self.valid_from
+            }
+
+            fn valid_to(&self) -> Option<NaiveDate> {
+                self.valid_to
+            }
+        })*
+    };
+}
+
+impl_validity!(Region, Province, District, City, Municipality, Barangay);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn is_valid_from_the_first_day_until_the_day_before_the_end() {
+        let region = Region::builder()
+            .id(uuid::Uuid::nil())
+            .code("1900000000".into())
+            .correspondence_code("150000000".into())
+            .name("Bangsamoro".into())
+            .slug("bangsamoro".into())
+            .designation("BARMM".into())
+            .population(0)
+            .valid_from(date("2019-01-21"))
+            .valid_to(date("2022-12-03"))
+            .created_at(chrono::Utc::now())
+            .updated_at(chrono::Utc::now())
+            .build();
+
+        assert!(!region.is_valid_on(date("2019-01-20")));
+        assert!(region.is_valid_on(date("2019-01-21")));
+        assert!(region.is_valid_on(date("2022-12-02")));
+        assert!(!region.is_valid_on(date("2022-12-03")));
+    }
+}
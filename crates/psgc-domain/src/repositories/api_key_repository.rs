@@ -0,0 +1,23 @@
+use std::future;
+
+use chrono::NaiveDate;
+
+use crate::{errors::RepositoryError, models::api_key::ApiKey};
+
+pub trait ApiKeyRepository: Send + Sync + 'static {
+    fn find_by_hash(
+        &self,
+        key_hash: &str,
+    ) -> impl future::Future<Output = Result<ApiKey, RepositoryError>>;
+    fn list_all(&self) -> impl future::Future<Output = Result<Vec<ApiKey>, RepositoryError>>;
+    fn create(&self, api_key: &ApiKey)
+    -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Revokes the key with this name; revoked keys are kept so their usage stays on record
+    fn revoke(&self, name: &str) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Counts one request against the key for `day` and returns the day's total so far
+    fn record_usage(
+        &self,
+        id: uuid::Uuid,
+        day: NaiveDate,
+    ) -> impl future::Future<Output = Result<u64, RepositoryError>>;
+}
@@ -0,0 +1,44 @@
+use std::future;
+
+use chrono::Utc;
+
+use crate::{
+    errors::RepositoryError,
+    models::{PaginateResult, audit::AuditEntry},
+};
+
+/// Read side of the audit log; entries are written by the repositories making the changes.
+pub trait AuditLogRepository: Send + Sync + 'static {
+    /// Newest first, optionally only for one code and from `since` onwards
+    fn list(
+        &self,
+        code: Option<&str>,
+        since: Option<chrono::DateTime<Utc>>,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<AuditEntry>, RepositoryError>>;
+    /// Every change to the unit that has, or once had, this code, oldest first
+    fn history(
+        &self,
+        entity: &str,
+        code: &str,
+    ) -> impl future::Future<Output = Result<Vec<AuditEntry>, RepositoryError>>;
+    /// Up to `limit` entries for the units of `release` positioned after `after`, oldest
+    /// first
+    fn list_unit_changes(
+        &self,
+        release: &str,
+        after: i64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<Vec<AuditEntry>, RepositoryError>>;
+    /// The newest entry for the units of `release`, which changes whenever any of them does
+    fn last_unit_change(
+        &self,
+        release: &str,
+    ) -> impl future::Future<Output = Result<Option<AuditEntry>, RepositoryError>>;
+    /// The position of the last entry made before `time`, or 0 when there is none
+    fn position_before(
+        &self,
+        time: chrono::DateTime<Utc>,
+    ) -> impl future::Future<Output = Result<i64, RepositoryError>>;
+}
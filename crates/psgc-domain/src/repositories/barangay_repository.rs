@@ -2,7 +2,10 @@ use std::future;
 
 use crate::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        stats::GroupCount,
+    },
 };
 
 pub trait BarangayRepository: Send + Sync + 'static {
@@ -10,11 +13,26 @@ pub trait BarangayRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Barangay, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<Barangay, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<Barangay>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<Barangay>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<Barangay>, RepositoryError>>;
     fn list_by_city_code(
         &self,
         code: &str,
@@ -27,4 +45,21 @@ pub trait BarangayRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Vec<Barangay>, RepositoryError>>;
+    fn count_by_urban_rural(
+        &self,
+    ) -> impl future::Future<Output = Result<Vec<GroupCount>, RepositoryError>>;
+    /// Inserts a new barangay and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        barangay: &Barangay,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the barangay with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        barangay: &Barangay,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
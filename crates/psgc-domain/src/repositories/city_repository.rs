@@ -2,7 +2,10 @@ use std::future;
 
 use crate::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay, city::City},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, city::City,
+        island_group::IslandGroup, stats::GroupCount,
+    },
 };
 
 pub trait CityRepository: Send + Sync + 'static {
@@ -10,11 +13,26 @@ pub trait CityRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<City, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<City, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<City>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<City>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<City>, RepositoryError>>;
     fn list_by_region_code(
         &self,
         code: &str,
@@ -27,4 +45,21 @@ pub trait CityRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Vec<Barangay>, RepositoryError>>;
+    fn count_by_income_class(
+        &self,
+    ) -> impl future::Future<Output = Result<Vec<GroupCount>, RepositoryError>>;
+    /// Inserts a new city and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        city: &City,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the city with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        city: &City,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
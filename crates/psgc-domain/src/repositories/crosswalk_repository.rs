@@ -0,0 +1,36 @@
+use std::{collections::HashMap, future};
+
+use crate::{
+    errors::RepositoryError,
+    models::{audit::AuditEntry, crosswalk::CrosswalkEntry, release_diff::ReleaseUnit},
+};
+
+/// Curated mappings of historical codes, and the units they can resolve to.
+pub trait CrosswalkRepository: Send + Sync + 'static {
+    fn find_by_id(
+        &self,
+        id: uuid::Uuid,
+    ) -> impl future::Future<Output = Result<CrosswalkEntry, RepositoryError>>;
+    /// Curated mappings of any of `codes`
+    fn list_by_historical_codes(
+        &self,
+        codes: &[String],
+    ) -> impl future::Future<Output = Result<Vec<CrosswalkEntry>, RepositoryError>>;
+    /// Units of `release` that haven't been abolished and have any of `codes` as either of
+    /// their two codes, by the code they matched
+    fn find_current_units(
+        &self,
+        release: &str,
+        codes: &[String],
+    ) -> impl future::Future<Output = Result<HashMap<String, ReleaseUnit>, RepositoryError>>;
+    fn create(
+        &self,
+        entry: &CrosswalkEntry,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    fn delete(
+        &self,
+        id: uuid::Uuid,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+}
@@ -2,7 +2,10 @@ use std::future;
 
 use crate::{
     errors::RepositoryError,
-    models::{PaginateResult, city::City, district::District, municipality::Municipality},
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, district::District,
+        island_group::IslandGroup, municipality::Municipality,
+    },
 };
 
 pub trait DistrictRepository: Send + Sync + 'static {
@@ -10,11 +13,26 @@ pub trait DistrictRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<District, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<District, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<District>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<District>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<District>, RepositoryError>>;
     fn list_by_region_code(
         &self,
         code: &str,
@@ -31,4 +49,18 @@ pub trait DistrictRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Vec<Municipality>, RepositoryError>>;
+    /// Inserts a new district and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        district: &District,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the district with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        district: &District,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
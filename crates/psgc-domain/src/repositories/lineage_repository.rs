@@ -0,0 +1,34 @@
+use std::future;
+
+use crate::{
+    errors::RepositoryError,
+    models::{audit::AuditEntry, lineage::LineageEvent},
+};
+
+/// The recorded lineage of PSGC codes, across releases.
+pub trait LineageRepository: Send + Sync + 'static {
+    fn find_by_id(
+        &self,
+        id: uuid::Uuid,
+    ) -> impl future::Future<Output = Result<LineageEvent, RepositoryError>>;
+    /// Events that replaced any of `codes`
+    fn list_by_predecessors(
+        &self,
+        codes: &[String],
+    ) -> impl future::Future<Output = Result<Vec<LineageEvent>, RepositoryError>>;
+    /// Events that produced any of `codes`
+    fn list_by_successors(
+        &self,
+        codes: &[String],
+    ) -> impl future::Future<Output = Result<Vec<LineageEvent>, RepositoryError>>;
+    fn create(
+        &self,
+        event: &LineageEvent,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    fn delete(
+        &self,
+        id: uuid::Uuid,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+}
@@ -1,6 +1,11 @@
+pub mod api_key_repository;
+pub mod audit_repository;
 pub mod barangay_repository;
 pub mod city_repository;
+pub mod crosswalk_repository;
 pub mod district_repository;
+pub mod lineage_repository;
 pub mod municipality_repository;
 pub mod province_repository;
 pub mod region_repository;
+pub mod release_repository;
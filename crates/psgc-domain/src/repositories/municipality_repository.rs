@@ -2,7 +2,10 @@ use std::future;
 
 use crate::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay, municipality::Municipality},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        municipality::Municipality, stats::GroupCount,
+    },
 };
 
 pub trait MunicipalityRepository: Send + Sync + 'static {
@@ -10,11 +13,26 @@ pub trait MunicipalityRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Municipality, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<Municipality, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<Municipality>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<Municipality>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<Municipality>, RepositoryError>>;
     fn list_by_region_code(
         &self,
         region_code: &str,
@@ -31,4 +49,21 @@ pub trait MunicipalityRepository: Send + Sync + 'static {
         &self,
         municipality_code: &str,
     ) -> impl future::Future<Output = Result<Vec<Barangay>, RepositoryError>>;
+    fn count_by_income_class(
+        &self,
+    ) -> impl future::Future<Output = Result<Vec<GroupCount>, RepositoryError>>;
+    /// Inserts a new municipality and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the municipality with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
@@ -2,7 +2,15 @@ use std::future;
 
 use crate::{
     errors::RepositoryError,
-    models::{PaginateResult, city::City, municipality::Municipality, province::Province},
+    models::{
+        PaginateResult,
+        audit::AuditEntry,
+        city::City,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        stats::{GroupCount, ProvinceStats},
+    },
 };
 
 pub trait ProvinceRepository: Send + Sync + 'static {
@@ -10,11 +18,26 @@ pub trait ProvinceRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Province, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<Province, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<Province>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<Province>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<Province>, RepositoryError>>;
     fn list_by_region_code(
         &self,
         region_code: &str,
@@ -27,4 +50,28 @@ pub trait ProvinceRepository: Send + Sync + 'static {
         &self,
         province_code: &str,
     ) -> impl future::Future<Output = Result<Vec<Municipality>, RepositoryError>>;
+    fn list_stats(
+        &self,
+    ) -> impl future::Future<Output = Result<Vec<ProvinceStats>, RepositoryError>>;
+    fn get_stats(
+        &self,
+        code: &str,
+    ) -> impl future::Future<Output = Result<ProvinceStats, RepositoryError>>;
+    fn count_by_income_class(
+        &self,
+    ) -> impl future::Future<Output = Result<Vec<GroupCount>, RepositoryError>>;
+    /// Inserts a new province and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        province: &Province,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the province with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        province: &Province,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
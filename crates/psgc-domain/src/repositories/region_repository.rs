@@ -3,7 +3,8 @@ use std::future;
 use crate::{
     errors::RepositoryError,
     models::{
-        PaginateResult, city::City, municipality::Municipality, province::Province, region::Region,
+        PaginateResult, audit::AuditEntry, city::City, island_group::IslandGroup,
+        municipality::Municipality, province::Province, region::Region, stats::RegionStats,
     },
 };
 
@@ -12,11 +13,32 @@ pub trait RegionRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Region, RepositoryError>>;
+    /// Falls back to [`Region::is_known_as`] when `code` isn't a PSGC code, so designations
+    /// and aliases like `IV-A`, `Region 4A` or `BARMM` resolve too
+    fn find_by_code_or_alias(
+        &self,
+        code: &str,
+    ) -> impl future::Future<Output = Result<Region, RepositoryError>>;
+    fn find_by_slug(
+        &self,
+        slug: &str,
+    ) -> impl future::Future<Output = Result<Region, RepositoryError>>;
+    /// Case- and accent-insensitive exact match on the name, falling back to aliases
+    fn list_by_name(
+        &self,
+        name: &str,
+    ) -> impl future::Future<Output = Result<Vec<Region>, RepositoryError>>;
     fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> impl future::Future<Output = Result<PaginateResult<Region>, RepositoryError>>;
+    fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> impl future::Future<Output = Result<PaginateResult<Region>, RepositoryError>>;
     fn list_provinces(
         &self,
         code: &str,
@@ -29,4 +51,24 @@ pub trait RegionRepository: Send + Sync + 'static {
         &self,
         code: &str,
     ) -> impl future::Future<Output = Result<Vec<Municipality>, RepositoryError>>;
+    fn list_stats(&self)
+    -> impl future::Future<Output = Result<Vec<RegionStats>, RepositoryError>>;
+    fn get_stats(
+        &self,
+        code: &str,
+    ) -> impl future::Future<Output = Result<RegionStats, RepositoryError>>;
+    /// Inserts a new region and records `audit` in the same transaction; parent links are
+    /// expected to have been checked by the caller
+    fn create(
+        &self,
+        region: &Region,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
+    /// Overwrites the region with the same id, code and parent links included, and records
+    /// `audit` in the same transaction
+    fn update(
+        &self,
+        region: &Region,
+        audit: &AuditEntry,
+    ) -> impl future::Future<Output = Result<(), RepositoryError>>;
 }
@@ -0,0 +1,19 @@
+use std::future;
+
+use crate::{
+    errors::RepositoryError,
+    models::{release::Release, release_diff::ReleaseUnit},
+};
+
+pub trait ReleaseRepository: Send + Sync + 'static {
+    /// Every loaded release, newest first
+    fn list(&self) -> impl future::Future<Output = Result<Vec<Release>, RepositoryError>>;
+    fn find(&self, id: &str) -> impl future::Future<Output = Result<Release, RepositoryError>>;
+    /// The newest loaded release, which requests without one are served from
+    fn latest(&self) -> impl future::Future<Output = Result<Release, RepositoryError>>;
+    /// Every unit of a release at every level, for comparing releases
+    fn units(
+        &self,
+        id: &str,
+    ) -> impl future::Future<Output = Result<Vec<ReleaseUnit>, RepositoryError>>;
+}
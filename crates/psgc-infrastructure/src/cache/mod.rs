@@ -0,0 +1,326 @@
+use std::{
+    future,
+    sync::{
+        Arc, OnceLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::NaiveDate;
+use psgc_domain::errors::RepositoryError;
+use redis::{
+    AsyncCommands, Client,
+    aio::{ConnectionManager, ConnectionManagerConfig},
+};
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::config::cache_config::CacheConfig;
+
+const KEY_PREFIX: &str = "psgc:v1";
+const TIMEOUT: Duration = Duration::from_millis(250);
+const RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Read-through Redis cache shared by the cached repository decorators.
+///
+/// Every Redis failure is logged and treated as a miss, so an unreachable or
+/// disabled cache falls back to the wrapped repository instead of failing requests.
+#[derive(Clone)]
+pub struct RedisCache {
+    /// `None` when no `REDIS_URL` is configured
+    connection: Option<Arc<LazyConnection>>,
+    entry_ttl: Duration,
+    list_ttl: Duration,
+    counters: Arc<Counters>,
+    /// Set on the copies handed to the repositories of one release, read as of a day or
+    /// not, so the same lookup in different releases or on different days gets different keys
+    scope: Option<Arc<str>>,
+}
+
+/// Opens the connection on first use, and again after a failed attempt once
+/// [`RETRY_INTERVAL`] has passed, so caching resumes when Redis comes back. Once open,
+/// the [`ConnectionManager`] reconnects by itself.
+struct LazyConnection {
+    client: Client,
+    manager: OnceLock<ConnectionManager>,
+    /// Held while connecting; requests arriving meanwhile skip the cache
+    retry_at: Mutex<Option<Instant>>,
+}
+
+impl LazyConnection {
+    async fn get(&self) -> Option<ConnectionManager> {
+        if let Some(manager) = self.manager.get() {
+            return Some(manager.clone());
+        }
+
+        let mut retry_at = self.retry_at.try_lock().ok()?;
+        if let Some(manager) = self.manager.get() {
+            return Some(manager.clone());
+        }
+        if retry_at.is_some_and(|at| Instant::now() < at) {
+            return None;
+        }
+
+        let config = ConnectionManagerConfig::new()
+            .set_connection_timeout(TIMEOUT)
+            .set_response_timeout(TIMEOUT)
+            .set_number_of_retries(1);
+
+        match self.client.get_connection_manager_with_config(config).await {
+            Ok(manager) => {
+                info!("connected to redis, caching enabled");
+                *retry_at = None;
+                Some(self.manager.get_or_init(|| manager).clone())
+            }
+            Err(e) => {
+                warn!(
+                    "redis unavailable, retrying in {}s: {}",
+                    RETRY_INTERVAL.as_secs(),
+                    e
+                );
+                *retry_at = Some(Instant::now() + RETRY_INTERVAL);
+                None
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Lookups since startup; Redis errors and undecodable entries count as misses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
+
+impl RedisCache {
+    /// Connects to `REDIS_URL`; a missing or invalid URL yields a disabled cache. A failed
+    /// connection is retried on later lookups, which fall back to the database meanwhile.
+    pub async fn connect(config: &CacheConfig) -> Self {
+        let connection = match config.redis_url.as_deref().map(Client::open) {
+            Some(Ok(client)) => Some(Arc::new(LazyConnection {
+                client,
+                manager: OnceLock::new(),
+                retry_at: Mutex::new(None),
+            })),
+            Some(Err(e)) => {
+                warn!("invalid REDIS_URL, caching disabled: {}", e);
+                None
+            }
+            None => None,
+        };
+
+        if let Some(connection) = &connection {
+            connection.get().await;
+        }
+
+        Self {
+            connection,
+            entry_ttl: config.entry_ttl,
+            list_ttl: config.list_ttl,
+            counters: Arc::default(),
+            scope: None,
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            connection: None,
+            entry_ttl: Duration::ZERO,
+            list_ttl: Duration::ZERO,
+            counters: Arc::default(),
+            scope: None,
+        }
+    }
+
+    /// The same cache with its keys scoped to `release`, and to `as_of` when set.
+    pub(crate) fn for_release(&self, release: &str, as_of: Option<NaiveDate>) -> Self {
+        let scope = match as_of {
+            Some(date) => format!("{}@{}", release, date),
+            None => release.to_string(),
+        };
+
+        Self {
+            scope: Some(Arc::from(scope)),
+            ..self.clone()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.connection.is_some()
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    pub(crate) async fn entry<T, F>(&self, key: String, fetch: F) -> Result<T, RepositoryError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: future::Future<Output = Result<T, RepositoryError>>,
+    {
+        self.get_or_fetch(key, self.entry_ttl, fetch).await
+    }
+
+    pub(crate) async fn list<T, F>(&self, key: String, fetch: F) -> Result<T, RepositoryError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: future::Future<Output = Result<T, RepositoryError>>,
+    {
+        self.get_or_fetch(key, self.list_ttl, fetch).await
+    }
+
+    /// Drops every cached entry. A correction can change lists, stats and parents' children
+    /// anywhere in the hierarchy, so nothing is kept rather than tracking what it touched.
+    pub(crate) async fn invalidate_all(&self) {
+        let Some(connection) = &self.connection else {
+            return;
+        };
+        let Some(connection) = connection.get().await else {
+            warn!("redis unavailable, cache not invalidated");
+            return;
+        };
+
+        let mut scan_connection = connection.clone();
+        let keys = match scan_connection
+            .scan_match::<_, String>(format!("{}:*", KEY_PREFIX))
+            .await
+        {
+            Ok(mut keys) => {
+                let mut collected = Vec::new();
+                while let Some(key) = keys.next_item().await {
+                    collected.push(key);
+                }
+                collected
+            }
+            Err(e) => {
+                warn!("redis scan failed, cache not invalidated: {}", e);
+                return;
+            }
+        };
+
+        let mut connection = connection.clone();
+        for chunk in keys.chunks(500) {
+            if let Err(e) = connection.del::<_, ()>(chunk).await {
+                warn!("redis del failed, cache may be stale: {}", e);
+            }
+        }
+    }
+
+    async fn get_or_fetch<T, F>(
+        &self,
+        key: String,
+        ttl: Duration,
+        fetch: F,
+    ) -> Result<T, RepositoryError>
+    where
+        T: Serialize + DeserializeOwned,
+        F: future::Future<Output = Result<T, RepositoryError>>,
+    {
+        let Some(connection) = &self.connection else {
+            return fetch.await;
+        };
+        let Some(mut connection) = connection.get().await else {
+            self.counters.misses.fetch_add(1, Ordering::Relaxed);
+            return fetch.await;
+        };
+
+        let key = match &self.scope {
+            Some(scope) => format!("{}:{}:{}", KEY_PREFIX, scope, key),
+            None => format!("{}:{}", KEY_PREFIX, key),
+        };
+
+        match connection.get::<_, Option<String>>(&key).await {
+            Ok(Some(cached)) => match serde_json::from_str(&cached) {
+                Ok(value) => {
+                    self.counters.hits.fetch_add(1, Ordering::Relaxed);
+                    return Ok(value);
+                }
+                Err(e) => warn!("discarding undecodable cache entry {}: {}", key, e),
+            },
+            Ok(None) => {}
+            Err(e) => {
+                warn!("redis get {} failed, falling back to database: {}", key, e);
+                self.counters.misses.fetch_add(1, Ordering::Relaxed);
+                return fetch.await;
+            }
+        }
+
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
+
+        let value = fetch.await?;
+
+        match serde_json::to_string(&value) {
+            Ok(encoded) => {
+                if let Err(e) = connection
+                    .set_ex::<_, _, ()>(&key, encoded, ttl.as_secs().max(1))
+                    .await
+                {
+                    warn!("redis set {} failed: {}", key, e);
+                }
+            }
+            Err(e) => warn!("failed to encode cache entry {}: {}", key, e),
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn falls_back_when_redis_is_unreachable() {
+        let config = CacheConfig::builder()
+            .redis_url("redis://127.0.0.1:1".to_string())
+            .build();
+        let cache = RedisCache::connect(&config).await;
+
+        // Still enabled, so lookups try to connect again once the retry interval passes
+        assert!(cache.is_enabled());
+        let value = cache
+            .entry("test:unreachable".to_string(), async { Ok(42u64) })
+            .await;
+        assert_eq!(value.unwrap(), 42);
+        assert_eq!(cache.stats().misses, 1);
+    }
+
+    /// Run with `REDIS_URL=redis://localhost:6379 cargo test -- --ignored`
+    #[tokio::test]
+    #[ignore = "requires a local Redis instance"]
+    async fn reads_through_local_redis() {
+        let config = CacheConfig::from_env().unwrap();
+        let cache = RedisCache::connect(&config).await;
+        assert!(cache.is_enabled());
+
+        let key = format!("test:read-through:{}", uuid::Uuid::now_v7());
+        let first = cache.entry(key.clone(), async { Ok(1u64) }).await;
+        let second = cache.entry(key, async { Ok(2u64) }).await;
+
+        assert_eq!(first.unwrap(), 1);
+        assert_eq!(second.unwrap(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+use std::{env, fmt, str::FromStr};
+
+use crate::config::{ConfigError, cache_config::CacheConfig, db_config::DatabaseConfig};
+
+/// Where the API reads PSGC data from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Postgres, or SQLite for a `sqlite:` `DATABASE_URL`
+    Sql,
+    /// [`Backend::Sql`] behind a Redis read-through cache
+    Cached,
+    /// The bundled seed data loaded into memory, no database needed
+    InMemory,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sql => "sql",
+            Self::Cached => "cached",
+            Self::InMemory => "memory",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Backend {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "sql" | "postgres" | "sqlite" => Ok(Self::Sql),
+            "cached" => Ok(Self::Cached),
+            "memory" | "in-memory" => Ok(Self::InMemory),
+            _ => Err(ConfigError::InvalidValue("REPOSITORY_BACKEND".to_string())),
+        }
+    }
+}
+
+#[derive(Debug, bon::Builder)]
+pub struct BackendConfig {
+    pub backend: Backend,
+    pub database: Option<DatabaseConfig>,
+    pub cache: CacheConfig,
+}
+
+impl BackendConfig {
+    /// Reads `REPOSITORY_BACKEND` (`sql`, `cached` or `memory`). When unset, the backend
+    /// follows from what's configured: no `DATABASE_URL` means memory, a `REDIS_URL`
+    /// means cached, otherwise plain SQL.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let database = DatabaseConfig::try_from_env();
+        let cache = CacheConfig::from_env()?;
+
+        let backend = match env::var("REPOSITORY_BACKEND") {
+            Ok(backend) if !backend.is_empty() => backend.parse()?,
+            _ => match (&database, &cache.redis_url) {
+                (None, _) => Backend::InMemory,
+                (Some(_), Some(_)) => Backend::Cached,
+                (Some(_), None) => Backend::Sql,
+            },
+        };
+
+        if backend != Backend::InMemory && database.is_none() {
+            return Err(ConfigError::MissingEnv("DATABASE_URL".to_string()));
+        }
+
+        Ok(Self::builder()
+            .backend(backend)
+            .maybe_database(database)
+            .cache(cache)
+            .build())
+    }
+}
@@ -0,0 +1,42 @@
+use std::{env, time::Duration};
+
+use crate::config::ConfigError;
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct CacheConfig {
+    /// Caching is disabled when unset
+    pub redis_url: Option<String>,
+    /// TTL for single-record lookups (by code, slug, name)
+    #[builder(default = Duration::from_secs(60 * 60))]
+    pub entry_ttl: Duration,
+    /// TTL for paginated listings, children and aggregates
+    #[builder(default = Duration::from_secs(10 * 60))]
+    pub list_ttl: Duration,
+}
+
+impl CacheConfig {
+    pub fn from_env() -> Result<Self, ConfigError> {
+        dotenvy::dotenv().ok();
+
+        let redis_url = env::var("REDIS_URL").ok().filter(|url| !url.is_empty());
+        let entry_ttl = ttl_from_env("CACHE_ENTRY_TTL_SECS")?;
+        let list_ttl = ttl_from_env("CACHE_LIST_TTL_SECS")?;
+
+        Ok(Self::builder()
+            .maybe_redis_url(redis_url)
+            .maybe_entry_ttl(entry_ttl)
+            .maybe_list_ttl(list_ttl)
+            .build())
+    }
+}
+
+fn ttl_from_env(key: &str) -> Result<Option<Duration>, ConfigError> {
+    env::var(key)
+        .ok()
+        .map(|secs| {
+            secs.parse::<u64>()
+                .map(Duration::from_secs)
+                .map_err(|_| ConfigError::InvalidValue(key.to_string()))
+        })
+        .transpose()
+}
@@ -9,7 +9,7 @@ pub struct DatabaseConfig {
 
 impl DatabaseConfig {
     pub fn from_env() -> Result<Self, ConfigError> {
-        dotenvy::dotenv().unwrap();
+        dotenvy::dotenv().ok();
 
         let db_url = env::var("DATABASE_URL")
             .map_err(|_| ConfigError::MissingEnv("missing DATABASE_URL".to_string()))
@@ -19,4 +19,30 @@ impl DatabaseConfig {
 
         Ok(Self::builder().db_url(db_url).build())
     }
+
+    /// `None` when `DATABASE_URL` isn't set, so the API can serve the bundled dataset
+    /// from memory instead of falling back to a default connection string.
+    pub fn try_from_env() -> Option<Self> {
+        dotenvy::dotenv().ok();
+
+        env::var("DATABASE_URL")
+            .ok()
+            .filter(|url| !url.is_empty())
+            .map(|db_url| Self::builder().db_url(db_url).build())
+    }
+
+    /// Whether `DATABASE_URL` points at an SQLite file (`sqlite://psgc.db`) rather than Postgres.
+    pub fn is_sqlite(&self) -> bool {
+        self.db_url.starts_with("sqlite:")
+    }
+
+    /// Filesystem path of an SQLite `DATABASE_URL`, without the scheme or query parameters.
+    pub fn sqlite_path(&self) -> &str {
+        let path = self
+            .db_url
+            .trim_start_matches("sqlite://")
+            .trim_start_matches("sqlite:");
+
+        path.split('?').next().unwrap_or(path)
+    }
 }
@@ -1,17 +1,23 @@
 use std::{env, net::Ipv4Addr};
 
-use crate::config::ConfigError;
+use crate::config::{ConfigError, rate_limit_config::RateLimitConfig};
 
 #[derive(Debug, Clone, bon::Builder)]
 pub struct HTTPConfig {
     pub host: Ipv4Addr,
     pub port: u16,
     pub allowed_origins: Vec<String>,
+    /// When set, `/metrics` and the probes are served on this port instead of `port`.
+    pub admin_port: Option<u16>,
+    /// Whether `/api/v1` requires an `X-API-Key`; needs a SQL backend to store the keys.
+    #[builder(default)]
+    pub require_api_key: bool,
+    pub rate_limit: RateLimitConfig,
 }
 
 impl HTTPConfig {
     pub fn from_env() -> Result<Self, ConfigError> {
-        dotenvy::dotenv().unwrap();
+        dotenvy::dotenv().ok();
 
         let host = std::env::var("HOST")
             .map_err(|_| ConfigError::MissingEnv("HOST".to_string()))?
@@ -32,14 +38,40 @@ impl HTTPConfig {
             .map(|o| o.to_string())
             .collect::<Vec<String>>();
 
+        let admin_port = match env::var("ADMIN_PORT") {
+            Ok(admin_port) if !admin_port.is_empty() => Some(
+                admin_port
+                    .parse::<u16>()
+                    .map_err(|_| ConfigError::InvalidValue("ADMIN_PORT".to_string()))?,
+            ),
+            _ => None,
+        };
+
+        let require_api_key = match env::var("REQUIRE_API_KEY") {
+            Ok(value) if !value.is_empty() => match value.to_lowercase().as_str() {
+                "true" | "1" | "yes" => true,
+                "false" | "0" | "no" => false,
+                _ => return Err(ConfigError::InvalidValue("REQUIRE_API_KEY".to_string())),
+            },
+            _ => false,
+        };
+
         Ok(Self::builder()
             .host(host)
             .port(port)
             .allowed_origins(allowed_origins)
+            .maybe_admin_port(admin_port)
+            .require_api_key(require_api_key)
+            .rate_limit(RateLimitConfig::from_env()?)
             .build())
     }
 
     pub fn get_connection_string(&self) -> String {
         format!("{}:{}", self.host, self.port)
     }
+
+    pub fn get_admin_connection_string(&self) -> Option<String> {
+        self.admin_port
+            .map(|admin_port| format!("{}:{}", self.host, admin_port))
+    }
 }
@@ -1,5 +1,8 @@
+pub mod backend_config;
+pub mod cache_config;
 pub mod db_config;
 pub mod http_config;
+pub mod rate_limit_config;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -0,0 +1,230 @@
+use std::{env, net::IpAddr, str::FromStr};
+
+use crate::config::ConfigError;
+
+/// Replenishes one request every ten seconds per client, with room for bursts of 1000.
+const DEFAULT_PER_MINUTE: u32 = 6;
+const DEFAULT_BURST: u32 = 1000;
+
+/// Requests a client may make: `per_minute` is the sustained rate and `burst` how many
+/// can be made at once before it applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub per_minute: u32,
+    pub burst: u32,
+}
+
+impl FromStr for RateLimit {
+    type Err = ConfigError;
+
+    /// `per_minute` or `per_minute:burst`; the burst defaults to the per-minute rate.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ConfigError::InvalidValue(format!("rate limit {s}"));
+        let (per_minute, burst) = match s.split_once(':') {
+            Some((per_minute, burst)) => (per_minute, Some(burst)),
+            None => (s, None),
+        };
+
+        let per_minute = per_minute.trim().parse::<u32>().map_err(|_| invalid())?;
+        let burst = match burst {
+            Some(burst) => burst.trim().parse::<u32>().map_err(|_| invalid())?,
+            None => per_minute,
+        };
+
+        if per_minute == 0 || burst == 0 {
+            return Err(invalid());
+        }
+
+        Ok(Self { per_minute, burst })
+    }
+}
+
+/// A stricter or looser limit for the routes matching `pattern`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteRateLimit {
+    /// A route pattern as registered, e.g. `/api/v1/regions/by-name`, where `*` stands
+    /// for any one segment: `/api/v1/*/by-name`
+    pub pattern: String,
+    pub limit: RateLimit,
+}
+
+impl RouteRateLimit {
+    pub fn matches(&self, route: &str) -> bool {
+        let mut pattern = self.pattern.trim_end_matches('/').split('/');
+        let mut route = route.trim_end_matches('/').split('/');
+
+        loop {
+            match (pattern.next(), route.next()) {
+                (Some(expected), Some(segment)) if expected == "*" || expected == segment => {}
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// An IP address or CIDR block, e.g. `10.0.0.0/8` or `::1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpCidr {
+    address: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpCidr {
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.address, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => prefix_eq(
+                network.to_bits().into(),
+                ip.to_bits().into(),
+                32,
+                self.prefix_len,
+            ),
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                prefix_eq(network.to_bits(), ip.to_bits(), 128, self.prefix_len)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn prefix_eq(network: u128, ip: u128, bits: u8, prefix_len: u8) -> bool {
+    let shift = bits - prefix_len;
+
+    shift >= bits || network >> shift == ip >> shift
+}
+
+impl FromStr for IpCidr {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ConfigError::InvalidValue(format!("trusted proxy {s}"));
+        let (address, prefix_len) = match s.split_once('/') {
+            Some((address, prefix_len)) => (address, Some(prefix_len)),
+            None => (s, None),
+        };
+
+        let address = address
+            .trim()
+            .parse::<IpAddr>()
+            .map_err(|_| invalid())?
+            .to_canonical();
+        let max_len = if address.is_ipv4() { 32 } else { 128 };
+        let prefix_len = match prefix_len {
+            Some(prefix_len) => prefix_len.trim().parse::<u8>().map_err(|_| invalid())?,
+            None => max_len,
+        };
+
+        if prefix_len > max_len {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            address,
+            prefix_len,
+        })
+    }
+}
+
+#[derive(Debug, Clone, bon::Builder)]
+pub struct RateLimitConfig {
+    /// Applies to every route without an override; no limit when `None`
+    pub default: Option<RateLimit>,
+    #[builder(default)]
+    pub routes: Vec<RouteRateLimit>,
+    /// Proxies allowed to report the client IP in `Forwarded` or `X-Forwarded-For`
+    #[builder(default)]
+    pub trusted_proxies: Vec<IpCidr>,
+}
+
+impl RateLimitConfig {
+    /// Reads `RATE_LIMIT` (`per_minute[:burst]`, or `off`), `RATE_LIMIT_ROUTES`
+    /// (`pattern=per_minute[:burst]`, comma-separated) and `TRUSTED_PROXIES`.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let default = match env::var("RATE_LIMIT") {
+            Ok(limit) if limit.eq_ignore_ascii_case("off") => None,
+            Ok(limit) if !limit.is_empty() => Some(limit.parse::<RateLimit>()?),
+            _ => Some(RateLimit {
+                per_minute: DEFAULT_PER_MINUTE,
+                burst: DEFAULT_BURST,
+            }),
+        };
+
+        let routes = env::var("RATE_LIMIT_ROUTES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|route| !route.is_empty())
+            .map(|route| {
+                let (pattern, limit) = route
+                    .split_once('=')
+                    .ok_or_else(|| ConfigError::InvalidValue("RATE_LIMIT_ROUTES".to_string()))?;
+
+                Ok(RouteRateLimit {
+                    pattern: pattern.trim().to_string(),
+                    limit: limit.parse()?,
+                })
+            })
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        let trusted_proxies = env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<IpCidr>, _>>()?;
+
+        Ok(Self::builder()
+            .maybe_default(default)
+            .routes(routes)
+            .trusted_proxies(trusted_proxies)
+            .build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_limits_and_matches_route_patterns() {
+        assert_eq!(
+            "30:5".parse::<RateLimit>().unwrap(),
+            RateLimit {
+                per_minute: 30,
+                burst: 5
+            }
+        );
+        assert_eq!("30".parse::<RateLimit>().unwrap().burst, 30);
+        assert!("0".parse::<RateLimit>().is_err());
+
+        let by_name = RouteRateLimit {
+            pattern: "/api/v1/*/by-name".to_string(),
+            limit: "30".parse().unwrap(),
+        };
+        assert!(by_name.matches("/api/v1/regions/by-name"));
+        assert!(by_name.matches("/api/v1/barangays/by-name/"));
+        assert!(!by_name.matches("/api/v1/regions/{code}"));
+        assert!(!by_name.matches("/api/v1/regions/by-name/extra"));
+    }
+
+    #[test]
+    fn matches_addresses_in_cidr_blocks() {
+        let private = "10.0.0.0/8".parse::<IpCidr>().unwrap();
+        assert!(private.contains(&"10.1.2.3".parse().unwrap()));
+        assert!(private.contains(&"::ffff:10.1.2.3".parse().unwrap()));
+        assert!(!private.contains(&"11.0.0.1".parse().unwrap()));
+
+        let loopback = "::1".parse::<IpCidr>().unwrap();
+        assert!(loopback.contains(&"::1".parse().unwrap()));
+        assert!(!loopback.contains(&"::2".parse().unwrap()));
+
+        assert!(
+            "0.0.0.0/0"
+                .parse::<IpCidr>()
+                .unwrap()
+                .contains(&"8.8.8.8".parse().unwrap())
+        );
+        assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+    }
+}
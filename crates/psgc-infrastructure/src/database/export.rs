@@ -0,0 +1,151 @@
+//! Builds the SQLite file of a dataset download.
+
+/// One table of the file: a level's units, each a JSON object as the API serves it.
+pub struct ExportTable<'a> {
+    pub name: &'a str,
+    pub rows: &'a [serde_json::Value],
+}
+
+/// A standalone SQLite database with a table per level and a `manifest` table of
+/// `key`/`value` pairs.
+///
+/// A table's columns are the fields of its units; nested values such as former names are
+/// stored as JSON text, and tables are indexed on `code`.
+#[cfg(feature = "sqlite")]
+pub fn sqlite_file(tables: &[ExportTable], manifest: &[(&str, String)]) -> anyhow::Result<Vec<u8>> {
+    let path = std::env::temp_dir().join(format!("psgc-export-{}.sqlite", uuid::Uuid::now_v7()));
+    let result = write_sqlite(&path, tables, manifest).and_then(|()| Ok(std::fs::read(&path)?));
+    // Only the bytes are kept; a file that failed to build is dropped too
+    let _ = std::fs::remove_file(&path);
+
+    result
+}
+
+#[cfg(not(feature = "sqlite"))]
+pub fn sqlite_file(
+    _tables: &[ExportTable],
+    _manifest: &[(&str, String)],
+) -> anyhow::Result<Vec<u8>> {
+    anyhow::bail!("psgc was built without the `sqlite` feature, so it can't build SQLite files")
+}
+
+#[cfg(feature = "sqlite")]
+fn write_sqlite(
+    path: &std::path::Path,
+    tables: &[ExportTable],
+    manifest: &[(&str, String)],
+) -> anyhow::Result<()> {
+    use rusqlite::{Connection, params_from_iter, types::Value};
+
+    let mut conn = Connection::open(path)?;
+    conn.execute_batch("PRAGMA journal_mode = OFF; PRAGMA synchronous = OFF;")?;
+    let tx = conn.transaction()?;
+
+    tx.execute(
+        "CREATE TABLE manifest (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+        [],
+    )?;
+    for (key, value) in manifest {
+        tx.execute(
+            "INSERT INTO manifest (key, value) VALUES (?1, ?2)",
+            (key, value),
+        )?;
+    }
+
+    for table in tables {
+        // Fields in the order first seen, so every unit fits even if some omit a field
+        let mut columns: Vec<&str> = Vec::new();
+        for row in table.rows {
+            for key in row.as_object().into_iter().flat_map(|row| row.keys()) {
+                if !columns.contains(&key.as_str()) {
+                    columns.push(key);
+                }
+            }
+        }
+        // A level without units, e.g. barangays in some releases, still gets its table
+        if columns.is_empty() {
+            columns.push("code");
+        }
+
+        let quoted: Vec<String> = columns.iter().map(|c| format!("\"{c}\"")).collect();
+        tx.execute(
+            &format!("CREATE TABLE {} ({})", table.name, quoted.join(", ")),
+            [],
+        )?;
+        if columns.contains(&"code") {
+            tx.execute(
+                &format!("CREATE INDEX {0}_code ON {0} (code)", table.name),
+                [],
+            )?;
+        }
+
+        let placeholders = vec!["?"; columns.len()].join(", ");
+        let mut insert = tx.prepare(&format!(
+            "INSERT INTO {} ({}) VALUES ({placeholders})",
+            table.name,
+            quoted.join(", ")
+        ))?;
+        for row in table.rows {
+            let values = columns.iter().map(|column| match row.get(*column) {
+                None | Some(serde_json::Value::Null) => Value::Null,
+                Some(serde_json::Value::Bool(b)) => Value::Integer(i64::from(*b)),
+                Some(serde_json::Value::Number(n)) => n
+                    .as_i64()
+                    .map(Value::Integer)
+                    .unwrap_or_else(|| Value::Real(n.as_f64().unwrap_or_default())),
+                Some(serde_json::Value::String(s)) => Value::Text(s.clone()),
+                Some(nested) => Value::Text(nested.to_string()),
+            });
+            insert.execute(params_from_iter(values))?;
+        }
+    }
+
+    tx.commit()?;
+    conn.close().map_err(|(_, e)| e)?;
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_a_table_per_level() {
+        let regions = [
+            serde_json::json!({"code": "0100000000", "name": "Region I", "former_names": []}),
+            serde_json::json!({"code": "0200000000", "name": "Region II", "population": 5}),
+        ];
+        let bytes = sqlite_file(
+            &[ExportTable {
+                name: "regions",
+                rows: &regions,
+            }],
+            &[("release", "2024Q3".to_string())],
+        )
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!("psgc-test-{}.sqlite", uuid::Uuid::now_v7()));
+        std::fs::write(&path, bytes).unwrap();
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        let release: String = conn
+            .query_row(
+                "SELECT value FROM manifest WHERE key = 'release'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        let (name, population): (String, Option<i64>) = conn
+            .query_row(
+                "SELECT name, population FROM regions WHERE code = '0200000000'",
+                [],
+                |r| Ok((r.get(0)?, r.get(1)?)),
+            )
+            .unwrap();
+        drop(conn);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(release, "2024Q3");
+        assert_eq!((name.as_str(), population), ("Region II", Some(5)));
+    }
+}
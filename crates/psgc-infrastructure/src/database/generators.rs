@@ -55,6 +55,72 @@ impl DateTimeUtcExt for rbatis::rbdc::DateTime {
     }
 }
 
+pub trait ChronoDateTimeExt {
+    fn into_db(self) -> rbatis::rbdc::DateTime;
+}
+
+impl ChronoDateTimeExt for chrono::DateTime<Utc> {
+    fn into_db(self) -> rbatis::rbdc::DateTime {
+        rbatis::rbdc::DateTime::from_timestamp_millis(self.timestamp_millis())
+    }
+}
+
+pub trait RBatisDateExt {
+    fn inner(&self) -> chrono::NaiveDate;
+}
+
+impl RBatisDateExt for rbatis::rbdc::Date {
+    fn inner(&self) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_str(&self.0.to_string()).unwrap()
+    }
+}
+
+pub trait NaiveDateExt {
+    fn into_db(self) -> rbatis::rbdc::Date;
+}
+
+impl NaiveDateExt for chrono::NaiveDate {
+    fn into_db(self) -> rbatis::rbdc::Date {
+        rbatis::rbdc::Date::from_str(&self.to_string()).unwrap()
+    }
+}
+
+/// Reads a boolean column, which SQLite stores as `0` or `1`.
+pub fn deserialize_db_bool<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<bool, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum DbBool {
+        Bool(bool),
+        Int(i64),
+    }
+
+    match serde::Deserialize::deserialize(deserializer)? {
+        DbBool::Bool(value) => Ok(value),
+        DbBool::Int(value) => Ok(value != 0),
+    }
+}
+
+/// Reads a JSON text column, which SQLite hands back already parsed when it holds an
+/// object.
+pub fn deserialize_json_text<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum JsonText {
+        Text(String),
+        Parsed(serde_json::Value),
+    }
+
+    Ok(match serde::Deserialize::deserialize(deserializer)? {
+        Some(JsonText::Text(text)) => Some(text),
+        Some(JsonText::Parsed(value)) => Some(value.to_string()),
+        None => None,
+    })
+}
+
 pub trait PageExt<T: Send + Sync> {
     fn into_domain<E: Send + Sync + From<T>>(self) -> PaginateResult<E>;
 }
@@ -1,82 +1,118 @@
 use std::collections::HashMap;
 
-use rbatis::{executor::RBatisConnExecutor, rbdc::Uuid};
+use psgc_shared::text::slugify;
+use rbatis::rbdc::Uuid;
 
 use crate::database::models::{
     city::City, municipality::Municipality, province::Province, region::Region,
 };
 
-pub async fn get_region_map(executor: &mut RBatisConnExecutor) -> HashMap<String, Uuid> {
-    Region::select_all(executor)
-        .await
-        .unwrap()
+pub fn region_map(regions: &[Region]) -> HashMap<String, Uuid> {
+    regions
         .iter()
-        .fold(HashMap::new(), |mut map, r| {
-            let id = r.id.clone();
-            let code = r.correspondence_code.clone();
-            let code = &code[0..2];
+        .map(|r| (r.correspondence_code[0..2].to_string(), r.id.clone()))
+        .collect()
+}
 
-            map.insert(code.to_string(), id);
-            map
-        })
+pub fn province_map(provinces: &[Province]) -> HashMap<String, Uuid> {
+    provinces
+        .iter()
+        .map(|p| (p.correspondence_code[0..4].to_string(), p.id.clone()))
+        .collect()
 }
 
-pub async fn get_province_map(executor: &mut RBatisConnExecutor) -> HashMap<String, Uuid> {
-    Province::select_all(executor)
-        .await
-        .unwrap()
+pub fn province_map_2(provinces: &[Province]) -> HashMap<String, Uuid> {
+    provinces
         .iter()
-        .fold(HashMap::new(), |mut map, p| {
-            let id = p.id.clone();
-            let code = p.correspondence_code.clone();
-            let code = &code[0..4];
+        .map(|p| (p.code[0..4].to_string(), p.id.clone()))
+        .collect()
+}
 
-            map.insert(code.to_string(), id);
-            map
-        })
+pub fn city_map(cities: &[City]) -> HashMap<String, Uuid> {
+    cities
+        .iter()
+        .map(|c| (c.correspondence_code[0..5].to_string(), c.id.clone()))
+        .collect()
 }
 
-pub async fn get_province_map_2(executor: &mut RBatisConnExecutor) -> HashMap<String, Uuid> {
-    Province::select_all(executor)
-        .await
-        .unwrap()
+pub fn municipality_map(municipalities: &[Municipality]) -> HashMap<String, Uuid> {
+    municipalities
         .iter()
-        .fold(HashMap::new(), |mut map, p| {
-            let id = p.id.clone();
-            let code = p.code.clone();
-            let code = &code[0..4];
+        .map(|m| (m.correspondence_code[0..8].to_string(), m.id.clone()))
+        .collect()
+}
 
-            map.insert(code.to_string(), id);
-            map
-        })
+pub fn region_slug_map(regions: &[Region]) -> HashMap<Uuid, String> {
+    regions
+        .iter()
+        .filter_map(|r| r.slug.clone().map(|slug| (r.id.clone(), slug)))
+        .collect()
 }
 
-pub async fn get_city_map(executor: &mut RBatisConnExecutor) -> HashMap<String, Uuid> {
-    City::select_all(executor)
-        .await
-        .unwrap()
+pub fn province_slug_map(provinces: &[Province]) -> HashMap<Uuid, String> {
+    provinces
         .iter()
-        .fold(HashMap::new(), |mut map, c| {
-            let id = c.id.clone();
-            let code = c.correspondence_code.clone();
-            let code = &code[0..5];
+        .filter_map(|p| p.slug.clone().map(|slug| (p.id.clone(), slug)))
+        .collect()
+}
 
-            map.insert(code.to_string(), id);
-            map
-        })
+pub fn city_slug_map(cities: &[City]) -> HashMap<Uuid, String> {
+    cities
+        .iter()
+        .filter_map(|c| c.slug.clone().map(|slug| (c.id.clone(), slug)))
+        .collect()
 }
 
-pub async fn get_municipality_map(executor: &mut RBatisConnExecutor) -> HashMap<String, Uuid> {
-    Municipality::select_all(executor)
-        .await
-        .unwrap()
+pub fn municipality_slug_map(municipalities: &[Municipality]) -> HashMap<Uuid, String> {
+    municipalities
         .iter()
-        .fold(HashMap::new(), |mut map, c| {
-            let id = c.id.clone();
-            let code = c.correspondence_code.clone();
-            let code = &code[0..8];
+        .filter_map(|m| m.slug.clone().map(|slug| (m.id.clone(), slug)))
+        .collect()
+}
+
+pub struct SlugCandidate<'a> {
+    pub name: &'a str,
+    /// Parent slug used to tell apart units sharing a name, e.g. `san-jose-batangas`
+    pub qualifier: Option<&'a str>,
+    pub code: &'a str,
+}
 
-            map.insert(code.to_string(), id);
-            map
+/// Assigns a slug to every candidate that is unique within the batch.
+///
+/// Names that are unique keep their plain slug. Shared names are qualified with their
+/// parent's slug, and whatever still collides falls back to appending the PSGC code.
+pub fn assign_unique_slugs(candidates: &[SlugCandidate]) -> Vec<String> {
+    let bases = candidates
+        .iter()
+        .map(|c| slugify(c.name))
+        .collect::<Vec<_>>();
+    let base_counts = count_slugs(&bases);
+
+    let qualified = bases
+        .iter()
+        .zip(candidates)
+        .map(|(base, c)| match c.qualifier {
+            Some(qualifier) if base_counts[base] > 1 && !qualifier.is_empty() => {
+                format!("{base}-{qualifier}")
+            }
+            _ => base.clone(),
         })
+        .collect::<Vec<_>>();
+    let qualified_counts = count_slugs(&qualified);
+
+    qualified
+        .iter()
+        .zip(candidates)
+        .map(|(slug, c)| match qualified_counts[slug] {
+            1 => slug.clone(),
+            _ => format!("{slug}-{}", c.code),
+        })
+        .collect()
+}
+
+fn count_slugs(slugs: &[String]) -> HashMap<&String, usize> {
+    slugs.iter().fold(HashMap::new(), |mut counts, slug| {
+        *counts.entry(slug).or_insert(0) += 1;
+        counts
+    })
 }
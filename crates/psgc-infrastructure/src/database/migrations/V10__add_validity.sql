@@ -0,0 +1,15 @@
+-- When each unit existed: from valid_from, or since before the records when it's unset,
+-- until the day before valid_to, or still when it's unset. Abolished units keep their row.
+ALTER TABLE regions ADD COLUMN valid_from DATE;
+ALTER TABLE provinces ADD COLUMN valid_from DATE;
+ALTER TABLE districts ADD COLUMN valid_from DATE;
+ALTER TABLE cities ADD COLUMN valid_from DATE;
+ALTER TABLE municipalities ADD COLUMN valid_from DATE;
+ALTER TABLE barangays ADD COLUMN valid_from DATE;
+
+ALTER TABLE regions ADD COLUMN valid_to DATE;
+ALTER TABLE provinces ADD COLUMN valid_to DATE;
+ALTER TABLE districts ADD COLUMN valid_to DATE;
+ALTER TABLE cities ADD COLUMN valid_to DATE;
+ALTER TABLE municipalities ADD COLUMN valid_to DATE;
+ALTER TABLE barangays ADD COLUMN valid_to DATE;
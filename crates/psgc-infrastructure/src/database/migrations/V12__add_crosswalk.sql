@@ -0,0 +1,12 @@
+-- Curated mappings from codes of earlier PSGC editions to the codes in use now, for codes
+-- that neither the current release nor the lineage table resolves. mapping_type is
+-- identical, renamed, split or merged; a code that maps to several has a row for each.
+CREATE TABLE
+    crosswalk (
+        id UUID PRIMARY KEY,
+        historical_code VARCHAR(10) NOT NULL,
+        current_code VARCHAR(10) NOT NULL,
+        mapping_type VARCHAR(16) NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        UNIQUE (historical_code, current_code)
+    );
@@ -0,0 +1,72 @@
+-- Where each entry stands in the feed of changes. The trigger hands positions out while
+-- holding a lock kept until the transaction ends, so they follow the order entries commit
+-- in: a reader that sees a position has already seen every smaller one. It stamps
+-- created_at under the same lock, so times run in the same order as positions.
+ALTER TABLE audit_log
+ADD COLUMN position BIGINT;
+
+UPDATE audit_log
+SET
+    position = ordered.position
+FROM
+    (
+        SELECT
+            id,
+            ROW_NUMBER() OVER (
+                ORDER BY
+                    id
+            ) AS position
+        FROM
+            audit_log
+    ) ordered
+WHERE
+    audit_log.id = ordered.id;
+
+CREATE SEQUENCE audit_log_position_seq OWNED BY audit_log.position;
+
+SELECT
+    setval (
+        'audit_log_position_seq',
+        COALESCE(MAX(position), 0) + 1,
+        false
+    )
+FROM
+    audit_log;
+
+ALTER TABLE audit_log
+ALTER COLUMN position
+SET NOT NULL;
+
+CREATE UNIQUE INDEX idx_audit_log_position ON audit_log (position);
+
+CREATE FUNCTION assign_audit_position () RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM pg_advisory_xact_lock(hashtext('audit_log_position'));
+    NEW.position := nextval('audit_log_position_seq');
+    NEW.created_at := clock_timestamp();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_position BEFORE INSERT ON audit_log FOR EACH ROW
+EXECUTE FUNCTION assign_audit_position ();
+
+-- Where the feed stood once a release was loaded: the position of its seed run's entry.
+-- Releases loaded before positions existed take the first seed run from their loading on.
+ALTER TABLE releases
+ADD COLUMN position BIGINT NOT NULL DEFAULT 0;
+
+UPDATE releases
+SET
+    position = COALESCE(
+        (
+            SELECT
+                MIN(audit_log.position)
+            FROM
+                audit_log
+            WHERE
+                entity = 'dataset'
+                AND created_at >= releases.loaded_at
+        ),
+        0
+    );
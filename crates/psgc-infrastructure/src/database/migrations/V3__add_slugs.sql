@@ -0,0 +1,22 @@
+-- Slugs and folded names are derived by the seeder (see psgc_shared::text)
+ALTER TABLE regions ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+ALTER TABLE provinces ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+ALTER TABLE districts ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+ALTER TABLE cities ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+ALTER TABLE municipalities ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+ALTER TABLE barangays ADD COLUMN slug VARCHAR(255), ADD COLUMN normalized_name VARCHAR(255);
+
+-- Slugs are unique per level
+CREATE UNIQUE INDEX idx_region_slug ON regions(slug);
+CREATE UNIQUE INDEX idx_province_slug ON provinces(slug);
+CREATE UNIQUE INDEX idx_district_slug ON districts(slug);
+CREATE UNIQUE INDEX idx_city_slug ON cities(slug);
+CREATE UNIQUE INDEX idx_municipality_slug ON municipalities(slug);
+CREATE UNIQUE INDEX idx_barangay_slug ON barangays(slug);
+
+CREATE INDEX idx_region_normalized_name ON regions(normalized_name);
+CREATE INDEX idx_province_normalized_name ON provinces(normalized_name);
+CREATE INDEX idx_district_normalized_name ON districts(normalized_name);
+CREATE INDEX idx_city_normalized_name ON cities(normalized_name);
+CREATE INDEX idx_municipality_normalized_name ON municipalities(normalized_name);
+CREATE INDEX idx_barangay_normalized_name ON barangays(normalized_name);
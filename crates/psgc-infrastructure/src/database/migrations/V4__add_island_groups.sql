@@ -0,0 +1,10 @@
+-- Island groups sit above regions and are derived from the region prefix of the code
+ALTER TABLE regions ADD COLUMN island_group VARCHAR(16);
+
+UPDATE regions SET island_group = CASE
+    WHEN LEFT(code, 2) IN ('01', '02', '03', '04', '05', '13', '14', '17') THEN 'luzon'
+    WHEN LEFT(code, 2) IN ('06', '07', '08', '18') THEN 'visayas'
+    WHEN LEFT(code, 2) IN ('09', '10', '11', '12', '16', '19') THEN 'mindanao'
+END;
+
+CREATE INDEX idx_region_island_group ON regions(island_group);
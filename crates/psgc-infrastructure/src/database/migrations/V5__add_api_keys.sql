@@ -0,0 +1,22 @@
+-- Keys are stored as SHA-256 digests; allowed_origins is a comma-separated list
+CREATE TABLE
+    api_keys (
+        id UUID PRIMARY KEY,
+        name VARCHAR(255) NOT NULL UNIQUE,
+        prefix VARCHAR(16) NOT NULL,
+        key_hash VARCHAR(64) NOT NULL UNIQUE,
+        rate_limit_per_minute INTEGER NOT NULL,
+        daily_quota BIGINT,
+        allowed_origins TEXT NOT NULL DEFAULT '',
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW (),
+        revoked_at TIMESTAMPTZ
+    );
+
+-- Requests per key per UTC day, with the day as YYYY-MM-DD
+CREATE TABLE
+    api_key_usage (
+        api_key_id UUID NOT NULL REFERENCES api_keys (id) ON DELETE CASCADE,
+        day VARCHAR(10) NOT NULL,
+        requests BIGINT NOT NULL DEFAULT 0,
+        PRIMARY KEY (api_key_id, day)
+    );
@@ -0,0 +1,21 @@
+-- One row per change to the data, written in the same transaction as the change.
+-- before_data and after_data hold the unit as JSON text and are NULL on create and
+-- delete respectively.
+CREATE TABLE
+    audit_log (
+        id UUID PRIMARY KEY,
+        actor VARCHAR(255) NOT NULL,
+        entity VARCHAR(32) NOT NULL,
+        entity_id UUID NOT NULL,
+        code VARCHAR(10) NOT NULL,
+        before_data TEXT,
+        after_data TEXT,
+        reason TEXT,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+CREATE INDEX idx_audit_log_code ON audit_log (code);
+
+CREATE INDEX idx_audit_log_created_at ON audit_log (created_at);
+
+CREATE INDEX idx_audit_log_entity ON audit_log (entity, entity_id);
@@ -0,0 +1,48 @@
+-- One row per PSGC release loaded by the seeder, e.g. 2024Q3. Ids sort in release order.
+CREATE TABLE
+    releases (
+        id VARCHAR(16) PRIMARY KEY,
+        loaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW ()
+    );
+
+-- Rows seeded before releases existed came from the bundled data
+INSERT INTO releases (id)
+SELECT '2024Q3'
+WHERE EXISTS (SELECT 1 FROM regions);
+
+ALTER TABLE regions ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+ALTER TABLE provinces ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+ALTER TABLE districts ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+ALTER TABLE cities ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+ALTER TABLE municipalities ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+ALTER TABLE barangays ADD COLUMN release_id VARCHAR(16) NOT NULL DEFAULT '2024Q3' REFERENCES releases (id) ON DELETE CASCADE;
+
+ALTER TABLE regions ALTER COLUMN release_id DROP DEFAULT;
+ALTER TABLE provinces ALTER COLUMN release_id DROP DEFAULT;
+ALTER TABLE districts ALTER COLUMN release_id DROP DEFAULT;
+ALTER TABLE cities ALTER COLUMN release_id DROP DEFAULT;
+ALTER TABLE municipalities ALTER COLUMN release_id DROP DEFAULT;
+ALTER TABLE barangays ALTER COLUMN release_id DROP DEFAULT;
+
+-- Slugs are unique per level within a release
+DROP INDEX idx_region_slug;
+DROP INDEX idx_province_slug;
+DROP INDEX idx_district_slug;
+DROP INDEX idx_city_slug;
+DROP INDEX idx_municipality_slug;
+DROP INDEX idx_barangay_slug;
+
+CREATE UNIQUE INDEX idx_region_release_slug ON regions(release_id, slug);
+CREATE UNIQUE INDEX idx_province_release_slug ON provinces(release_id, slug);
+CREATE UNIQUE INDEX idx_district_release_slug ON districts(release_id, slug);
+CREATE UNIQUE INDEX idx_city_release_slug ON cities(release_id, slug);
+CREATE UNIQUE INDEX idx_municipality_release_slug ON municipalities(release_id, slug);
+CREATE UNIQUE INDEX idx_barangay_release_slug ON barangays(release_id, slug);
+
+-- So are codes; the admin API checks first, and these stop two writes racing past it
+CREATE UNIQUE INDEX idx_region_release_code ON regions(release_id, code);
+CREATE UNIQUE INDEX idx_province_release_code ON provinces(release_id, code);
+CREATE UNIQUE INDEX idx_district_release_code ON districts(release_id, code);
+CREATE UNIQUE INDEX idx_city_release_code ON cities(release_id, code);
+CREATE UNIQUE INDEX idx_municipality_release_code ON municipalities(release_id, code);
+CREATE UNIQUE INDEX idx_barangay_release_code ON barangays(release_id, code);
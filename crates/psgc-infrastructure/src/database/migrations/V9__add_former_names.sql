@@ -0,0 +1,15 @@
+-- Names a province, city, municipality or barangay was known by before, taken from the
+-- oldName field of the PSA data. unit_id points into the table named by level.
+CREATE TABLE
+    former_names (
+        id UUID PRIMARY KEY,
+        release_id VARCHAR(16) NOT NULL REFERENCES releases (id) ON DELETE CASCADE,
+        level VARCHAR(16) NOT NULL,
+        unit_id UUID NOT NULL,
+        name VARCHAR(255) NOT NULL,
+        normalized_name VARCHAR(255) NOT NULL
+    );
+
+CREATE INDEX idx_former_names_unit_id ON former_names (unit_id);
+
+CREATE INDEX idx_former_names_normalized_name ON former_names (release_id, level, normalized_name);
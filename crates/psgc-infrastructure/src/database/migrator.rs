@@ -9,7 +9,19 @@ mod embedded {
     embed_migrations!("./src/database/migrations/");
 }
 
+/// SQLite mirrors of the Postgres migrations; kept outside `migrations/` because refinery
+/// walks that directory recursively.
+#[cfg(feature = "sqlite")]
+mod embedded_sqlite {
+    use refinery::embed_migrations;
+    embed_migrations!("./src/database/sqlite_migrations/");
+}
+
 pub async fn migrator(config: &DatabaseConfig) -> anyhow::Result<()> {
+    if config.is_sqlite() {
+        return migrate_sqlite(config);
+    }
+
     let conn_url = &config.db_url;
     let manager = PostgresConnectionManager::new_from_stringlike(conn_url, NoTls)?;
     let pool = Pool::builder().max_size(15).build(manager).await?;
@@ -23,3 +35,59 @@ pub async fn migrator(config: &DatabaseConfig) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "sqlite")]
+fn migrate_sqlite(config: &DatabaseConfig) -> anyhow::Result<()> {
+    let mut conn = rusqlite::Connection::open(config.sqlite_path())?;
+
+    let report = run_sqlite_migrations(&mut conn)?;
+
+    for migrated in report.applied_migrations() {
+        println!("Applied migration: {}", migrated);
+    }
+
+    Ok(())
+}
+
+/// Foreign keys are off while migrating, as SQLite needs to add a reference to an existing
+/// table; the pool turns them back on for every connection it opens.
+#[cfg(feature = "sqlite")]
+pub(crate) fn run_sqlite_migrations(
+    conn: &mut rusqlite::Connection,
+) -> anyhow::Result<refinery::Report> {
+    conn.pragma_update(None, "foreign_keys", false)?;
+
+    Ok(embedded_sqlite::migrations::runner().run(conn)?)
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn migrate_sqlite(_config: &DatabaseConfig) -> anyhow::Result<()> {
+    anyhow::bail!("sqlite DATABASE_URL given but psgc was built without the `sqlite` feature")
+}
+
+/// Highest migration version embedded in this build, i.e. what a current schema reports.
+pub(crate) fn latest_migration_version(sqlite: bool) -> i64 {
+    let migrations = if sqlite {
+        latest_sqlite_migrations()
+    } else {
+        embedded::migrations::runner().get_migrations().clone()
+    };
+
+    migrations
+        .iter()
+        .map(|migration| i64::from(migration.version()))
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "sqlite")]
+fn latest_sqlite_migrations() -> Vec<refinery::Migration> {
+    embedded_sqlite::migrations::runner()
+        .get_migrations()
+        .clone()
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn latest_sqlite_migrations() -> Vec<refinery::Migration> {
+    Vec::new()
+}
@@ -1,9 +1,11 @@
+pub mod export;
 pub(crate) mod generators;
 pub(crate) mod helpers;
 pub(crate) mod mappers;
 pub mod migrator;
 pub(crate) mod models;
 pub mod pool;
+pub mod seed_data;
 pub mod seeder;
 
 #[allow(dead_code)]
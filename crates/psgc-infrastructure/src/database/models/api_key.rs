@@ -0,0 +1,114 @@
+use rbatis::executor::RBatisConnExecutor;
+use serde::{Deserialize, Serialize};
+
+use crate::database::generators::{DateTimeUtcExt, RBatisUuidExt, UuidExt, deserialize_db_bool};
+
+#[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
+pub struct ApiKey {
+    pub id: rbatis::rbdc::Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub key_hash: String,
+    pub rate_limit_per_minute: u32,
+    pub daily_quota: Option<u64>,
+    /// Comma-separated, empty for any origin
+    pub allowed_origins: String,
+    #[serde(deserialize_with = "deserialize_db_bool")]
+    pub admin: bool,
+    pub created_at: rbatis::rbdc::DateTime,
+    pub revoked_at: Option<rbatis::rbdc::DateTime>,
+}
+
+rbatis::crud!(ApiKey {}, "api_keys");
+rbatis::impl_select!(ApiKey {select_by_key_hash(key_hash: &str) -> Option => "`where key_hash = #{key_hash} limit 1`"}, "api_keys");
+rbatis::impl_select!(ApiKey {list_all() => "`order by created_at, name`"}, "api_keys");
+
+#[derive(Debug, Serialize, Deserialize)]
+struct UsageRow {
+    requests: u64,
+}
+
+/// Marks the key as revoked and returns how many keys were affected.
+pub async fn revoke_api_key(
+    executor: &RBatisConnExecutor,
+    name: &str,
+) -> Result<u64, rbatis::Error> {
+    let result = executor
+        .exec(
+            "UPDATE api_keys SET revoked_at = ? WHERE name = ? AND revoked_at IS NULL",
+            vec![
+                rbs::value!(rbatis::rbdc::DateTime::utc()),
+                rbs::Value::String(name.to_string()),
+            ],
+        )
+        .await?;
+
+    Ok(result.rows_affected)
+}
+
+/// Adds one request to the key's count for `day` and returns the new count.
+pub async fn increment_usage(
+    executor: &RBatisConnExecutor,
+    api_key_id: uuid::Uuid,
+    day: &str,
+) -> Result<u64, rbatis::Error> {
+    let rows: Vec<UsageRow> = executor
+        .query_decode(
+            "INSERT INTO api_key_usage (api_key_id, day, requests) VALUES (?, ?, 1) \
+            ON CONFLICT (api_key_id, day) DO UPDATE SET requests = api_key_usage.requests + 1 \
+            RETURNING requests",
+            vec![
+                rbs::value!(api_key_id.into_db()),
+                rbs::Value::String(day.to_string()),
+            ],
+        )
+        .await?;
+
+    Ok(rows.first().map(|row| row.requests).unwrap_or_default())
+}
+
+impl From<ApiKey> for psgc_domain::models::api_key::ApiKey {
+    fn from(value: ApiKey) -> Self {
+        let allowed_origins = value
+            .allowed_origins
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        Self::builder()
+            .id(value.id.inner())
+            .name(value.name)
+            .prefix(value.prefix)
+            .key_hash(value.key_hash)
+            .rate_limit_per_minute(value.rate_limit_per_minute)
+            .maybe_daily_quota(value.daily_quota)
+            .allowed_origins(allowed_origins)
+            .admin(value.admin)
+            .created_at(value.created_at.inner())
+            .maybe_revoked_at(value.revoked_at.map(|revoked_at| revoked_at.inner()))
+            .build()
+    }
+}
+
+impl From<&psgc_domain::models::api_key::ApiKey> for ApiKey {
+    fn from(value: &psgc_domain::models::api_key::ApiKey) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .name(value.name.clone())
+            .prefix(value.prefix.clone())
+            .key_hash(value.key_hash.clone())
+            .rate_limit_per_minute(value.rate_limit_per_minute)
+            .maybe_daily_quota(value.daily_quota)
+            .allowed_origins(value.allowed_origins.join(","))
+            .admin(value.admin)
+            .created_at(rbatis::rbdc::DateTime::from_timestamp_millis(
+                value.created_at.timestamp_millis(),
+            ))
+            .maybe_revoked_at(value.revoked_at.map(|revoked_at| {
+                rbatis::rbdc::DateTime::from_timestamp_millis(revoked_at.timestamp_millis())
+            }))
+            .build()
+    }
+}
@@ -0,0 +1,156 @@
+use rbatis::executor::RBatisConnExecutor;
+use serde::{Deserialize, Serialize};
+
+use crate::database::generators::{
+    ChronoDateTimeExt, DateTimeUtcExt, RBatisUuidExt, UuidExt, deserialize_json_text,
+};
+
+#[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
+pub struct AuditLog {
+    pub id: rbatis::rbdc::Uuid,
+    pub actor: String,
+    pub entity: String,
+    pub entity_id: rbatis::rbdc::Uuid,
+    pub code: String,
+    /// JSON text
+    #[serde(deserialize_with = "deserialize_json_text")]
+    pub before_data: Option<String>,
+    /// JSON text
+    #[serde(deserialize_with = "deserialize_json_text")]
+    pub after_data: Option<String>,
+    pub reason: Option<String>,
+    /// Left out of inserts, so the database hands it out
+    pub position: Option<i64>,
+    pub created_at: rbatis::rbdc::DateTime,
+}
+
+/// An empty code matches every code.
+#[derive(Debug, Serialize)]
+pub struct AuditFilter {
+    pub code: String,
+    pub since: rbatis::rbdc::DateTime,
+}
+
+rbatis::crud!(AuditLog {}, "audit_log");
+rbatis::impl_select_page!(AuditLog {list_filtered(filter: &AuditFilter) => "`where (#{filter.code} = '' or code = #{filter.code}) and created_at >= #{filter.since} order by created_at desc, id desc`"}, "audit_log");
+rbatis::impl_select!(AuditLog {select_history(entity: &str, code: &str) => "`where entity = #{entity} and entity_id in (select entity_id from audit_log where entity = #{entity} and code = #{code}) order by created_at, id`"}, "audit_log");
+
+/// Tables of the units whose entries make up the feed of changes.
+const UNIT_TABLES: [&str; 6] = [
+    "regions",
+    "provinces",
+    "districts",
+    "cities",
+    "municipalities",
+    "barangays",
+];
+
+/// Ids of the units of a release, taking the release once per table.
+fn unit_ids_sql() -> String {
+    UNIT_TABLES
+        .iter()
+        .map(|table| format!("SELECT id FROM {table} WHERE release_id = ?"))
+        .collect::<Vec<_>>()
+        .join(" UNION ALL ")
+}
+
+fn release_args(release: &str) -> impl Iterator<Item = rbs::Value> {
+    UNIT_TABLES
+        .map(|_| rbs::Value::String(release.to_string()))
+        .into_iter()
+}
+
+/// Up to `limit` entries positioned after `after` for units of `release`, oldest first. An
+/// entry's `entity_id` is the id of the unit row, which belongs to one release.
+pub async fn select_unit_changes(
+    executor: &RBatisConnExecutor,
+    release: &str,
+    after: i64,
+    limit: u64,
+) -> Result<Vec<AuditLog>, rbatis::Error> {
+    let sql = format!(
+        "SELECT * FROM audit_log WHERE position > ? AND entity_id IN ({}) ORDER BY position LIMIT {limit}",
+        unit_ids_sql()
+    );
+    let mut args = vec![rbs::value!(after)];
+    args.extend(release_args(release));
+
+    executor.query_decode(&sql, args).await
+}
+
+/// The newest entry for a unit of `release`.
+pub async fn select_last_unit_change(
+    executor: &RBatisConnExecutor,
+    release: &str,
+) -> Result<Option<AuditLog>, rbatis::Error> {
+    let sql = format!(
+        "SELECT * FROM audit_log WHERE entity_id IN ({}) ORDER BY position DESC LIMIT 1",
+        unit_ids_sql()
+    );
+    let entries: Vec<AuditLog> = executor
+        .query_decode(&sql, release_args(release).collect())
+        .await?;
+
+    Ok(entries.into_iter().next())
+}
+
+#[derive(Debug, Deserialize)]
+struct PositionRow {
+    position: i64,
+}
+
+/// The position of the last entry made before `time`, or 0. The trigger that hands out
+/// positions also stamps `created_at`, so every later entry has a larger position.
+pub async fn select_position_before(
+    executor: &RBatisConnExecutor,
+    time: &rbatis::rbdc::DateTime,
+) -> Result<i64, rbatis::Error> {
+    let rows: Vec<PositionRow> = executor
+        .query_decode(
+            "SELECT CAST(COALESCE(MAX(position), 0) AS BIGINT) AS position FROM audit_log WHERE created_at < ?",
+            vec![rbs::value!(time)],
+        )
+        .await?;
+
+    Ok(rows.first().map_or(0, |row| row.position))
+}
+
+/// Entries whose JSON no longer parses keep the rest of the entry rather than failing the
+/// whole page.
+fn parse_json(value: Option<String>) -> Option<serde_json::Value> {
+    value.and_then(|value| serde_json::from_str(&value).ok())
+}
+
+impl From<AuditLog> for psgc_domain::models::audit::AuditEntry {
+    fn from(value: AuditLog) -> Self {
+        Self::builder()
+            .id(value.id.inner())
+            .actor(value.actor)
+            .entity(value.entity)
+            .entity_id(value.entity_id.inner())
+            .code(value.code)
+            .maybe_before(parse_json(value.before_data))
+            .maybe_after(parse_json(value.after_data))
+            .maybe_reason(value.reason)
+            .maybe_position(value.position)
+            .created_at(value.created_at.inner())
+            .build()
+    }
+}
+
+impl From<&psgc_domain::models::audit::AuditEntry> for AuditLog {
+    fn from(value: &psgc_domain::models::audit::AuditEntry) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .actor(value.actor.clone())
+            .entity(value.entity.clone())
+            .entity_id(value.entity_id.into_db())
+            .code(value.code.clone())
+            .maybe_before_data(value.before.as_ref().map(|before| before.to_string()))
+            .maybe_after_data(value.after.as_ref().map(|after| after.to_string()))
+            .maybe_reason(value.reason.clone())
+            .maybe_position(value.position)
+            .created_at(value.created_at.into_db())
+            .build()
+    }
+}
@@ -1,42 +1,74 @@
 use crate::database::{
     DatabaseSeedError,
-    generators::{DateTimeUtcExt, RBatisUuidExt, datetime_utc_now, uuid_now},
-    helpers::{get_city_map, get_municipality_map},
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+        datetime_utc_now, uuid_now,
+    },
+    helpers::{
+        SlugCandidate, assign_unique_slugs, city_map, city_slug_map, municipality_map,
+        municipality_slug_map,
+    },
+    models::{
+        ListScope, city::City, former_name::FormerName, municipality::Municipality, update_by_id,
+    },
+    seed_data::SeedData,
 };
+use psgc_shared::text::fold_name;
 use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
-use tracing::info;
 
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct Barangay {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub population: u64,
     pub urban_rural: String,
     pub city_id: Option<rbatis::rbdc::Uuid>,
     pub municipality_id: Option<rbatis::rbdc::Uuid>,
     pub district_id: Option<rbatis::rbdc::Uuid>,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 impl Barangay {
     #[rbatis::py_sql(
-        "SELECT b.* FROM barangays b LEFT JOIN cities c ON b.city_id = c.id WHERE c.code = #{code}"
+        "SELECT b.* FROM barangays b LEFT JOIN cities c ON b.city_id = c.id WHERE c.release_id = #{release} AND c.code = #{code}"
     )]
-    async fn list_barangays_by_city_code(rb: &dyn Executor, code: &str) -> Vec<Barangay> {}
+    async fn list_barangays_by_city_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<Barangay> {
+    }
 
     #[rbatis::py_sql(
-        "SELECT b.* FROM barangays b LEFT JOIN municipalities m ON b.municipality_id = m.id WHERE m.code = #{code}"
+        "SELECT b.* FROM barangays b LEFT JOIN municipalities m ON b.municipality_id = m.id WHERE m.release_id = #{release} AND m.code = #{code}"
     )]
-    async fn list_barangays_by_municipality_code(rb: &dyn Executor, code: &str) -> Vec<Barangay> {}
+    async fn list_barangays_by_municipality_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<Barangay> {
+    }
 
     #[rbatis::py_sql(
-        "SELECT b.* FROM barangays b LEFT JOIN districts d ON b.district_id = d.id WHERE d.code = #{code}"
+        "SELECT b.* FROM barangays b LEFT JOIN districts d ON b.district_id = d.id WHERE d.release_id = #{release} AND d.code = #{code}"
     )]
-    async fn list_barangays_by_district_code(rb: &dyn Executor, code: &str) -> Vec<Barangay> {}
+    async fn list_barangays_by_district_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<Barangay> {
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, bon::Builder)]
@@ -48,30 +80,35 @@ struct BarangayData {
     code: String,
     #[serde(rename = "urbanRural")]
     urban_rural: String,
+    #[serde(rename = "oldName", default)]
+    old_name: String,
 }
 
 rbatis::crud!(Barangay {}, "barangays");
-rbatis::impl_select_page!(Barangay {list_barangays() => ""}, "barangays");
-
-rbatis::impl_select!(Barangay {select_by_code(code: &str) -> Option => "`where code = #{code} limit 1`"}, "barangays");
-
-pub async fn seed_barangays(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError> {
-    info!("Seeding barangays...");
-
-    let mut executor = db
-        .acquire()
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    let city_map = get_city_map(&mut executor).await;
-    let municipality_map = get_municipality_map(&mut executor).await;
+rbatis::impl_select_page!(Barangay {list_barangays(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "barangays");
+rbatis::impl_select_page!(Barangay {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and (city_id in (select c.id from cities c join regions r on c.region_id = r.id where r.island_group = #{scope.island_group}) or municipality_id in (select m.id from municipalities m join regions r on m.region_id = r.id where r.island_group = #{scope.island_group})) order by code, id`"}, "barangays");
+
+rbatis::impl_select!(Barangay {select_by_code(release: &str, code: &str) -> Option => "`where release_id = #{release} and code = #{code} limit 1`"}, "barangays");
+rbatis::impl_select!(Barangay {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "barangays");
+rbatis::impl_select!(Barangay {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and (normalized_name = #{normalized_name} or id in (select unit_id from former_names where release_id = #{release} and level = 'barangay' and normalized_name = #{normalized_name})) order by code`"}, "barangays");
+
+/// Builds barangay rows from the seed data of one release, linked to already built
+/// parents, and the former names given as `oldName`.
+pub fn build_barangays(
+    data: &SeedData,
+    cities: &[City],
+    municipalities: &[Municipality],
+) -> Result<(Vec<Barangay>, Vec<FormerName>), DatabaseSeedError> {
+    let city_map = city_map(cities);
+    let municipality_map = municipality_map(municipalities);
+    let city_slug_map = city_slug_map(cities);
+    let municipality_slug_map = municipality_slug_map(municipalities);
     // TODO: add district mapping
 
-    let barangays_json = include_str!("../data/json/barangays.json");
-    let barangays_data = serde_json::from_str::<Vec<BarangayData>>(barangays_json)
+    let barangays_data = serde_json::from_str::<Vec<BarangayData>>(&data.barangays)
         .map_err(|e| crate::database::DatabaseSeedError::Serialization(e))?;
 
-    let barangays: Vec<Barangay> = barangays_data
+    let parent_ids = barangays_data
         .iter()
         .map(|barangay| {
             let municipality_code = &barangay.correspondence_code[0..8];
@@ -87,9 +124,36 @@ pub async fn seed_barangays(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError
                 }
             };
 
+            (city_id, municipality_id)
+        })
+        .collect::<Vec<_>>();
+
+    let candidates = barangays_data
+        .iter()
+        .zip(&parent_ids)
+        .map(|(barangay, (city_id, municipality_id))| SlugCandidate {
+            name: &barangay.name,
+            qualifier: municipality_id
+                .as_ref()
+                .and_then(|id| municipality_slug_map.get(id))
+                .or_else(|| city_id.as_ref().and_then(|id| city_slug_map.get(id)))
+                .map(String::as_str),
+            code: &barangay.code,
+        })
+        .collect::<Vec<_>>();
+    let slugs = assign_unique_slugs(&candidates);
+
+    let barangays: Vec<Barangay> = barangays_data
+        .iter()
+        .zip(parent_ids)
+        .zip(slugs)
+        .map(|((barangay, (city_id, municipality_id)), slug)| {
             let barangay = Barangay::builder()
                 .id(uuid_now())
+                .release_id(data.release.clone())
                 .name(barangay.name.to_owned())
+                .slug(slug)
+                .normalized_name(fold_name(&barangay.name))
                 .population(0)
                 .code(barangay.code.to_owned())
                 .correspondence_code(barangay.correspondence_code.to_owned())
@@ -105,13 +169,15 @@ pub async fn seed_barangays(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError
         })
         .collect::<Vec<_>>();
 
-    Barangay::insert_batch(&mut executor, &barangays, 1000)
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    info!("Added {} barangays to database", barangays.len());
+    let former_names = barangays_data
+        .iter()
+        .zip(&barangays)
+        .filter_map(|(d, row)| {
+            FormerName::from_seed(&data.release, "barangay", &row.id, &d.old_name)
+        })
+        .collect();
 
-    Ok(())
+    Ok((barangays, former_names))
 }
 
 impl From<Barangay> for psgc_domain::models::barangay::Barangay {
@@ -119,6 +185,7 @@ impl From<Barangay> for psgc_domain::models::barangay::Barangay {
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
@@ -126,8 +193,63 @@ impl From<Barangay> for psgc_domain::models::barangay::Barangay {
             .maybe_city_id(value.city_id.map(|id| id.inner()))
             .maybe_municipality_id(value.municipality_id.map(|id| id.inner()))
             .maybe_district_id(value.district_id.map(|id| id.inner()))
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
     }
 }
+
+impl From<&psgc_domain::models::barangay::Barangay> for Barangay {
+    fn from(value: &psgc_domain::models::barangay::Barangay) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .population(value.population)
+            .urban_rural(value.urban_rural.clone())
+            .maybe_city_id(value.city_id.map(|id| id.into_db()))
+            .maybe_municipality_id(value.municipality_id.map(|id| id.into_db()))
+            .maybe_district_id(value.district_id.map(|id| id.into_db()))
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_barangay(
+    executor: &dyn Executor,
+    barangay: &Barangay,
+) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "barangays",
+        &barangay.id,
+        vec![
+            ("code", rbs::value!(&barangay.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&barangay.correspondence_code),
+            ),
+            ("name", rbs::value!(&barangay.name)),
+            ("slug", rbs::value!(&barangay.slug)),
+            ("normalized_name", rbs::value!(&barangay.normalized_name)),
+            ("population", rbs::value!(barangay.population)),
+            ("urban_rural", rbs::value!(&barangay.urban_rural)),
+            ("city_id", rbs::value!(&barangay.city_id)),
+            ("municipality_id", rbs::value!(&barangay.municipality_id)),
+            ("district_id", rbs::value!(&barangay.district_id)),
+            ("valid_from", rbs::value!(&barangay.valid_from)),
+            ("valid_to", rbs::value!(&barangay.valid_to)),
+            ("updated_at", rbs::value!(&barangay.updated_at)),
+        ],
+    )
+    .await
+}
@@ -1,41 +1,69 @@
 use crate::database::{
-    generators::{DateTimeUtcExt, RBatisUuidExt, datetime_utc_now, uuid_now},
-    helpers::{get_province_map, get_province_map_2, get_region_map},
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+        datetime_utc_now, uuid_now,
+    },
+    helpers::{
+        SlugCandidate, assign_unique_slugs, province_map, province_map_2, province_slug_map,
+        region_map, region_slug_map,
+    },
+    models::{
+        ListScope, former_name::FormerName, province::Province, region::Region, update_by_id,
+    },
+    seed_data::SeedData,
 };
+use psgc_shared::text::fold_name;
 use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
-use tracing::info;
 
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct City {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub population: u64,
     pub city_class: String,
     pub income_class: String,
     pub region_id: Option<rbatis::rbdc::Uuid>,
     pub province_id: Option<rbatis::rbdc::Uuid>,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 impl City {
     #[rbatis::py_sql(
-        "SELECT c.* FROM cities c LEFT JOIN regions r ON c.region_id = r.id WHERE r.code = #{code}"
+        "SELECT c.* FROM cities c LEFT JOIN regions r ON c.region_id = r.id WHERE r.release_id = #{release} AND r.code = #{code}"
     )]
-    async fn list_cities_by_region_code(rb: &dyn Executor, code: &str) -> Vec<City> {}
+    async fn list_cities_by_region_code(rb: &dyn Executor, release: &str, code: &str) -> Vec<City> {
+    }
 
     #[rbatis::py_sql(
-        "SELECT c.* FROM cities c LEFT JOIN provinces p ON c.province_id = p.id WHERE p.code = #{code}"
+        "SELECT c.* FROM cities c LEFT JOIN provinces p ON c.province_id = p.id WHERE p.release_id = #{release} AND p.code = #{code}"
     )]
-    async fn list_cities_by_province_code(rb: &dyn Executor, code: &str) -> Vec<City> {}
+    async fn list_cities_by_province_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<City> {
+    }
 
     #[rbatis::py_sql(
-        "SELECT c.* FROM cities c LEFT JOIN provinces p ON c.province_id = p.id WHERE p.id IS NOT NULL AND EXISTS (SELECT 1 FROM districts d WHERE d.id IN (SELECT DISTINCT district_id FROM municipalities m WHERE m.province_id = p.id) AND d.code = #{code})"
+        "SELECT c.* FROM cities c LEFT JOIN provinces p ON c.province_id = p.id WHERE p.id IS NOT NULL AND EXISTS (SELECT 1 FROM districts d WHERE d.id IN (SELECT DISTINCT district_id FROM municipalities m WHERE m.province_id = p.id) AND d.release_id = #{release} AND d.code = #{code})"
     )]
-    async fn list_cities_by_district_code(rb: &dyn Executor, code: &str) -> Vec<City> {}
+    async fn list_cities_by_district_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<City> {
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, bon::Builder)]
@@ -46,29 +74,34 @@ struct CityData {
     city_class: String,
     income_class: String,
     urban_rural: String,
+    #[serde(rename = "oldName", default)]
+    old_name: String,
 }
 
 rbatis::crud!(City {}, "cities");
-rbatis::impl_select_page!(City {list_cities() => ""}, "cities");
-rbatis::impl_select!(City {select_by_code(code: &str) -> Option => "`where code = #{code} limit 1`"}, "cities");
-
-pub async fn seed_cities(db: &rbatis::RBatis) -> Result<(), crate::database::DatabaseSeedError> {
-    info!("Seeding cities...");
-
-    let mut executor = db
-        .acquire()
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    let region_map = get_region_map(&mut executor).await;
-    let province_map = get_province_map(&mut executor).await;
-    let province_map_by_code = get_province_map_2(&mut executor).await;
-
-    let cities_json = include_str!("../data/json/cities.json");
-    let cities_data = serde_json::from_str::<Vec<CityData>>(cities_json)
+rbatis::impl_select_page!(City {list_cities(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "cities");
+rbatis::impl_select_page!(City {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and region_id in (select id from regions where island_group = #{scope.island_group}) order by code, id`"}, "cities");
+rbatis::impl_select!(City {select_by_code(release: &str, code: &str) -> Option => "`where release_id = #{release} and code = #{code} limit 1`"}, "cities");
+rbatis::impl_select!(City {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "cities");
+rbatis::impl_select!(City {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and (normalized_name = #{normalized_name} or id in (select unit_id from former_names where release_id = #{release} and level = 'city' and normalized_name = #{normalized_name})) order by code`"}, "cities");
+
+/// Builds city rows from the seed data of one release, linked to already built parents,
+/// and the former names given as `oldName`.
+pub fn build_cities(
+    data: &SeedData,
+    regions: &[Region],
+    provinces: &[Province],
+) -> Result<(Vec<City>, Vec<FormerName>), crate::database::DatabaseSeedError> {
+    let region_map = region_map(regions);
+    let province_map = province_map(provinces);
+    let province_map_by_code = province_map_2(provinces);
+    let region_slug_map = region_slug_map(regions);
+    let province_slug_map = province_slug_map(provinces);
+
+    let cities_data = serde_json::from_str::<Vec<CityData>>(&data.cities)
         .map_err(|e| crate::database::DatabaseSeedError::Serialization(e))?;
 
-    let cities: Vec<City> = cities_data
+    let parent_ids = cities_data
         .iter()
         .map(|city| {
             let region_code = &city.correspondence_code[0..2];
@@ -89,9 +122,36 @@ pub async fn seed_cities(db: &rbatis::RBatis) -> Result<(), crate::database::Dat
                 }
             };
 
+            (region_id, province_id)
+        })
+        .collect::<Vec<_>>();
+
+    let candidates = cities_data
+        .iter()
+        .zip(&parent_ids)
+        .map(|(city, (region_id, province_id))| SlugCandidate {
+            name: &city.name,
+            qualifier: province_id
+                .as_ref()
+                .and_then(|id| province_slug_map.get(id))
+                .or_else(|| region_id.as_ref().and_then(|id| region_slug_map.get(id)))
+                .map(String::as_str),
+            code: &city.code,
+        })
+        .collect::<Vec<_>>();
+    let slugs = assign_unique_slugs(&candidates);
+
+    let cities: Vec<City> = cities_data
+        .iter()
+        .zip(parent_ids)
+        .zip(slugs)
+        .map(|((city, (region_id, province_id)), slug)| {
             let city = City::builder()
                 .id(uuid_now())
+                .release_id(data.release.clone())
                 .name(city.name.to_owned())
+                .slug(slug)
+                .normalized_name(fold_name(&city.name))
                 .city_class(city.city_class.to_owned())
                 .income_class(city.income_class.to_owned())
                 .population(0)
@@ -107,13 +167,13 @@ pub async fn seed_cities(db: &rbatis::RBatis) -> Result<(), crate::database::Dat
         })
         .collect::<Vec<_>>();
 
-    City::insert_batch(&mut executor, &cities, 100)
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    info!("Added {} cities to database", cities.len());
+    let former_names = cities_data
+        .iter()
+        .zip(&cities)
+        .filter_map(|(d, row)| FormerName::from_seed(&data.release, "city", &row.id, &d.old_name))
+        .collect();
 
-    Ok(())
+    Ok((cities, former_names))
 }
 
 impl From<City> for psgc_domain::models::city::City {
@@ -121,6 +181,7 @@ impl From<City> for psgc_domain::models::city::City {
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
@@ -128,8 +189,60 @@ impl From<City> for psgc_domain::models::city::City {
             .income_class(value.income_class)
             .maybe_region_id(value.region_id.map(|id| id.inner()))
             .maybe_province_id(value.province_id.map(|id| id.inner()))
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
     }
 }
+
+impl From<&psgc_domain::models::city::City> for City {
+    fn from(value: &psgc_domain::models::city::City) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .population(value.population)
+            .city_class(value.city_class.clone())
+            .income_class(value.income_class.clone())
+            .maybe_region_id(value.region_id.map(|id| id.into_db()))
+            .maybe_province_id(value.province_id.map(|id| id.into_db()))
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_city(executor: &dyn Executor, city: &City) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "cities",
+        &city.id,
+        vec![
+            ("code", rbs::value!(&city.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&city.correspondence_code),
+            ),
+            ("name", rbs::value!(&city.name)),
+            ("slug", rbs::value!(&city.slug)),
+            ("normalized_name", rbs::value!(&city.normalized_name)),
+            ("population", rbs::value!(city.population)),
+            ("city_class", rbs::value!(&city.city_class)),
+            ("income_class", rbs::value!(&city.income_class)),
+            ("region_id", rbs::value!(&city.region_id)),
+            ("province_id", rbs::value!(&city.province_id)),
+            ("valid_from", rbs::value!(&city.valid_from)),
+            ("valid_to", rbs::value!(&city.valid_to)),
+            ("updated_at", rbs::value!(&city.updated_at)),
+        ],
+    )
+    .await
+}
@@ -0,0 +1,66 @@
+use psgc_domain::{errors::RepositoryError, models::crosswalk::CrosswalkEntry};
+use rbatis::executor::RBatisConnExecutor;
+use serde::{Deserialize, Serialize};
+
+use crate::database::generators::{ChronoDateTimeExt, DateTimeUtcExt, RBatisUuidExt, UuidExt};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct Crosswalk {
+    pub id: rbatis::rbdc::Uuid,
+    pub historical_code: String,
+    pub current_code: String,
+    /// `identical`, `renamed`, `split` or `merged`
+    pub mapping_type: String,
+    pub created_at: rbatis::rbdc::DateTime,
+}
+
+rbatis::crud!(Crosswalk {}, "crosswalk");
+
+/// Mappings of any of `codes`.
+pub async fn select_by_historical_codes(
+    executor: &RBatisConnExecutor,
+    codes: &[String],
+) -> Result<Vec<Crosswalk>, rbatis::Error> {
+    if codes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let placeholders = vec!["?"; codes.len()].join(", ");
+    let sql = format!(
+        "SELECT * FROM crosswalk WHERE historical_code IN ({placeholders}) ORDER BY historical_code, current_code"
+    );
+    let args = codes.iter().map(|code| rbs::value!(code)).collect();
+
+    executor.query_decode(&sql, args).await
+}
+
+impl TryFrom<Crosswalk> for CrosswalkEntry {
+    type Error = RepositoryError;
+
+    fn try_from(value: Crosswalk) -> Result<Self, Self::Error> {
+        Ok(Self::builder()
+            .id(value.id.inner())
+            .historical_code(value.historical_code)
+            .current_code(value.current_code)
+            .mapping_type(
+                value
+                    .mapping_type
+                    .parse()
+                    .map_err(RepositoryError::UnexpectedError)?,
+            )
+            .created_at(value.created_at.inner())
+            .build())
+    }
+}
+
+impl From<&CrosswalkEntry> for Crosswalk {
+    fn from(value: &CrosswalkEntry) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .historical_code(value.historical_code.clone())
+            .current_code(value.current_code.clone())
+            .mapping_type(value.mapping_type.as_str().to_string())
+            .created_at(value.created_at.into_db())
+            .build()
+    }
+}
@@ -1,4 +1,10 @@
-use crate::database::generators::{DateTimeUtcExt, RBatisUuidExt};
+use crate::database::{
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+    },
+    models::{ListScope, update_by_id},
+};
+use psgc_shared::text::fold_name;
 use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
 
@@ -6,32 +12,52 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct District {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub population: u64,
     pub region_id: Option<rbatis::rbdc::Uuid>,
     pub province_id: Option<rbatis::rbdc::Uuid>,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 impl District {
     #[rbatis::py_sql(
-        "SELECT d.* FROM districts d LEFT JOIN regions r ON d.region_id = r.id WHERE r.code = #{code}"
+        "SELECT d.* FROM districts d LEFT JOIN regions r ON d.region_id = r.id WHERE r.release_id = #{release} AND r.code = #{code}"
     )]
-    async fn list_districts_by_region_code(rb: &dyn Executor, code: &str) -> Vec<District> {}
+    async fn list_districts_by_region_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<District> {
+    }
 
     #[rbatis::py_sql(
-        "SELECT d.* FROM districts d LEFT JOIN provinces p ON d.province_id = p.id WHERE p.code = #{code}"
+        "SELECT d.* FROM districts d LEFT JOIN provinces p ON d.province_id = p.id WHERE p.release_id = #{release} AND p.code = #{code}"
     )]
-    async fn list_districts_by_province_code(rb: &dyn Executor, code: &str) -> Vec<District> {}
+    async fn list_districts_by_province_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<District> {
+    }
 }
 
 rbatis::crud!(District {}, "districts");
-rbatis::impl_select_page!(District {list_districts() => ""}, "districts");
+rbatis::impl_select_page!(District {list_districts(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "districts");
+rbatis::impl_select_page!(District {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and region_id in (select id from regions where island_group = #{scope.island_group}) order by code, id`"}, "districts");
 
-rbatis::impl_select!(District {select_by_code(code: &str) -> Option => "`where code = #{code} limit 1`"}, "districts");
+rbatis::impl_select!(District {select_by_code(release: &str, code: &str) -> Option => "`where release_id = #{release} and code = #{code} limit 1`"}, "districts");
+rbatis::impl_select!(District {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "districts");
+rbatis::impl_select!(District {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and normalized_name = #{normalized_name} order by code`"}, "districts");
 
 impl From<District> for psgc_domain::models::district::District {
     fn from(value: District) -> Self {
@@ -43,10 +69,13 @@ impl From<District> for psgc_domain::models::district::District {
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
             .region_id(region_id)
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
@@ -54,3 +83,50 @@ impl From<District> for psgc_domain::models::district::District {
 }
 
 // TODO: add seeder
+
+impl From<&psgc_domain::models::district::District> for District {
+    fn from(value: &psgc_domain::models::district::District) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .population(value.population)
+            .region_id(value.region_id.into_db())
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_district(
+    executor: &dyn Executor,
+    district: &District,
+) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "districts",
+        &district.id,
+        vec![
+            ("code", rbs::value!(&district.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&district.correspondence_code),
+            ),
+            ("name", rbs::value!(&district.name)),
+            ("slug", rbs::value!(&district.slug)),
+            ("normalized_name", rbs::value!(&district.normalized_name)),
+            ("population", rbs::value!(district.population)),
+            ("region_id", rbs::value!(&district.region_id)),
+            ("valid_from", rbs::value!(&district.valid_from)),
+            ("valid_to", rbs::value!(&district.valid_to)),
+            ("updated_at", rbs::value!(&district.updated_at)),
+        ],
+    )
+    .await
+}
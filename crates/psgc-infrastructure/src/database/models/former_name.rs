@@ -0,0 +1,97 @@
+use rbatis::executor::{Executor, RBatisConnExecutor};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct FormerName {
+    pub id: rbatis::rbdc::Uuid,
+    pub release_id: String,
+    /// `province`, `city`, `municipality` or `barangay`
+    pub level: String,
+    pub unit_id: rbatis::rbdc::Uuid,
+    pub name: String,
+    pub normalized_name: String,
+}
+
+rbatis::crud!(FormerName {}, "former_names");
+
+impl FormerName {
+    /// The former name of a unit built from seed data, if its `oldName` is set.
+    pub(crate) fn from_seed(
+        release: &str,
+        level: &str,
+        unit_id: &rbatis::rbdc::Uuid,
+        old_name: &str,
+    ) -> Option<Self> {
+        let old_name = old_name.trim();
+
+        (!old_name.is_empty()).then(|| Self::new(release, level, unit_id, old_name))
+    }
+
+    fn new(release: &str, level: &str, unit_id: &rbatis::rbdc::Uuid, name: &str) -> Self {
+        Self::builder()
+            .id(crate::database::generators::uuid_now())
+            .release_id(release.to_string())
+            .level(level.to_string())
+            .unit_id(unit_id.clone())
+            .name(name.to_string())
+            .normalized_name(psgc_shared::text::fold_name(name))
+            .build()
+    }
+}
+
+/// Stores those of `names` not yet stored for the unit, such as the name it had before
+/// a rename through the admin API.
+pub async fn insert_new(
+    executor: &dyn Executor,
+    release: &str,
+    level: &str,
+    unit_id: &rbatis::rbdc::Uuid,
+    names: &[String],
+) -> Result<(), rbatis::Error> {
+    let stored = FormerName::select_by_map(executor, rbs::value! {"unit_id": unit_id}).await?;
+
+    for name in names {
+        if !stored.iter().any(|former| &former.name == name) {
+            FormerName::insert(executor, &FormerName::new(release, level, unit_id, name)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FormerNameRow {
+    pub unit_id: rbatis::rbdc::Uuid,
+    pub name: String,
+}
+
+/// Former names of the units with these ids, oldest row first.
+pub async fn select_by_unit_ids(
+    executor: &RBatisConnExecutor,
+    unit_ids: &[rbatis::rbdc::Uuid],
+) -> Result<Vec<FormerNameRow>, rbatis::Error> {
+    if unit_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let placeholders = vec!["?"; unit_ids.len()].join(", ");
+    let sql = format!(
+        "SELECT unit_id, name FROM former_names WHERE unit_id IN ({placeholders}) ORDER BY id"
+    );
+    let args = unit_ids.iter().map(|id| rbs::value!(id)).collect();
+
+    executor.query_decode(&sql, args).await
+}
+
+/// Former names of every unit in a release, oldest row first.
+pub async fn select_by_release(
+    executor: &RBatisConnExecutor,
+    release: &str,
+) -> Result<Vec<FormerNameRow>, rbatis::Error> {
+    executor
+        .query_decode(
+            "SELECT unit_id, name FROM former_names WHERE release_id = ? ORDER BY id",
+            vec![rbs::value!(release)],
+        )
+        .await
+}
@@ -0,0 +1,72 @@
+use psgc_domain::{errors::RepositoryError, models::lineage::LineageEvent};
+use rbatis::executor::RBatisConnExecutor;
+use serde::{Deserialize, Serialize};
+
+use crate::database::generators::{
+    ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+};
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, bon::Builder)]
+pub struct Lineage {
+    pub id: rbatis::rbdc::Uuid,
+    pub predecessor_code: String,
+    pub successor_code: String,
+    /// `conversion`, `split`, `merge`, `rename` or `code_change`
+    pub event_type: String,
+    pub effective_on: rbatis::rbdc::Date,
+    pub created_at: rbatis::rbdc::DateTime,
+}
+
+rbatis::crud!(Lineage {}, "lineage");
+
+/// Events with any of `codes` in `column`, `predecessor_code` or `successor_code`.
+pub async fn select_by_codes(
+    executor: &RBatisConnExecutor,
+    column: &str,
+    codes: &[String],
+) -> Result<Vec<Lineage>, rbatis::Error> {
+    if codes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let placeholders = vec!["?"; codes.len()].join(", ");
+    let sql = format!(
+        "SELECT * FROM lineage WHERE {column} IN ({placeholders}) ORDER BY effective_on, id"
+    );
+    let args = codes.iter().map(|code| rbs::value!(code)).collect();
+
+    executor.query_decode(&sql, args).await
+}
+
+impl TryFrom<Lineage> for LineageEvent {
+    type Error = RepositoryError;
+
+    fn try_from(value: Lineage) -> Result<Self, Self::Error> {
+        Ok(Self::builder()
+            .id(value.id.inner())
+            .predecessor_code(value.predecessor_code)
+            .successor_code(value.successor_code)
+            .event_type(
+                value
+                    .event_type
+                    .parse()
+                    .map_err(RepositoryError::UnexpectedError)?,
+            )
+            .effective_on(value.effective_on.inner())
+            .created_at(value.created_at.inner())
+            .build())
+    }
+}
+
+impl From<&LineageEvent> for Lineage {
+    fn from(value: &LineageEvent) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .predecessor_code(value.predecessor_code.clone())
+            .successor_code(value.successor_code.clone())
+            .event_type(value.event_type.as_str().to_string())
+            .effective_on(value.effective_on.into_db())
+            .created_at(value.created_at.into_db())
+            .build()
+    }
+}
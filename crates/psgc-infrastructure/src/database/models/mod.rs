@@ -1,6 +1,56 @@
+pub mod api_key;
+pub mod audit_log;
 pub mod barangay;
 pub mod city;
+pub mod crosswalk;
 pub mod district;
+pub mod former_name;
+pub mod lineage;
 pub mod municipality;
 pub mod province;
 pub mod region;
+pub mod release;
+pub mod stats;
+
+use rbatis::executor::Executor;
+use serde::Serialize;
+
+/// A release read as of a day: only units that existed on `date` are listed.
+#[derive(Debug, Serialize)]
+pub struct ListScope {
+    pub release: String,
+    pub date: rbatis::rbdc::Date,
+    /// Only set to list the units of one island group
+    pub island_group: Option<String>,
+}
+
+/// Sets `columns` on the row with this id and returns how many rows were affected.
+///
+/// Unlike the generated `update_by_*` functions, `NULL`s are written too, so a parent
+/// link can be cleared.
+pub(crate) async fn update_by_id(
+    executor: &dyn Executor,
+    table: &str,
+    id: &rbatis::rbdc::Uuid,
+    columns: Vec<(&str, rbs::Value)>,
+) -> Result<u64, rbatis::Error> {
+    let assignments = columns
+        .iter()
+        .map(|(column, _)| format!("{column} = ?"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut args = columns
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect::<Vec<_>>();
+    args.push(rbs::value!(id));
+
+    let result = executor
+        .exec(
+            &format!("UPDATE {table} SET {assignments} WHERE id = ?"),
+            args,
+        )
+        .await?;
+
+    Ok(result.rows_affected)
+}
@@ -1,52 +1,71 @@
 use crate::database::{
-    DatabaseSeedError,
-    generators::{DateTimeUtcExt, RBatisUuidExt, datetime_utc_now, uuid_now},
-    helpers::{get_province_map, get_province_map_2, get_region_map},
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+        datetime_utc_now, uuid_now,
+    },
+    helpers::{
+        SlugCandidate, assign_unique_slugs, province_map, province_map_2, province_slug_map,
+        region_map, region_slug_map,
+    },
+    models::{
+        ListScope, former_name::FormerName, province::Province, region::Region, update_by_id,
+    },
+    seed_data::SeedData,
 };
+use psgc_shared::text::fold_name;
 use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
-use tracing::info;
 
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct Municipality {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub population: u64,
     pub income_class: String,
     pub region_id: Option<rbatis::rbdc::Uuid>,
     pub province_id: Option<rbatis::rbdc::Uuid>,
     pub district_id: Option<rbatis::rbdc::Uuid>,
     pub sub_municipality_id: Option<rbatis::rbdc::Uuid>,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 impl Municipality {
     #[rbatis::py_sql(
-        "SELECT m.* FROM municipalities m LEFT JOIN regions r ON m.region_id = r.id WHERE r.code = #{code}"
+        "SELECT m.* FROM municipalities m LEFT JOIN regions r ON m.region_id = r.id WHERE r.release_id = #{release} AND r.code = #{code}"
     )]
     async fn list_municipalities_by_region_code(
         rb: &dyn Executor,
+        release: &str,
         code: &str,
     ) -> Vec<Municipality> {
     }
 
     #[rbatis::py_sql(
-        "SELECT m.* FROM municipalities m LEFT JOIN provinces p ON m.province_id = p.id WHERE p.code = #{code}"
+        "SELECT m.* FROM municipalities m LEFT JOIN provinces p ON m.province_id = p.id WHERE p.release_id = #{release} AND p.code = #{code}"
     )]
     async fn list_municipalities_by_province_code(
         rb: &dyn Executor,
+        release: &str,
         code: &str,
     ) -> Vec<Municipality> {
     }
 
     #[rbatis::py_sql(
-        "SELECT m.* FROM municipalities m LEFT JOIN districts d ON m.district_id = d.id WHERE d.code = #{code}"
+        "SELECT m.* FROM municipalities m LEFT JOIN districts d ON m.district_id = d.id WHERE d.release_id = #{release} AND d.code = #{code}"
     )]
     async fn list_municipalities_by_district_code(
         rb: &dyn Executor,
+        release: &str,
         code: &str,
     ) -> Vec<Municipality> {
     }
@@ -61,30 +80,35 @@ struct MunicipalityData {
     code: String,
     #[serde(rename = "incomeClassification")]
     income_class: String,
+    #[serde(rename = "oldName", default)]
+    old_name: String,
 }
 
 rbatis::crud!(Municipality {}, "municipalities");
-rbatis::impl_select_page!(Municipality {list_municipalities() => ""}, "municipalities");
+rbatis::impl_select_page!(Municipality {list_municipalities(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "municipalities");
+rbatis::impl_select_page!(Municipality {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and region_id in (select id from regions where island_group = #{scope.island_group}) order by code, id`"}, "municipalities");
 
-rbatis::impl_select!(Municipality {select_by_code(code: &str) -> Option => "`where code = #{code} limit 1`"}, "municipalities");
+rbatis::impl_select!(Municipality {select_by_code(release: &str, code: &str) -> Option => "`where release_id = #{release} and code = #{code} limit 1`"}, "municipalities");
+rbatis::impl_select!(Municipality {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "municipalities");
+rbatis::impl_select!(Municipality {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and (normalized_name = #{normalized_name} or id in (select unit_id from former_names where release_id = #{release} and level = 'municipality' and normalized_name = #{normalized_name})) order by code`"}, "municipalities");
 
-pub async fn seed_municipalities(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError> {
-    info!("Seeding municipalities...");
+/// Builds municipality rows from the seed data of one release, linked to already built
+/// parents, and the former names given as `oldName`.
+pub fn build_municipalities(
+    data: &SeedData,
+    regions: &[Region],
+    provinces: &[Province],
+) -> Result<(Vec<Municipality>, Vec<FormerName>), crate::database::DatabaseSeedError> {
+    let region_map = region_map(regions);
+    let province_map = province_map(provinces);
+    let province_map_by_code = province_map_2(provinces);
+    let region_slug_map = region_slug_map(regions);
+    let province_slug_map = province_slug_map(provinces);
 
-    let mut executor = db
-        .acquire()
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    let region_map = get_region_map(&mut executor).await;
-    let province_map = get_province_map(&mut executor).await;
-    let province_map_by_code = get_province_map_2(&mut executor).await;
-
-    let municipalities_json = include_str!("../data/json/municipalities.json");
-    let municipalities_data = serde_json::from_str::<Vec<MunicipalityData>>(municipalities_json)
+    let municipalities_data = serde_json::from_str::<Vec<MunicipalityData>>(&data.municipalities)
         .map_err(|e| crate::database::DatabaseSeedError::Serialization(e))?;
 
-    let municipalities: Vec<Municipality> = municipalities_data
+    let parent_ids = municipalities_data
         .iter()
         .map(|m| {
             let region_code = &m.code[0..2];
@@ -100,9 +124,36 @@ pub async fn seed_municipalities(db: &rbatis::RBatis) -> Result<(), DatabaseSeed
                 }
             };
 
+            (region_id, province_id)
+        })
+        .collect::<Vec<_>>();
+
+    let candidates = municipalities_data
+        .iter()
+        .zip(&parent_ids)
+        .map(|(m, (region_id, province_id))| SlugCandidate {
+            name: &m.name,
+            qualifier: province_id
+                .as_ref()
+                .and_then(|id| province_slug_map.get(id))
+                .or_else(|| region_id.as_ref().and_then(|id| region_slug_map.get(id)))
+                .map(String::as_str),
+            code: &m.code,
+        })
+        .collect::<Vec<_>>();
+    let slugs = assign_unique_slugs(&candidates);
+
+    let municipalities: Vec<Municipality> = municipalities_data
+        .iter()
+        .zip(parent_ids)
+        .zip(slugs)
+        .map(|((m, (region_id, province_id)), slug)| {
             let municipality = Municipality::builder()
                 .id(uuid_now())
+                .release_id(data.release.clone())
                 .name(m.name.to_owned())
+                .slug(slug)
+                .normalized_name(fold_name(&m.name))
                 .code(m.code.to_owned())
                 .correspondence_code(m.correspondence_code.to_owned())
                 .population(0)
@@ -118,13 +169,15 @@ pub async fn seed_municipalities(db: &rbatis::RBatis) -> Result<(), DatabaseSeed
         })
         .collect::<Vec<_>>();
 
-    Municipality::insert_batch(&mut executor, &municipalities, 100)
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    info!("Added {} municipalities to database", municipalities.len());
+    let former_names = municipalities_data
+        .iter()
+        .zip(&municipalities)
+        .filter_map(|(d, row)| {
+            FormerName::from_seed(&data.release, "municipality", &row.id, &d.old_name)
+        })
+        .collect();
 
-    Ok(())
+    Ok((municipalities, former_names))
 }
 
 impl From<Municipality> for psgc_domain::models::municipality::Municipality {
@@ -132,6 +185,7 @@ impl From<Municipality> for psgc_domain::models::municipality::Municipality {
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
@@ -140,8 +194,66 @@ impl From<Municipality> for psgc_domain::models::municipality::Municipality {
             .maybe_province_id(value.province_id.map(|id| id.inner()))
             .maybe_district_id(value.district_id.map(|id| id.inner()))
             .maybe_sub_municipality_id(value.sub_municipality_id.map(|id| id.inner()))
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
     }
 }
+
+impl From<&psgc_domain::models::municipality::Municipality> for Municipality {
+    fn from(value: &psgc_domain::models::municipality::Municipality) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .population(value.population)
+            .income_class(value.income_class.clone())
+            .maybe_region_id(value.region_id.map(|id| id.into_db()))
+            .maybe_province_id(value.province_id.map(|id| id.into_db()))
+            .maybe_district_id(value.district_id.map(|id| id.into_db()))
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_municipality(
+    executor: &dyn Executor,
+    municipality: &Municipality,
+) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "municipalities",
+        &municipality.id,
+        vec![
+            ("code", rbs::value!(&municipality.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&municipality.correspondence_code),
+            ),
+            ("name", rbs::value!(&municipality.name)),
+            ("slug", rbs::value!(&municipality.slug)),
+            (
+                "normalized_name",
+                rbs::value!(&municipality.normalized_name),
+            ),
+            ("population", rbs::value!(municipality.population)),
+            ("income_class", rbs::value!(&municipality.income_class)),
+            ("region_id", rbs::value!(&municipality.region_id)),
+            ("province_id", rbs::value!(&municipality.province_id)),
+            ("district_id", rbs::value!(&municipality.district_id)),
+            ("valid_from", rbs::value!(&municipality.valid_from)),
+            ("valid_to", rbs::value!(&municipality.valid_to)),
+            ("updated_at", rbs::value!(&municipality.updated_at)),
+        ],
+    )
+    .await
+}
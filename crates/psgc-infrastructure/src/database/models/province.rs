@@ -1,36 +1,57 @@
 use crate::database::{
-    generators::{DateTimeUtcExt, RBatisUuidExt, datetime_utc_now},
-    helpers::get_region_map,
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+        datetime_utc_now,
+    },
+    helpers::{SlugCandidate, assign_unique_slugs, region_map, region_slug_map},
+    models::{ListScope, former_name::FormerName, region::Region, update_by_id},
+    seed_data::SeedData,
 };
+use psgc_shared::text::fold_name;
 use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
-use tracing::{info, warn};
+use tracing::warn;
 
 #[allow(dead_code)]
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct Province {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub population: u64,
     pub income_class: String,
     pub region_id: Option<rbatis::rbdc::Uuid>,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 impl Province {
     #[rbatis::py_sql(
-        "SELECT p.* FROM provinces p LEFT JOIN regions r ON p.region_id = r.id WHERE r.code = #{code}"
+        "SELECT p.* FROM provinces p LEFT JOIN regions r ON p.region_id = r.id WHERE r.release_id = #{release} AND r.code = #{code}"
     )]
-    async fn list_provinces_by_region_code(rb: &dyn Executor, code: &str) -> Vec<Province> {}
+    async fn list_provinces_by_region_code(
+        rb: &dyn Executor,
+        release: &str,
+        code: &str,
+    ) -> Vec<Province> {
+    }
 }
 
 rbatis::crud!(Province {}, "provinces");
-rbatis::impl_select_page!(Province {list_provinces() => ""}, "provinces");
+rbatis::impl_select_page!(Province {list_provinces(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "provinces");
+rbatis::impl_select_page!(Province {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and region_id in (select id from regions where island_group = #{scope.island_group}) order by code, id`"}, "provinces");
 
-rbatis::impl_select!(Province {select_by_code(code: &str) -> Option => "`where code = #{code} limit 1`"}, "provinces");
+rbatis::impl_select!(Province {select_by_code(release: &str, code: &str) -> Option => "`where release_id = #{release} and code = #{code} limit 1`"}, "provinces");
+rbatis::impl_select!(Province {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "provinces");
+rbatis::impl_select!(Province {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and (normalized_name = #{normalized_name} or id in (select unit_id from former_names where release_id = #{release} and level = 'province' and normalized_name = #{normalized_name})) order by code`"}, "provinces");
 
 #[derive(Debug, Serialize, Deserialize, bon::Builder)]
 struct ProvinceData {
@@ -41,7 +62,7 @@ struct ProvinceData {
     code: String,
     #[serde(rename = "geographicLevel")]
     geographic_level: String,
-    #[serde(rename = "oldName")]
+    #[serde(rename = "oldName", default)]
     old_name: String,
     #[serde(rename = "cityClass")]
     city_class: String,
@@ -51,23 +72,21 @@ struct ProvinceData {
     urban_rural: String,
 }
 
-pub async fn seed_provinces(db: &rbatis::RBatis) -> Result<(), crate::database::DatabaseSeedError> {
-    info!("Seeding provinces...");
-
-    let mut executor = db
-        .acquire()
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
-
-    let region_map = get_region_map(&mut executor).await;
+/// Builds province rows from the seed data of one release, linked to already built
+/// `regions`, and the former names given as `oldName`.
+pub fn build_provinces(
+    data: &SeedData,
+    regions: &[Region],
+) -> Result<(Vec<Province>, Vec<FormerName>), crate::database::DatabaseSeedError> {
+    let region_map = region_map(regions);
+    let region_slug_map = region_slug_map(regions);
 
-    let provinces_json = include_str!("../data/json/provinces.json");
-    let provinces_data = serde_json::from_str::<Vec<ProvinceData>>(provinces_json)
+    let provinces_data = serde_json::from_str::<Vec<ProvinceData>>(&data.provinces)
         .map_err(|e| crate::database::DatabaseSeedError::Serialization(e))?;
 
-    let provinces: Vec<Province> = provinces_data
+    let region_ids = provinces_data
         .iter()
-        .filter_map(|p| {
+        .map(|p| {
             // Extract 2-character region code from province
             let region_code = &p.code[0..2];
             let region_id = region_map.get(region_code).cloned();
@@ -79,29 +98,55 @@ pub async fn seed_provinces(db: &rbatis::RBatis) -> Result<(), crate::database::
                 );
             }
 
-            Some(
-                Province::builder()
-                    .id(crate::database::generators::uuid_now())
-                    .code(p.code.clone())
-                    .correspondence_code(p.correspondence_code.clone())
-                    .name(p.name.clone())
-                    .population(0) // Population not available in province data
-                    .income_class(p.income_classification.clone())
-                    .maybe_region_id(region_id)
-                    .created_at(datetime_utc_now())
-                    .updated_at(datetime_utc_now())
-                    .build(),
-            )
+            region_id
         })
-        .collect();
+        .collect::<Vec<_>>();
 
-    Province::insert_batch(db, &provinces, 100)
-        .await
-        .map_err(|e| crate::database::DatabaseSeedError::DbError(e))?;
+    let candidates = provinces_data
+        .iter()
+        .zip(&region_ids)
+        .map(|(p, region_id)| SlugCandidate {
+            name: &p.name,
+            qualifier: region_id
+                .as_ref()
+                .and_then(|id| region_slug_map.get(id))
+                .map(String::as_str),
+            code: &p.code,
+        })
+        .collect::<Vec<_>>();
+    let slugs = assign_unique_slugs(&candidates);
 
-    info!("Added {} provinces to database", provinces.len());
+    let provinces: Vec<Province> = provinces_data
+        .iter()
+        .zip(region_ids)
+        .zip(slugs)
+        .map(|((p, region_id), slug)| {
+            Province::builder()
+                .id(crate::database::generators::uuid_now())
+                .release_id(data.release.clone())
+                .code(p.code.clone())
+                .correspondence_code(p.correspondence_code.clone())
+                .name(p.name.clone())
+                .slug(slug)
+                .normalized_name(fold_name(&p.name))
+                .population(0) // Population not available in province data
+                .income_class(p.income_classification.clone())
+                .maybe_region_id(region_id)
+                .created_at(datetime_utc_now())
+                .updated_at(datetime_utc_now())
+                .build()
+        })
+        .collect();
 
-    Ok(())
+    let former_names = provinces_data
+        .iter()
+        .zip(&provinces)
+        .filter_map(|(d, row)| {
+            FormerName::from_seed(&data.release, "province", &row.id, &d.old_name)
+        })
+        .collect();
+
+    Ok((provinces, former_names))
 }
 
 impl From<Province> for psgc_domain::models::province::Province {
@@ -114,13 +159,65 @@ impl From<Province> for psgc_domain::models::province::Province {
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
             .income_class(value.income_class)
             .region_id(region_id)
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
     }
 }
+
+impl From<&psgc_domain::models::province::Province> for Province {
+    fn from(value: &psgc_domain::models::province::Province) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .population(value.population)
+            .income_class(value.income_class.clone())
+            .region_id(value.region_id.into_db())
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_province(
+    executor: &dyn Executor,
+    province: &Province,
+) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "provinces",
+        &province.id,
+        vec![
+            ("code", rbs::value!(&province.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&province.correspondence_code),
+            ),
+            ("name", rbs::value!(&province.name)),
+            ("slug", rbs::value!(&province.slug)),
+            ("normalized_name", rbs::value!(&province.normalized_name)),
+            ("population", rbs::value!(province.population)),
+            ("income_class", rbs::value!(&province.income_class)),
+            ("region_id", rbs::value!(&province.region_id)),
+            ("valid_from", rbs::value!(&province.valid_from)),
+            ("valid_to", rbs::value!(&province.valid_to)),
+            ("updated_at", rbs::value!(&province.updated_at)),
+        ],
+    )
+    .await
+}
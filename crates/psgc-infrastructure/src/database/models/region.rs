@@ -1,26 +1,45 @@
 use crate::database::{
     DatabaseSeedError,
-    generators::{DateTimeUtcExt, RBatisUuidExt, datetime_utc_now, uuid_now},
+    generators::{
+        ChronoDateTimeExt, DateTimeUtcExt, NaiveDateExt, RBatisDateExt, RBatisUuidExt, UuidExt,
+        datetime_utc_now, uuid_now,
+    },
+    helpers::{SlugCandidate, assign_unique_slugs},
+    models::{ListScope, update_by_id},
+    seed_data::SeedData,
 };
+use psgc_domain::models::island_group::IslandGroup;
+use psgc_shared::text::fold_name;
+use rbatis::executor::Executor;
 use serde::{Deserialize, Serialize};
-use tracing::info;
 
 #[allow(dead_code)]
 #[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
 pub struct Region {
     pub id: rbatis::rbdc::Uuid,
+    /// Set by the repository or seeder writing the row
+    #[builder(default)]
+    pub release_id: String,
     pub code: String,
     pub correspondence_code: String,
     pub name: String,
+    pub slug: Option<String>,
+    pub normalized_name: Option<String>,
     pub designation: String,
+    pub island_group: Option<String>,
     pub population: u64,
+    pub valid_from: Option<rbatis::rbdc::Date>,
+    pub valid_to: Option<rbatis::rbdc::Date>,
     pub created_at: rbatis::rbdc::DateTime,
     pub updated_at: rbatis::rbdc::DateTime,
 }
 
 rbatis::crud!(Region {}, "regions");
-rbatis::impl_select!(Region {select_by_codename(codename: &str) -> Option => "`where code = #{codename} limit 1`"}, "regions");
-rbatis::impl_select_page!(Region {list_all() => ""}, "regions");
+rbatis::impl_select!(Region {select_by_codename(release: &str, codename: &str) -> Option => "`where release_id = #{release} and code = #{codename} limit 1`"}, "regions");
+rbatis::impl_select!(Region {select_by_slug(release: &str, slug: &str) -> Option => "`where release_id = #{release} and slug = #{slug} limit 1`"}, "regions");
+rbatis::impl_select!(Region {select_by_normalized_name(release: &str, normalized_name: &str) => "`where release_id = #{release} and normalized_name = #{normalized_name} order by code`"}, "regions");
+rbatis::impl_select_page!(Region {list_all(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) order by code, id`"}, "regions");
+rbatis::impl_select_page!(Region {list_by_island_group(scope: &ListScope) => "`where release_id = #{scope.release} and (valid_from is null or valid_from <= #{scope.date}) and (valid_to is null or valid_to > #{scope.date}) and island_group = #{scope.island_group} order by code, id`"}, "regions");
 
 #[derive(Debug, Serialize, Deserialize, bon::Builder)]
 struct RegionData {
@@ -31,22 +50,38 @@ struct RegionData {
     population: u64,
 }
 
-pub async fn seed_regions(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError> {
-    info!("Sedding regions...");
-    let regions = include_str!("../data/json/regions.json");
-
-    let regions = serde_json::from_str::<Vec<RegionData>>(regions)
+/// Builds region rows from the seed data of one release.
+pub fn build_regions(data: &SeedData) -> Result<Vec<Region>, DatabaseSeedError> {
+    let regions = serde_json::from_str::<Vec<RegionData>>(&data.regions)
         .map_err(|e| DatabaseSeedError::Serialization(e))?;
 
+    let candidates = regions
+        .iter()
+        .map(|r| SlugCandidate {
+            // Regions are referred to by designation (`ncr`, `region-iv-a`) rather than name
+            name: &r.designation,
+            qualifier: None,
+            code: &r.code,
+        })
+        .collect::<Vec<_>>();
+    let slugs = assign_unique_slugs(&candidates);
+
     let regions = regions
         .iter()
-        .map(|r| {
+        .zip(slugs)
+        .map(|(r, slug)| {
             Region::builder()
                 .id(uuid_now())
+                .release_id(data.release.clone())
                 .name(r.name.to_owned())
+                .slug(slug)
+                .normalized_name(fold_name(&r.name))
                 .code(r.code.to_owned())
                 .correspondence_code(r.correspondence_code.to_owned())
                 .designation(r.designation.to_owned())
+                .maybe_island_group(
+                    IslandGroup::from_region_code(&r.code).map(|g| g.id().to_string()),
+                )
                 .population(r.population)
                 .created_at(datetime_utc_now())
                 .updated_at(datetime_utc_now())
@@ -54,26 +89,76 @@ pub async fn seed_regions(db: &rbatis::RBatis) -> Result<(), DatabaseSeedError>
         })
         .collect::<Vec<_>>();
 
-    Region::insert_batch(db, &regions, 100)
-        .await
-        .map_err(|e| DatabaseSeedError::DbError(e))?;
-
-    info!("Added {} regions to database", regions.len());
-
-    Ok(())
+    Ok(regions)
 }
 
 impl From<Region> for psgc_domain::models::region::Region {
     fn from(value: Region) -> Self {
+        let island_group = value
+            .island_group
+            .as_deref()
+            .and_then(|g| g.parse::<IslandGroup>().ok())
+            .or_else(|| IslandGroup::from_region_code(&value.code));
+
         Self::builder()
             .id(value.id.inner())
             .name(value.name)
+            .slug(value.slug.unwrap_or_default())
             .code(value.code)
             .correspondence_code(value.correspondence_code)
             .population(value.population)
             .designation(value.designation)
+            .maybe_island_group(island_group)
+            .maybe_valid_from(value.valid_from.map(|d| d.inner()))
+            .maybe_valid_to(value.valid_to.map(|d| d.inner()))
             .created_at(value.created_at.inner())
             .updated_at(value.updated_at.inner())
             .build()
     }
 }
+
+impl From<&psgc_domain::models::region::Region> for Region {
+    fn from(value: &psgc_domain::models::region::Region) -> Self {
+        Self::builder()
+            .id(value.id.into_db())
+            .code(value.code.clone())
+            .correspondence_code(value.correspondence_code.clone())
+            .name(value.name.clone())
+            .slug(value.slug.clone())
+            .normalized_name(fold_name(&value.name))
+            .designation(value.designation.clone())
+            .maybe_island_group(value.island_group.map(|g| g.id().to_string()))
+            .population(value.population)
+            .maybe_valid_from(value.valid_from.map(|d| d.into_db()))
+            .maybe_valid_to(value.valid_to.map(|d| d.into_db()))
+            .created_at(value.created_at.into_db())
+            .updated_at(value.updated_at.into_db())
+            .build()
+    }
+}
+
+/// Overwrites every column but the id and creation time.
+pub async fn update_region(executor: &dyn Executor, region: &Region) -> Result<u64, rbatis::Error> {
+    update_by_id(
+        executor,
+        "regions",
+        &region.id,
+        vec![
+            ("code", rbs::value!(&region.code)),
+            (
+                "correspondence_code",
+                rbs::value!(&region.correspondence_code),
+            ),
+            ("name", rbs::value!(&region.name)),
+            ("slug", rbs::value!(&region.slug)),
+            ("normalized_name", rbs::value!(&region.normalized_name)),
+            ("designation", rbs::value!(&region.designation)),
+            ("island_group", rbs::value!(&region.island_group)),
+            ("population", rbs::value!(region.population)),
+            ("valid_from", rbs::value!(&region.valid_from)),
+            ("valid_to", rbs::value!(&region.valid_to)),
+            ("updated_at", rbs::value!(&region.updated_at)),
+        ],
+    )
+    .await
+}
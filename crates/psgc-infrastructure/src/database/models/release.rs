@@ -0,0 +1,171 @@
+use rbatis::executor::{Executor, RBatisConnExecutor};
+use serde::{Deserialize, Serialize};
+
+use crate::database::generators::DateTimeUtcExt;
+
+#[derive(Debug, Default, Serialize, Deserialize, bon::Builder)]
+pub struct Release {
+    pub id: String,
+    pub loaded_at: rbatis::rbdc::DateTime,
+    #[builder(default)]
+    pub position: i64,
+}
+
+rbatis::crud!(Release {}, "releases");
+rbatis::impl_select!(Release {select_by_id(id: &str) -> Option => "`where id = #{id} limit 1`"}, "releases");
+rbatis::impl_select!(Release {list_newest_first() => "`order by id desc`"}, "releases");
+
+/// Records the position the audit trigger gave the seed run's entry `audit_id` as where
+/// the feed stood once `release` was loaded.
+pub async fn update_position(
+    executor: &dyn Executor,
+    release: &str,
+    audit_id: &rbatis::rbdc::Uuid,
+) -> Result<rbatis::rbdc::db::ExecResult, rbatis::Error> {
+    executor
+        .exec(
+            "UPDATE releases SET position = (SELECT position FROM audit_log WHERE id = ?) WHERE id = ?",
+            vec![rbs::value!(audit_id), rbs::value!(release)],
+        )
+        .await
+}
+
+impl From<Release> for psgc_domain::models::release::Release {
+    fn from(value: Release) -> Self {
+        Self::builder()
+            .id(value.id)
+            .loaded_at(value.loaded_at.inner())
+            .position(value.position)
+            .build()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReleaseUnitRow {
+    pub id: rbatis::rbdc::Uuid,
+    pub level: String,
+    pub code: String,
+    pub alternate_code: Option<String>,
+    pub name: String,
+    pub parent_code: Option<String>,
+    pub classification: Option<String>,
+}
+
+/// The 10-digit code of the row aliased `alias`; levels differ in which of their two codes
+/// has that form.
+fn psgc_code(alias: &str) -> String {
+    format!(
+        "CASE WHEN LENGTH({alias}.code) = 10 THEN {alias}.code ELSE {alias}.correspondence_code END"
+    )
+}
+
+/// The other code of the row aliased `alias`, usually the 9-digit one.
+fn alternate_code(alias: &str) -> String {
+    format!(
+        "CASE WHEN LENGTH({alias}.code) = 10 THEN {alias}.correspondence_code ELSE {alias}.code END"
+    )
+}
+
+/// Every unit of a release at every level that hasn't been abolished, with its parent's
+/// code.
+pub async fn select_release_units(
+    executor: &RBatisConnExecutor,
+    release: &str,
+) -> Result<Vec<ReleaseUnitRow>, rbatis::Error> {
+    let sql = release_units_sql(|_| String::new());
+    let args = vec![rbs::Value::String(release.to_string()); 6];
+
+    executor.query_decode(&sql, args).await
+}
+
+/// The units of a release that haven't been abolished and have any of `codes` as either
+/// of their two codes.
+pub async fn select_release_units_by_codes(
+    executor: &RBatisConnExecutor,
+    release: &str,
+    codes: &[String],
+) -> Result<Vec<ReleaseUnitRow>, rbatis::Error> {
+    if codes.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let placeholders = vec!["?"; codes.len()].join(", ");
+    let sql = release_units_sql(|alias| {
+        format!(
+            " AND ({alias}.code IN ({placeholders}) OR {alias}.correspondence_code IN ({placeholders}))"
+        )
+    });
+    let mut args = Vec::with_capacity(6 * (1 + 2 * codes.len()));
+    for _ in 0..6 {
+        args.push(rbs::Value::String(release.to_string()));
+        for _ in 0..2 {
+            args.extend(codes.iter().map(|code| rbs::Value::String(code.clone())));
+        }
+    }
+
+    executor.query_decode(&sql, args).await
+}
+
+/// One `SELECT` per level, each taking the release and then whatever `filter` adds to the
+/// level's table alias.
+fn release_units_sql(filter: impl Fn(&str) -> String) -> String {
+    let (r, p, d, c, m, b) = (
+        psgc_code("r"),
+        psgc_code("p"),
+        psgc_code("d"),
+        psgc_code("c"),
+        psgc_code("m"),
+        psgc_code("b"),
+    );
+    let (fr, fp, fd, fc, fm, fb) = (
+        filter("r"),
+        filter("p"),
+        filter("d"),
+        filter("c"),
+        filter("m"),
+        filter("b"),
+    );
+
+    format!(
+        "SELECT r.id, 'region' AS level, {r} AS code, {ar} AS alternate_code, r.name, NULL AS parent_code, '' AS classification \
+            FROM regions r WHERE r.release_id = ? AND r.valid_to IS NULL{fr} \
+        UNION ALL \
+        SELECT p.id, 'province', {p}, {ap}, p.name, {r}, '' \
+            FROM provinces p LEFT JOIN regions r ON r.id = p.region_id WHERE p.release_id = ? AND p.valid_to IS NULL{fp} \
+        UNION ALL \
+        SELECT d.id, 'district', {d}, {ad}, d.name, {r}, '' \
+            FROM districts d LEFT JOIN regions r ON r.id = d.region_id WHERE d.release_id = ? AND d.valid_to IS NULL{fd} \
+        UNION ALL \
+        SELECT c.id, 'city', {c}, {ac}, c.name, COALESCE({p}, {r}), c.city_class \
+            FROM cities c LEFT JOIN provinces p ON p.id = c.province_id \
+            LEFT JOIN regions r ON r.id = c.region_id WHERE c.release_id = ? AND c.valid_to IS NULL{fc} \
+        UNION ALL \
+        SELECT m.id, 'municipality', {m}, {am}, m.name, COALESCE({p}, {d}, {r}), '' \
+            FROM municipalities m LEFT JOIN provinces p ON p.id = m.province_id \
+            LEFT JOIN districts d ON d.id = m.district_id \
+            LEFT JOIN regions r ON r.id = m.region_id WHERE m.release_id = ? AND m.valid_to IS NULL{fm} \
+        UNION ALL \
+        SELECT b.id, 'barangay', {b}, {ab}, b.name, COALESCE({c}, {m}), b.urban_rural \
+            FROM barangays b \
+            LEFT JOIN cities c ON c.id = b.city_id \
+            LEFT JOIN municipalities m ON m.id = b.municipality_id WHERE b.release_id = ? AND b.valid_to IS NULL{fb}",
+        ar = alternate_code("r"),
+        ap = alternate_code("p"),
+        ad = alternate_code("d"),
+        ac = alternate_code("c"),
+        am = alternate_code("m"),
+        ab = alternate_code("b"),
+    )
+}
+
+impl From<ReleaseUnitRow> for psgc_domain::models::release_diff::ReleaseUnit {
+    fn from(value: ReleaseUnitRow) -> Self {
+        Self::builder()
+            .level(value.level)
+            .code(value.code)
+            .name(value.name)
+            .maybe_parent_code(value.parent_code)
+            .classification(value.classification.unwrap_or_default())
+            .build()
+    }
+}
@@ -0,0 +1,197 @@
+use rbatis::executor::RBatisConnExecutor;
+use serde::{Deserialize, Serialize};
+
+use crate::database::models::ListScope;
+
+/// Barangays hang off either a city or a municipality, so their region and province
+/// are resolved through whichever parent they have. Takes the date twice.
+fn barangay_parents() -> String {
+    format!(
+        "SELECT b.urban_rural, \
+        COALESCE(ci.region_id, mu.region_id) AS region_id, \
+        COALESCE(ci.province_id, mu.province_id) AS province_id \
+        FROM barangays b \
+        LEFT JOIN cities ci ON b.city_id = ci.id \
+        LEFT JOIN municipalities mu ON b.municipality_id = mu.id \
+        WHERE {}",
+        existed_on("b")
+    )
+}
+
+/// Whether the row of `table` (or alias) existed on the date, bound twice after it.
+fn existed_on(table: &str) -> String {
+    format!(
+        "({table}.valid_from IS NULL OR {table}.valid_from <= ?) \
+        AND ({table}.valid_to IS NULL OR {table}.valid_to > ?)"
+    )
+}
+
+/// The arguments of a query with `dated` date conditions, then the release, then one
+/// more condition on the date and the code, if any.
+fn stats_args(scope: &ListScope, dated: usize, code: Option<&str>) -> Vec<rbs::Value> {
+    let date = || rbs::value!(&scope.date);
+
+    std::iter::repeat_with(date)
+        .take(dated * 2)
+        .chain(std::iter::once(rbs::Value::String(scope.release.clone())))
+        .chain([date(), date()])
+        .chain(code.map(|code| rbs::Value::String(code.to_string())))
+        .collect()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegionStatsRow {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub province_count: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProvinceStatsRow {
+    pub code: String,
+    pub name: String,
+    pub population: u64,
+    pub city_count: u64,
+    pub municipality_count: u64,
+    pub barangay_count: u64,
+    pub urban_barangay_count: u64,
+    pub rural_barangay_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupCountRow {
+    pub group_name: String,
+    pub count: u64,
+}
+
+pub async fn select_region_stats(
+    executor: &RBatisConnExecutor,
+    scope: &ListScope,
+    code: Option<&str>,
+) -> Result<Vec<RegionStatsRow>, rbatis::Error> {
+    let sql = format!(
+        "SELECT r.code, r.name, r.population, \
+            CAST(COALESCE(p.count, 0) AS BIGINT) AS province_count, \
+            CAST(COALESCE(c.count, 0) AS BIGINT) AS city_count, \
+            CAST(COALESCE(m.count, 0) AS BIGINT) AS municipality_count, \
+            CAST(COALESCE(b.count, 0) AS BIGINT) AS barangay_count, \
+            CAST(COALESCE(b.urban, 0) AS BIGINT) AS urban_barangay_count, \
+            CAST(COALESCE(b.rural, 0) AS BIGINT) AS rural_barangay_count \
+        FROM regions r \
+        LEFT JOIN (SELECT region_id, COUNT(*) AS count FROM provinces WHERE {existed_on_provinces} GROUP BY region_id) p ON p.region_id = r.id \
+        LEFT JOIN (SELECT region_id, COUNT(*) AS count FROM cities WHERE {existed_on_cities} GROUP BY region_id) c ON c.region_id = r.id \
+        LEFT JOIN (SELECT region_id, COUNT(*) AS count FROM municipalities WHERE {existed_on_municipalities} GROUP BY region_id) m ON m.region_id = r.id \
+        LEFT JOIN (SELECT region_id, COUNT(*) AS count, \
+                COUNT(*) FILTER (WHERE urban_rural = 'U') AS urban, \
+                COUNT(*) FILTER (WHERE urban_rural = 'R') AS rural \
+            FROM ({barangay_parents}) bp GROUP BY region_id) b ON b.region_id = r.id \
+        WHERE r.release_id = ? AND {existed_on_r} {} \
+        ORDER BY r.code",
+        if code.is_some() { "AND r.code = ?" } else { "" },
+        existed_on_provinces = existed_on("provinces"),
+        existed_on_cities = existed_on("cities"),
+        existed_on_municipalities = existed_on("municipalities"),
+        barangay_parents = barangay_parents(),
+        existed_on_r = existed_on("r"),
+    );
+    let args = stats_args(scope, 4, code);
+
+    executor.query_decode(&sql, args).await
+}
+
+pub async fn select_province_stats(
+    executor: &RBatisConnExecutor,
+    scope: &ListScope,
+    code: Option<&str>,
+) -> Result<Vec<ProvinceStatsRow>, rbatis::Error> {
+    let sql = format!(
+        "SELECT p.code, p.name, p.population, \
+            CAST(COALESCE(c.count, 0) AS BIGINT) AS city_count, \
+            CAST(COALESCE(m.count, 0) AS BIGINT) AS municipality_count, \
+            CAST(COALESCE(b.count, 0) AS BIGINT) AS barangay_count, \
+            CAST(COALESCE(b.urban, 0) AS BIGINT) AS urban_barangay_count, \
+            CAST(COALESCE(b.rural, 0) AS BIGINT) AS rural_barangay_count \
+        FROM provinces p \
+        LEFT JOIN (SELECT province_id, COUNT(*) AS count FROM cities WHERE {existed_on_cities} GROUP BY province_id) c ON c.province_id = p.id \
+        LEFT JOIN (SELECT province_id, COUNT(*) AS count FROM municipalities WHERE {existed_on_municipalities} GROUP BY province_id) m ON m.province_id = p.id \
+        LEFT JOIN (SELECT province_id, COUNT(*) AS count, \
+                COUNT(*) FILTER (WHERE urban_rural = 'U') AS urban, \
+                COUNT(*) FILTER (WHERE urban_rural = 'R') AS rural \
+            FROM ({barangay_parents}) bp GROUP BY province_id) b ON b.province_id = p.id \
+        WHERE p.release_id = ? AND {existed_on_p} {} \
+        ORDER BY p.code",
+        if code.is_some() { "AND p.code = ?" } else { "" },
+        existed_on_cities = existed_on("cities"),
+        existed_on_municipalities = existed_on("municipalities"),
+        barangay_parents = barangay_parents(),
+        existed_on_p = existed_on("p"),
+    );
+    let args = stats_args(scope, 3, code);
+
+    executor.query_decode(&sql, args).await
+}
+
+/// Counts the rows of `table` in scope grouped by `column`. Both are fixed identifiers
+/// chosen by the caller, never user input.
+pub async fn select_group_counts(
+    executor: &RBatisConnExecutor,
+    scope: &ListScope,
+    table: &str,
+    column: &str,
+) -> Result<Vec<GroupCountRow>, rbatis::Error> {
+    let sql = format!(
+        "SELECT {column} AS group_name, CAST(COUNT(*) AS BIGINT) AS count \
+        FROM {table} WHERE release_id = ? AND {} GROUP BY {column} ORDER BY {column}",
+        existed_on(table)
+    );
+
+    executor
+        .query_decode(&sql, stats_args(scope, 0, None))
+        .await
+}
+
+impl From<RegionStatsRow> for psgc_domain::models::stats::RegionStats {
+    fn from(value: RegionStatsRow) -> Self {
+        Self::builder()
+            .code(value.code)
+            .name(value.name)
+            .population(value.population)
+            .province_count(value.province_count)
+            .city_count(value.city_count)
+            .municipality_count(value.municipality_count)
+            .barangay_count(value.barangay_count)
+            .urban_barangay_count(value.urban_barangay_count)
+            .rural_barangay_count(value.rural_barangay_count)
+            .build()
+    }
+}
+
+impl From<ProvinceStatsRow> for psgc_domain::models::stats::ProvinceStats {
+    fn from(value: ProvinceStatsRow) -> Self {
+        Self::builder()
+            .code(value.code)
+            .name(value.name)
+            .population(value.population)
+            .city_count(value.city_count)
+            .municipality_count(value.municipality_count)
+            .barangay_count(value.barangay_count)
+            .urban_barangay_count(value.urban_barangay_count)
+            .rural_barangay_count(value.rural_barangay_count)
+            .build()
+    }
+}
+
+impl From<GroupCountRow> for psgc_domain::models::stats::GroupCount {
+    fn from(value: GroupCountRow) -> Self {
+        Self::builder()
+            .group(value.group_name)
+            .count(value.count)
+            .build()
+    }
+}
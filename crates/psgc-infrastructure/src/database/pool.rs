@@ -1,16 +1,52 @@
 use rbatis::RBatis;
+use serde::Deserialize;
 use std::sync::Arc;
 
 use crate::config::db_config::DatabaseConfig;
 
+/// Picks the rbatis driver from the URL scheme: `sqlite:` needs the `sqlite` feature,
+/// anything else is treated as Postgres.
 pub fn create_db_pool(config: &DatabaseConfig) -> anyhow::Result<Arc<RBatis>> {
     let db = rbatis::RBatis::new();
     let db = Arc::new(db);
 
-    let driver = rbdc_pg::driver::PgDriver {};
     let conn_url = &config.db_url;
 
-    db.init(driver, &conn_url)?;
+    if config.is_sqlite() {
+        init_sqlite(&db, conn_url)?;
+    } else {
+        let driver = rbdc_pg::driver::PgDriver {};
+        db.init(driver, conn_url)?;
+    }
 
     Ok(db)
 }
+
+#[cfg(feature = "sqlite")]
+fn init_sqlite(db: &RBatis, conn_url: &str) -> anyhow::Result<()> {
+    let driver = rbdc_sqlite::driver::SqliteDriver {};
+    db.init(driver, conn_url)?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn init_sqlite(_db: &RBatis, _conn_url: &str) -> anyhow::Result<()> {
+    anyhow::bail!("sqlite DATABASE_URL given but psgc was built without the `sqlite` feature")
+}
+
+/// Snapshot of the rbatis connection pool.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct PoolStats {
+    pub max_open: u64,
+    pub connections: u64,
+    pub in_use: u64,
+    pub idle: u64,
+    pub waits: u64,
+}
+
+pub async fn pool_stats(db: &RBatis) -> Result<PoolStats, rbatis::Error> {
+    let state = db.get_pool()?.state().await;
+
+    rbs::from_value(state)
+}
@@ -0,0 +1,90 @@
+use std::{borrow::Cow, path::Path};
+
+use crate::database::{
+    DatabaseSeedError,
+    models::{
+        barangay::{Barangay, build_barangays},
+        city::{City, build_cities},
+        former_name::FormerName,
+        municipality::{Municipality, build_municipalities},
+        province::{Province, build_provinces},
+        region::{Region, build_regions},
+    },
+};
+
+/// The PSGC release the bundled seed data was taken from.
+pub const BUNDLED_RELEASE: &str = "2024Q3";
+
+/// The PSA JSON files of one release, either bundled into the binary or read from a
+/// directory holding `regions.json`, `provinces.json`, `cities.json`,
+/// `municipalities.json` and `barangays.json` in the same format.
+pub struct SeedData {
+    pub release: String,
+    pub(crate) regions: Cow<'static, str>,
+    pub(crate) provinces: Cow<'static, str>,
+    pub(crate) cities: Cow<'static, str>,
+    pub(crate) municipalities: Cow<'static, str>,
+    pub(crate) barangays: Cow<'static, str>,
+}
+
+/// Rows built from [`SeedData`], with parents linked and slugs assigned.
+pub(crate) struct SeedRows {
+    pub regions: Vec<Region>,
+    pub provinces: Vec<Province>,
+    pub cities: Vec<City>,
+    pub municipalities: Vec<Municipality>,
+    pub barangays: Vec<Barangay>,
+    pub former_names: Vec<FormerName>,
+}
+
+impl SeedData {
+    pub fn bundled() -> Self {
+        Self {
+            release: BUNDLED_RELEASE.to_string(),
+            regions: Cow::Borrowed(include_str!("data/json/regions.json")),
+            provinces: Cow::Borrowed(include_str!("data/json/provinces.json")),
+            cities: Cow::Borrowed(include_str!("data/json/cities.json")),
+            municipalities: Cow::Borrowed(include_str!("data/json/municipalities.json")),
+            barangays: Cow::Borrowed(include_str!("data/json/barangays.json")),
+        }
+    }
+
+    pub fn from_dir(release: &str, dir: &Path) -> Result<Self, DatabaseSeedError> {
+        let read = |file: &str| {
+            let path = dir.join(file);
+            std::fs::read_to_string(&path).map(Cow::Owned).map_err(|e| {
+                DatabaseSeedError::Internal(format!("failed to read {}: {e}", path.display()))
+            })
+        };
+
+        Ok(Self {
+            release: release.to_string(),
+            regions: read("regions.json")?,
+            provinces: read("provinces.json")?,
+            cities: read("cities.json")?,
+            municipalities: read("municipalities.json")?,
+            barangays: read("barangays.json")?,
+        })
+    }
+
+    pub(crate) fn build(&self) -> Result<SeedRows, DatabaseSeedError> {
+        let regions = build_regions(self)?;
+        let (provinces, mut former_names) = build_provinces(self, &regions)?;
+        let (cities, city_names) = build_cities(self, &regions, &provinces)?;
+        let (municipalities, municipality_names) =
+            build_municipalities(self, &regions, &provinces)?;
+        let (barangays, barangay_names) = build_barangays(self, &cities, &municipalities)?;
+        former_names.extend(city_names);
+        former_names.extend(municipality_names);
+        former_names.extend(barangay_names);
+
+        Ok(SeedRows {
+            regions,
+            provinces,
+            cities,
+            municipalities,
+            barangays,
+            former_names,
+        })
+    }
+}
@@ -1,17 +1,134 @@
-use rbatis::RBatis;
+use psgc_domain::models::{audit::AuditEntry, release::Release as ReleaseModel};
+use rbatis::{RBatis, executor::Executor};
+use serde::Serialize;
 use std::sync::Arc;
+use tracing::info;
 
-use crate::database::models::{
-    barangay::seed_barangays, city::seed_cities, municipality::seed_municipalities,
-    province::seed_provinces, region::seed_regions,
+use crate::database::{
+    DatabaseSeedError,
+    generators::{UuidExt, datetime_utc_now},
+    models::{
+        audit_log::AuditLog, barangay::Barangay, city::City, former_name::FormerName,
+        municipality::Municipality, province::Province, region::Region, release, release::Release,
+    },
+    seed_data::SeedData,
 };
 
-pub async fn seeder(db: Arc<RBatis>) -> anyhow::Result<()> {
-    seed_regions(&db).await?;
-    seed_provinces(&db).await?;
-    seed_cities(&db).await?;
-    seed_municipalities(&db).await?;
-    seed_barangays(&db).await?;
+/// Loads one release alongside those already in the database, in one transaction that
+/// also records the run in the audit log, so a failed or partial seed leaves the database
+/// as it was. A release can only be loaded once.
+pub async fn seeder(db: Arc<RBatis>, data: &SeedData) -> anyhow::Result<()> {
+    if !ReleaseModel::is_valid_id(&data.release) {
+        anyhow::bail!(
+            "{} is not a release id; use the year and quarter, e.g. 2024Q3",
+            data.release
+        );
+    }
 
-    Ok(())
+    let existing = Release::select_by_id(db.as_ref(), &data.release).await?;
+    if existing.is_some() {
+        anyhow::bail!("release {} is already loaded", data.release);
+    }
+
+    info!("Building release {} from the seed data...", data.release);
+    let rows = data.build()?;
+
+    let tx = db.acquire_begin().await?;
+
+    let result = async {
+        let release = Release::builder()
+            .id(data.release.clone())
+            .loaded_at(datetime_utc_now())
+            .build();
+        Release::insert(&tx, &release)
+            .await
+            .map_err(DatabaseSeedError::DbError)?;
+
+        let regions = insert_rows(&tx, "regions", &rows.regions, Region::insert_batch).await?;
+        let provinces =
+            insert_rows(&tx, "provinces", &rows.provinces, Province::insert_batch).await?;
+        let cities = insert_rows(&tx, "cities", &rows.cities, City::insert_batch).await?;
+        let municipalities = insert_rows(
+            &tx,
+            "municipalities",
+            &rows.municipalities,
+            Municipality::insert_batch,
+        )
+        .await?;
+        let barangays =
+            insert_rows(&tx, "barangays", &rows.barangays, Barangay::insert_batch).await?;
+        let former_names = insert_rows(
+            &tx,
+            "former names",
+            &rows.former_names,
+            FormerName::insert_batch,
+        )
+        .await?;
+
+        let seeded = serde_json::json!({
+            "release": data.release,
+            "regions": regions,
+            "provinces": provinces,
+            "cities": cities,
+            "municipalities": municipalities,
+            "barangays": barangays,
+            "former_names": former_names,
+        });
+        let audit = AuditEntry::builder()
+            .id(uuid::Uuid::now_v7())
+            .actor("seeder".to_string())
+            .entity("dataset".to_string())
+            .entity_id(uuid::Uuid::now_v7())
+            .code(String::new())
+            .after(seeded)
+            .reason(format!("Seeded release {} from the PSA data", data.release))
+            .created_at(chrono::Utc::now())
+            .build();
+        AuditLog::insert(&tx, &(&audit).into())
+            .await
+            .map_err(DatabaseSeedError::DbError)?;
+        release::update_position(&tx, &data.release, &audit.id.into_db())
+            .await
+            .map_err(DatabaseSeedError::DbError)?;
+
+        Ok::<_, DatabaseSeedError>(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => Ok(tx.commit().await?),
+        Err(e) => {
+            tx.rollback().await?;
+            Err(e.into())
+        }
+    }
+}
+
+/// Inserts `rows` in batches with the table's generated `insert_batch` and returns how
+/// many were added.
+async fn insert_rows<'a, T: Serialize>(
+    executor: &'a dyn Executor,
+    table: &str,
+    rows: &'a [T],
+    insert_batch: impl AsyncFnOnce(
+        &'a dyn Executor,
+        &'a [T],
+        u64,
+    ) -> Result<rbatis::rbdc::db::ExecResult, rbatis::Error>,
+) -> Result<usize, DatabaseSeedError> {
+    info!("Seeding {}...", table);
+
+    // rbatis refuses an empty batch; a release may lack a level, e.g. barangays
+    if rows.is_empty() {
+        info!("No {} to add", table);
+        return Ok(0);
+    }
+
+    insert_batch(executor, rows, 1000)
+        .await
+        .map_err(DatabaseSeedError::DbError)?;
+
+    info!("Added {} {} to database", rows.len(), table);
+
+    Ok(rows.len())
 }
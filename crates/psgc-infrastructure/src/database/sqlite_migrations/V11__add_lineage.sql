@@ -0,0 +1,16 @@
+-- How units replaced one another: one row per predecessor and successor of a conversion,
+-- split, merge, rename or code change. A split has a row per successor and a merge a row
+-- per predecessor; a rename or conversion that kept its code has the same code on both
+-- sides. Codes are kept as recorded and aren't tied to a release.
+CREATE TABLE
+    lineage (
+        id TEXT PRIMARY KEY,
+        predecessor_code TEXT NOT NULL,
+        successor_code TEXT NOT NULL,
+        event_type TEXT NOT NULL,
+        effective_on TEXT NOT NULL,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE (predecessor_code, successor_code, event_type, effective_on)
+    );
+
+CREATE INDEX idx_lineage_successor_code ON lineage (successor_code);
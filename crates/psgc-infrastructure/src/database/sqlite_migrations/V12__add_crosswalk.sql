@@ -0,0 +1,12 @@
+-- Curated mappings from codes of earlier PSGC editions to the codes in use now, for codes
+-- that neither the current release nor the lineage table resolves. mapping_type is
+-- identical, renamed, split or merged; a code that maps to several has a row for each.
+CREATE TABLE
+    crosswalk (
+        id TEXT PRIMARY KEY,
+        historical_code TEXT NOT NULL,
+        current_code TEXT NOT NULL,
+        mapping_type TEXT NOT NULL,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE (historical_code, current_code)
+    );
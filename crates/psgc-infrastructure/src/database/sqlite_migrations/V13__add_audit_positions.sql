@@ -0,0 +1,68 @@
+-- Where each entry stands in the feed of changes. SQLite runs one write transaction at a
+-- time, so numbering entries as they are inserted follows the order they commit in. An
+-- entry's created_at is stamped before its transaction starts, so the trigger also moves
+-- it up to that of the entry before, keeping times in the same order as positions.
+ALTER TABLE audit_log
+ADD COLUMN position INTEGER;
+
+UPDATE audit_log
+SET
+    position = (
+        SELECT
+            COUNT(*)
+        FROM
+            audit_log earlier
+        WHERE
+            earlier.id <= audit_log.id
+    );
+
+CREATE UNIQUE INDEX idx_audit_log_position ON audit_log (position);
+
+CREATE TRIGGER audit_log_position AFTER INSERT ON audit_log WHEN NEW.position IS NULL
+BEGIN
+UPDATE audit_log
+SET
+    position = (
+        SELECT
+            COALESCE(MAX(position), 0) + 1
+        FROM
+            audit_log
+    ),
+    created_at = MAX(
+        NEW.created_at,
+        COALESCE(
+            (
+                SELECT
+                    MAX(created_at)
+                FROM
+                    audit_log
+                WHERE
+                    id <> NEW.id
+            ),
+            NEW.created_at
+        )
+    )
+WHERE
+    id = NEW.id;
+
+END;
+
+-- Where the feed stood once a release was loaded: the position of its seed run's entry.
+-- Releases loaded before positions existed take the first seed run from their loading on.
+ALTER TABLE releases
+ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+
+UPDATE releases
+SET
+    position = COALESCE(
+        (
+            SELECT
+                MIN(audit_log.position)
+            FROM
+                audit_log
+            WHERE
+                entity = 'dataset'
+                AND created_at >= releases.loaded_at
+        ),
+        0
+    );
@@ -0,0 +1,90 @@
+-- sqlite_migrations/V1__initial.sql, mirrors migrations/V1__initial.sql
+-- UUIDs and timestamps are stored as TEXT
+-- Create regions table
+CREATE TABLE
+    regions (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        designation TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+-- Create provinces table
+CREATE TABLE
+    provinces (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        income_class TEXT NOT NULL,
+        region_id TEXT REFERENCES regions (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+-- Create districts table
+CREATE TABLE
+    districts (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        region_id TEXT REFERENCES regions (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+-- Create cities table
+CREATE TABLE
+    cities (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        city_class TEXT NOT NULL,
+        income_class TEXT NOT NULL,
+        region_id TEXT REFERENCES regions (id) ON DELETE CASCADE,
+        province_id TEXT REFERENCES provinces (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+-- Create municipalities table
+CREATE TABLE
+    municipalities (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        income_class TEXT NOT NULL,
+        parent_municipality_id TEXT NULL REFERENCES municipalities (id) ON DELETE CASCADE,
+        region_id TEXT REFERENCES regions (id) ON DELETE CASCADE,
+        province_id TEXT REFERENCES provinces (id) ON DELETE CASCADE,
+        district_id TEXT REFERENCES districts (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
+
+-- Create barangays table
+CREATE TABLE
+    barangays (
+        id TEXT PRIMARY KEY,
+        code TEXT NOT NULL,
+        correspondence_code TEXT NOT NULL,
+        name TEXT NOT NULL,
+        population INTEGER NOT NULL,
+        urban_rural TEXT NOT NULL,
+        city_id TEXT REFERENCES cities (id) ON DELETE CASCADE,
+        municipality_id TEXT REFERENCES municipalities (id) ON DELETE CASCADE,
+        district_id TEXT REFERENCES districts (id) ON DELETE CASCADE,
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+    );
@@ -0,0 +1,29 @@
+-- Slugs and folded names are derived by the seeder (see psgc_shared::text)
+-- SQLite only adds one column per ALTER TABLE
+ALTER TABLE regions ADD COLUMN slug TEXT;
+ALTER TABLE regions ADD COLUMN normalized_name TEXT;
+ALTER TABLE provinces ADD COLUMN slug TEXT;
+ALTER TABLE provinces ADD COLUMN normalized_name TEXT;
+ALTER TABLE districts ADD COLUMN slug TEXT;
+ALTER TABLE districts ADD COLUMN normalized_name TEXT;
+ALTER TABLE cities ADD COLUMN slug TEXT;
+ALTER TABLE cities ADD COLUMN normalized_name TEXT;
+ALTER TABLE municipalities ADD COLUMN slug TEXT;
+ALTER TABLE municipalities ADD COLUMN normalized_name TEXT;
+ALTER TABLE barangays ADD COLUMN slug TEXT;
+ALTER TABLE barangays ADD COLUMN normalized_name TEXT;
+
+-- Slugs are unique per level
+CREATE UNIQUE INDEX idx_region_slug ON regions(slug);
+CREATE UNIQUE INDEX idx_province_slug ON provinces(slug);
+CREATE UNIQUE INDEX idx_district_slug ON districts(slug);
+CREATE UNIQUE INDEX idx_city_slug ON cities(slug);
+CREATE UNIQUE INDEX idx_municipality_slug ON municipalities(slug);
+CREATE UNIQUE INDEX idx_barangay_slug ON barangays(slug);
+
+CREATE INDEX idx_region_normalized_name ON regions(normalized_name);
+CREATE INDEX idx_province_normalized_name ON provinces(normalized_name);
+CREATE INDEX idx_district_normalized_name ON districts(normalized_name);
+CREATE INDEX idx_city_normalized_name ON cities(normalized_name);
+CREATE INDEX idx_municipality_normalized_name ON municipalities(normalized_name);
+CREATE INDEX idx_barangay_normalized_name ON barangays(normalized_name);
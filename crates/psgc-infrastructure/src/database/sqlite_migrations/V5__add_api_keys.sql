@@ -0,0 +1,22 @@
+-- Keys are stored as SHA-256 digests; allowed_origins is a comma-separated list
+CREATE TABLE
+    api_keys (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL UNIQUE,
+        prefix TEXT NOT NULL,
+        key_hash TEXT NOT NULL UNIQUE,
+        rate_limit_per_minute INTEGER NOT NULL,
+        daily_quota INTEGER,
+        allowed_origins TEXT NOT NULL DEFAULT '',
+        created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        revoked_at TEXT
+    );
+
+-- Requests per key per UTC day, with the day as YYYY-MM-DD
+CREATE TABLE
+    api_key_usage (
+        api_key_id TEXT NOT NULL REFERENCES api_keys (id) ON DELETE CASCADE,
+        day TEXT NOT NULL,
+        requests INTEGER NOT NULL DEFAULT 0,
+        PRIMARY KEY (api_key_id, day)
+    );
@@ -0,0 +1,2 @@
+-- Admin keys can use the /api/v1/admin write endpoints
+ALTER TABLE api_keys ADD COLUMN admin BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,15 @@
+-- Names a province, city, municipality or barangay was known by before, taken from the
+-- oldName field of the PSA data. unit_id points into the table named by level.
+CREATE TABLE
+    former_names (
+        id TEXT PRIMARY KEY,
+        release_id TEXT NOT NULL REFERENCES releases (id) ON DELETE CASCADE,
+        level TEXT NOT NULL,
+        unit_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        normalized_name TEXT NOT NULL
+    );
+
+CREATE INDEX idx_former_names_unit_id ON former_names (unit_id);
+
+CREATE INDEX idx_former_names_normalized_name ON former_names (release_id, level, normalized_name);
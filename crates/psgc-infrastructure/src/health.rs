@@ -0,0 +1,175 @@
+//! Readiness checks behind the `/readyz` probe.
+
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use rbatis::RBatis;
+use serde::Deserialize;
+
+use crate::{database::migrator::latest_migration_version, repositories::memory::InMemoryDataset};
+
+/// Upper bound for a single check, so a hung pool can't stall the probe.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Tables that must hold rows once the seeder has run. Districts are left out since the
+/// seed data doesn't populate them.
+const SEEDED_TABLES: [&str; 5] = [
+    "regions",
+    "provinces",
+    "cities",
+    "municipalities",
+    "barangays",
+];
+
+/// What `/readyz` inspects, matching the store the repositories read from.
+#[derive(Clone)]
+pub enum ReadinessProbe {
+    Sql(Arc<RBatis>),
+    InMemory(Arc<InMemoryDataset>),
+}
+
+#[derive(Debug)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub latency: Duration,
+    pub error: Option<String>,
+}
+
+impl CheckResult {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct ReadinessReport {
+    pub checks: Vec<CheckResult>,
+}
+
+impl ReadinessReport {
+    pub fn is_ready(&self) -> bool {
+        self.checks.iter().all(CheckResult::is_ok)
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionRow {
+    version: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct CountRow {
+    count: i64,
+}
+
+impl ReadinessProbe {
+    pub async fn check(&self) -> ReadinessReport {
+        let checks = match self {
+            Self::Sql(db) => vec![
+                run_check("database", check_connection(db)).await,
+                run_check("migrations", check_migrations(db)).await,
+                run_check("seed_data", check_seeded_tables(db)).await,
+            ],
+            Self::InMemory(dataset) => {
+                vec![run_check("seed_data", async { check_dataset(dataset) }).await]
+            }
+        };
+
+        ReadinessReport { checks }
+    }
+}
+
+async fn run_check(
+    name: &'static str,
+    check: impl Future<Output = Result<(), String>>,
+) -> CheckResult {
+    let started = Instant::now();
+    let result = tokio::time::timeout(CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Err(format!("timed out after {}ms", CHECK_TIMEOUT.as_millis())));
+
+    CheckResult {
+        name,
+        latency: started.elapsed(),
+        error: result.err(),
+    }
+}
+
+async fn check_connection(db: &RBatis) -> Result<(), String> {
+    let conn = db.acquire().await.map_err(|e| e.to_string())?;
+    conn.exec("SELECT 1", vec![])
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+async fn check_migrations(db: &RBatis) -> Result<(), String> {
+    let sqlite = db.driver_type().map_err(|e| e.to_string())? == "sqlite";
+    let conn = db.acquire().await.map_err(|e| e.to_string())?;
+    let rows: Vec<VersionRow> = conn
+        .query_decode(
+            "SELECT CAST(MAX(version) AS BIGINT) AS version FROM refinery_schema_history",
+            vec![],
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let applied = rows.into_iter().next().and_then(|row| row.version);
+    let expected = latest_migration_version(sqlite);
+
+    match applied {
+        Some(applied) if applied >= expected => Ok(()),
+        Some(applied) => Err(format!(
+            "schema is at version {applied}, expected {expected}"
+        )),
+        None => Err("no migrations have been applied".to_string()),
+    }
+}
+
+async fn check_seeded_tables(db: &RBatis) -> Result<(), String> {
+    let conn = db.acquire().await.map_err(|e| e.to_string())?;
+    let mut empty = Vec::new();
+
+    for table in SEEDED_TABLES {
+        let sql = format!("SELECT CAST(COUNT(*) AS BIGINT) AS count FROM {table}");
+        let rows: Vec<CountRow> = conn
+            .query_decode(&sql, vec![])
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if rows.first().is_none_or(|row| row.count == 0) {
+            empty.push(table);
+        }
+    }
+
+    empty_tables_error(&empty)
+}
+
+fn check_dataset(dataset: &InMemoryDataset) -> Result<(), String> {
+    let counts = [
+        ("regions", dataset.regions.len()),
+        ("provinces", dataset.provinces.len()),
+        ("cities", dataset.cities.len()),
+        ("municipalities", dataset.municipalities.len()),
+        ("barangays", dataset.barangays.len()),
+    ];
+    let empty: Vec<_> = counts
+        .into_iter()
+        .filter(|(_, count)| *count == 0)
+        .map(|(table, _)| table)
+        .collect();
+
+    empty_tables_error(&empty)
+}
+
+fn empty_tables_error(empty: &[&str]) -> Result<(), String> {
+    if empty.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("no rows in {}", empty.join(", ")))
+    }
+}
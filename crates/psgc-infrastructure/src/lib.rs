@@ -1,3 +1,5 @@
+pub mod cache;
 pub mod config;
 pub mod database;
+pub mod health;
 pub mod repositories;
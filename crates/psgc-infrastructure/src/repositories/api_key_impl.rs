@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use psgc_domain::{
+    errors::RepositoryError, models::api_key::ApiKey,
+    repositories::api_key_repository::ApiKeyRepository,
+};
+use rbatis::RBatis;
+use tracing::instrument;
+
+use crate::database::models;
+
+/// API keys live in the database for both SQL drivers; there's no in-memory or cached variant.
+pub struct PgApiKeyRepository {
+    db: Arc<RBatis>,
+}
+
+impl PgApiKeyRepository {
+    pub fn new(db: Arc<RBatis>) -> Self {
+        Self { db }
+    }
+}
+
+impl ApiKeyRepository for PgApiKeyRepository {
+    #[instrument(name = "PgApiKeyRepository::find_by_hash", skip_all)]
+    async fn find_by_hash(&self, key_hash: &str) -> Result<ApiKey, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let api_key = models::api_key::ApiKey::select_by_key_hash(&executor, key_hash)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(api_key.into())
+    }
+
+    #[instrument(name = "PgApiKeyRepository::list_all", skip(self))]
+    async fn list_all(&self) -> Result<Vec<ApiKey>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let api_keys = models::api_key::ApiKey::list_all(&executor)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(api_keys.into_iter().map(|k| k.into()).collect())
+    }
+
+    #[instrument(name = "PgApiKeyRepository::create", skip_all, fields(name = %api_key.name))]
+    async fn create(&self, api_key: &ApiKey) -> Result<(), RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let existing =
+            models::api_key::ApiKey::select_by_map(&executor, rbs::value! {"name": &api_key.name})
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !existing.is_empty() {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "an API key named {} already exists",
+                api_key.name
+            )));
+        }
+
+        models::api_key::ApiKey::insert(&executor, &api_key.into())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "PgApiKeyRepository::revoke", skip(self))]
+    async fn revoke(&self, name: &str) -> Result<(), RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let revoked = models::api_key::revoke_api_key(&executor, name)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        match revoked {
+            0 => Err(RepositoryError::NotFound),
+            _ => Ok(()),
+        }
+    }
+
+    #[instrument(name = "PgApiKeyRepository::record_usage", skip(self))]
+    async fn record_usage(&self, id: uuid::Uuid, day: NaiveDate) -> Result<u64, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        models::api_key::increment_usage(&executor, id, &day.format("%Y-%m-%d").to_string())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+    }
+}
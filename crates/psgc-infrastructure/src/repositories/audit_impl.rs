@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{PaginateResult, audit::AuditEntry},
+    repositories::audit_repository::AuditLogRepository,
+};
+use rbatis::{PageRequest, RBatis, executor::RBatisTxExecutor};
+use tracing::{instrument, warn};
+
+use crate::database::{
+    generators::{ChronoDateTimeExt, PageExt},
+    models,
+};
+
+/// The audit log lives in the database for both SQL drivers; there's no in-memory or
+/// cached variant.
+pub struct PgAuditLogRepository {
+    db: Arc<RBatis>,
+}
+
+impl PgAuditLogRepository {
+    pub fn new(db: Arc<RBatis>) -> Self {
+        Self { db }
+    }
+}
+
+/// Runs `change` and records `audit` in one transaction, so neither is kept without the
+/// other. `change` returns the number of rows it affected; when that's zero nothing is
+/// recorded and the unit is reported as not found.
+pub(crate) async fn audited(
+    db: &RBatis,
+    audit: &AuditEntry,
+    change: impl AsyncFnOnce(&RBatisTxExecutor) -> Result<u64, rbatis::Error>,
+) -> Result<(), RepositoryError> {
+    let tx = db
+        .acquire_begin()
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+    let result = async {
+        if change(&tx).await? == 0 {
+            return Ok(false);
+        }
+        models::audit_log::AuditLog::insert(&tx, &audit.into()).await?;
+
+        Ok::<_, rbatis::Error>(true)
+    }
+    .await;
+
+    match result {
+        Ok(true) => tx
+            .commit()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string())),
+        Ok(false) => {
+            rollback(&tx).await;
+            Err(RepositoryError::NotFound)
+        }
+        Err(e) => {
+            rollback(&tx).await;
+            Err(write_error(e))
+        }
+    }
+}
+
+/// A write turned down by a unique index, such as a second unit with a code already used
+/// in its release, is a conflict rather than a database failure.
+fn write_error(e: rbatis::Error) -> RepositoryError {
+    let message = e.to_string();
+    if !message.contains("duplicate key value violates unique constraint")
+        && !message.contains("UNIQUE constraint failed")
+    {
+        return RepositoryError::DatabaseError(message);
+    }
+
+    // Postgres names the index, SQLite the columns
+    let taken = if message.contains("release_code") || message.contains(".code") {
+        "a unit of this level with this code already exists in the release".to_string()
+    } else if message.contains("slug") {
+        "a unit of this level with this slug already exists in the release".to_string()
+    } else {
+        format!("this would duplicate an existing record: {message}")
+    };
+
+    RepositoryError::ConstraintViolation(taken)
+}
+
+async fn rollback(tx: &RBatisTxExecutor) {
+    if let Err(e) = tx.rollback().await {
+        warn!("failed to roll back transaction: {}", e);
+    }
+}
+
+impl AuditLogRepository for PgAuditLogRepository {
+    #[instrument(name = "PgAuditLogRepository::list", skip(self))]
+    async fn list(
+        &self,
+        code: Option<&str>,
+        since: Option<chrono::DateTime<Utc>>,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<AuditEntry>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let filter = models::audit_log::AuditFilter {
+            code: code.unwrap_or_default().to_string(),
+            since: since.unwrap_or(chrono::DateTime::UNIX_EPOCH).into_db(),
+        };
+        let entries = models::audit_log::AuditLog::list_filtered(
+            &executor,
+            &PageRequest::new(page, limit),
+            &filter,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(entries.into_domain())
+    }
+
+    #[instrument(name = "PgAuditLogRepository::history", skip(self))]
+    async fn history(&self, entity: &str, code: &str) -> Result<Vec<AuditEntry>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let entries = models::audit_log::AuditLog::select_history(&executor, entity, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(entries.into_iter().map(|e| e.into()).collect())
+    }
+
+    #[instrument(name = "PgAuditLogRepository::list_unit_changes", skip(self))]
+    async fn list_unit_changes(
+        &self,
+        release: &str,
+        after: i64,
+        limit: u64,
+    ) -> Result<Vec<AuditEntry>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let entries = models::audit_log::select_unit_changes(&executor, release, after, limit)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(entries.into_iter().map(|e| e.into()).collect())
+    }
+
+    #[instrument(name = "PgAuditLogRepository::last_unit_change", skip(self))]
+    async fn last_unit_change(&self, release: &str) -> Result<Option<AuditEntry>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let entry = models::audit_log::select_last_unit_change(&executor, release)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(entry.map(|e| e.into()))
+    }
+
+    #[instrument(name = "PgAuditLogRepository::position_before", skip(self))]
+    async fn position_before(&self, time: chrono::DateTime<Utc>) -> Result<i64, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        models::audit_log::select_position_before(&executor, &time.into_db())
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))
+    }
+}
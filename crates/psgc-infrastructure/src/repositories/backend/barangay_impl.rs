@@ -0,0 +1,77 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        stats::GroupCount,
+    },
+    repositories::barangay_repository::BarangayRepository,
+};
+
+use crate::repositories::{
+    barangay_impl::PgBarangayRepository, cached::barangay_impl::CachedBarangayRepository,
+    memory::barangay_impl::InMemoryBarangayRepository,
+};
+
+pub enum AnyBarangayRepository {
+    Sql(PgBarangayRepository),
+    Cached(CachedBarangayRepository<PgBarangayRepository>),
+    InMemory(InMemoryBarangayRepository),
+}
+
+impl BarangayRepository for AnyBarangayRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Barangay, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Barangay, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_city_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_city_code(code))
+    }
+
+    async fn list_by_municipality_code(
+        &self,
+        code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_municipality_code(code))
+    }
+
+    async fn list_by_district_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_district_code(code))
+    }
+
+    async fn count_by_urban_rural(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        dispatch!(self, repository => repository.count_by_urban_rural())
+    }
+
+    async fn create(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(barangay, audit))
+    }
+
+    async fn update(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(barangay, audit))
+    }
+}
@@ -0,0 +1,74 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, city::City,
+        island_group::IslandGroup, stats::GroupCount,
+    },
+    repositories::city_repository::CityRepository,
+};
+
+use crate::repositories::{
+    cached::city_impl::CachedCityRepository, city_impl::PgCityRepository,
+    memory::city_impl::InMemoryCityRepository,
+};
+
+pub enum AnyCityRepository {
+    Sql(PgCityRepository),
+    Cached(CachedCityRepository<PgCityRepository>),
+    InMemory(InMemoryCityRepository),
+}
+
+impl CityRepository for AnyCityRepository {
+    async fn find_by_code(&self, code: &str) -> Result<City, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<City, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_region_code(code))
+    }
+
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_province_code(code))
+    }
+
+    async fn list_barangays(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_barangays(code))
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        dispatch!(self, repository => repository.count_by_income_class())
+    }
+
+    async fn create(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(city, audit))
+    }
+
+    async fn update(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(city, audit))
+    }
+}
@@ -0,0 +1,74 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, district::District,
+        island_group::IslandGroup, municipality::Municipality,
+    },
+    repositories::district_repository::DistrictRepository,
+};
+
+use crate::repositories::{
+    cached::district_impl::CachedDistrictRepository, district_impl::PgDistrictRepository,
+    memory::district_impl::InMemoryDistrictRepository,
+};
+
+pub enum AnyDistrictRepository {
+    Sql(PgDistrictRepository),
+    Cached(CachedDistrictRepository<PgDistrictRepository>),
+    InMemory(InMemoryDistrictRepository),
+}
+
+impl DistrictRepository for AnyDistrictRepository {
+    async fn find_by_code(&self, code: &str) -> Result<District, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<District, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<District>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_region_code(code))
+    }
+
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_province_code(code))
+    }
+
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_cities(code))
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_municipalities(code))
+    }
+
+    async fn create(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(district, audit))
+    }
+
+    async fn update(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(district, audit))
+    }
+}
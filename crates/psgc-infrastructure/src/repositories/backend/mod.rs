@@ -0,0 +1,308 @@
+//! Runtime-selected repositories.
+//!
+//! The repository traits use `async fn`, so they aren't object safe; each `Any*Repository`
+//! enum dispatches to whichever backend was picked from [`BackendConfig`] instead.
+
+/// Forwards a call to the backend wrapped by an `Any*Repository`.
+macro_rules! dispatch {
+    ($self:ident, $repository:ident => $call:expr) => {
+        match $self {
+            Self::Sql($repository) => $call.await,
+            Self::Cached($repository) => $call.await,
+            Self::InMemory($repository) => $call.await,
+        }
+    };
+}
+
+pub mod barangay_impl;
+pub mod city_impl;
+pub mod district_impl;
+pub mod municipality_impl;
+pub mod province_impl;
+pub mod region_impl;
+pub mod release_impl;
+
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use rbatis::RBatis;
+use tracing::{info, warn};
+
+use crate::{
+    cache::{CacheStats, RedisCache},
+    config::backend_config::{Backend, BackendConfig},
+    database::pool::{PoolStats, create_db_pool, pool_stats},
+    health::ReadinessProbe,
+    repositories::{
+        api_key_impl::PgApiKeyRepository,
+        audit_impl::PgAuditLogRepository,
+        backend::{
+            barangay_impl::AnyBarangayRepository, city_impl::AnyCityRepository,
+            district_impl::AnyDistrictRepository, municipality_impl::AnyMunicipalityRepository,
+            province_impl::AnyProvinceRepository, region_impl::AnyRegionRepository,
+            release_impl::AnyReleaseRepository,
+        },
+        barangay_impl::PgBarangayRepository,
+        cached::{
+            barangay_impl::CachedBarangayRepository, city_impl::CachedCityRepository,
+            district_impl::CachedDistrictRepository,
+            municipality_impl::CachedMunicipalityRepository,
+            province_impl::CachedProvinceRepository, region_impl::CachedRegionRepository,
+            release_impl::CachedReleaseRepository,
+        },
+        city_impl::PgCityRepository,
+        crosswalk_impl::PgCrosswalkRepository,
+        district_impl::PgDistrictRepository,
+        lineage_impl::PgLineageRepository,
+        memory::{
+            InMemoryDataset, barangay_impl::InMemoryBarangayRepository,
+            city_impl::InMemoryCityRepository, district_impl::InMemoryDistrictRepository,
+            municipality_impl::InMemoryMunicipalityRepository,
+            province_impl::InMemoryProvinceRepository, region_impl::InMemoryRegionRepository,
+            release_impl::InMemoryReleaseRepository,
+        },
+        municipality_impl::PgMunicipalityRepository,
+        province_impl::PgProvinceRepository,
+        region_impl::PgRegionRepository,
+        release_impl::PgReleaseRepository,
+    },
+};
+
+/// One repository per PSGC level, all reading the same release from the same store.
+#[derive(Clone)]
+pub struct UnitRepositories {
+    pub region: Arc<AnyRegionRepository>,
+    pub province: Arc<AnyProvinceRepository>,
+    pub municipality: Arc<AnyMunicipalityRepository>,
+    pub district: Arc<AnyDistrictRepository>,
+    pub city: Arc<AnyCityRepository>,
+    pub barangay: Arc<AnyBarangayRepository>,
+}
+
+/// Where the data lives, kept so repositories for any release can be built from it.
+#[derive(Clone)]
+enum Store {
+    Sql(Arc<RBatis>),
+    Cached(Arc<RBatis>, RedisCache),
+    InMemory(Arc<InMemoryDataset>),
+}
+
+/// Everything the server reads and writes through, backed by one store. The PSGC units
+/// themselves are read per release with [`Repositories::for_release`].
+#[derive(Clone)]
+pub struct Repositories {
+    pub releases: Arc<AnyReleaseRepository>,
+    pub readiness: ReadinessProbe,
+    /// Only the SQL backends can store API keys
+    pub api_keys: Option<Arc<PgApiKeyRepository>>,
+    /// Likewise the audit log; the in-memory backend is read-only and has nothing to log
+    pub audit_log: Option<Arc<PgAuditLogRepository>>,
+    /// And the lineage of codes, which the bundled data doesn't have
+    pub lineage: Option<Arc<PgLineageRepository>>,
+    /// And the crosswalk of historical codes
+    pub crosswalk: Option<Arc<PgCrosswalkRepository>>,
+    store: Store,
+}
+
+impl Repositories {
+    pub async fn connect(config: &BackendConfig) -> anyhow::Result<Self> {
+        info!("using the {} repository backend", config.backend);
+
+        match config.backend {
+            Backend::InMemory => Ok(Self::in_memory(Arc::new(InMemoryDataset::load()?))),
+            Backend::Sql | Backend::Cached => {
+                let database = config
+                    .database
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!("DATABASE_URL is required"))?;
+                info!(
+                    "serving from {}",
+                    if database.is_sqlite() {
+                        "SQLite"
+                    } else {
+                        "PostgreSQL"
+                    }
+                );
+                let db = create_db_pool(database)?;
+
+                if config.backend == Backend::Cached {
+                    let cache = RedisCache::connect(&config.cache).await;
+                    Ok(Self::cached(db, cache))
+                } else {
+                    Ok(Self::sql(db))
+                }
+            }
+        }
+    }
+
+    /// Postgres or SQLite, depending on the driver `db` was created with.
+    pub fn sql(db: Arc<RBatis>) -> Self {
+        Self {
+            releases: Arc::new(AnyReleaseRepository::Sql(PgReleaseRepository::new(
+                db.clone(),
+            ))),
+            readiness: ReadinessProbe::Sql(db.clone()),
+            api_keys: Some(Arc::new(PgApiKeyRepository::new(db.clone()))),
+            audit_log: Some(Arc::new(PgAuditLogRepository::new(db.clone()))),
+            lineage: Some(Arc::new(PgLineageRepository::new(db.clone()))),
+            crosswalk: Some(Arc::new(PgCrosswalkRepository::new(db.clone()))),
+            store: Store::Sql(db),
+        }
+    }
+
+    /// SQL repositories behind a Redis read-through cache.
+    pub fn cached(db: Arc<RBatis>, cache: RedisCache) -> Self {
+        Self {
+            releases: Arc::new(AnyReleaseRepository::Cached(CachedReleaseRepository::new(
+                PgReleaseRepository::new(db.clone()),
+                cache.clone(),
+            ))),
+            readiness: ReadinessProbe::Sql(db.clone()),
+            api_keys: Some(Arc::new(PgApiKeyRepository::new(db.clone()))),
+            audit_log: Some(Arc::new(PgAuditLogRepository::new(db.clone()))),
+            lineage: Some(Arc::new(PgLineageRepository::new(db.clone()))),
+            crosswalk: Some(Arc::new(PgCrosswalkRepository::new(db.clone()))),
+            store: Store::Cached(db, cache),
+        }
+    }
+
+    pub fn in_memory(dataset: Arc<InMemoryDataset>) -> Self {
+        Self {
+            releases: Arc::new(AnyReleaseRepository::InMemory(
+                InMemoryReleaseRepository::new(dataset.clone()),
+            )),
+            readiness: ReadinessProbe::InMemory(dataset.clone()),
+            api_keys: None,
+            audit_log: None,
+            lineage: None,
+            crosswalk: None,
+            store: Store::InMemory(dataset),
+        }
+    }
+
+    /// Repositories for the units of `release`, which is expected to have been looked up in
+    /// [`Repositories::releases`], as they stood on `as_of` or today. The in-memory backend
+    /// only holds the bundled release, which has no history, and serves it whatever is
+    /// asked for.
+    pub fn for_release(&self, release: &str, as_of: Option<NaiveDate>) -> UnitRepositories {
+        match &self.store {
+            Store::Sql(db) => {
+                let release = release.to_string();
+
+                UnitRepositories {
+                    region: Arc::new(AnyRegionRepository::Sql(PgRegionRepository::new(
+                        db.clone(),
+                        release.clone(),
+                        as_of,
+                    ))),
+                    province: Arc::new(AnyProvinceRepository::Sql(PgProvinceRepository::new(
+                        db.clone(),
+                        release.clone(),
+                        as_of,
+                    ))),
+                    municipality: Arc::new(AnyMunicipalityRepository::Sql(
+                        PgMunicipalityRepository::new(db.clone(), release.clone(), as_of),
+                    )),
+                    district: Arc::new(AnyDistrictRepository::Sql(PgDistrictRepository::new(
+                        db.clone(),
+                        release.clone(),
+                        as_of,
+                    ))),
+                    city: Arc::new(AnyCityRepository::Sql(PgCityRepository::new(
+                        db.clone(),
+                        release.clone(),
+                        as_of,
+                    ))),
+                    barangay: Arc::new(AnyBarangayRepository::Sql(PgBarangayRepository::new(
+                        db.clone(),
+                        release,
+                        as_of,
+                    ))),
+                }
+            }
+            Store::Cached(db, cache) => {
+                let cache = cache.for_release(release, as_of);
+                let release = release.to_string();
+
+                UnitRepositories {
+                    region: Arc::new(AnyRegionRepository::Cached(CachedRegionRepository::new(
+                        PgRegionRepository::new(db.clone(), release.clone(), as_of),
+                        cache.clone(),
+                    ))),
+                    province: Arc::new(AnyProvinceRepository::Cached(
+                        CachedProvinceRepository::new(
+                            PgProvinceRepository::new(db.clone(), release.clone(), as_of),
+                            cache.clone(),
+                        ),
+                    )),
+                    municipality: Arc::new(AnyMunicipalityRepository::Cached(
+                        CachedMunicipalityRepository::new(
+                            PgMunicipalityRepository::new(db.clone(), release.clone(), as_of),
+                            cache.clone(),
+                        ),
+                    )),
+                    district: Arc::new(AnyDistrictRepository::Cached(
+                        CachedDistrictRepository::new(
+                            PgDistrictRepository::new(db.clone(), release.clone(), as_of),
+                            cache.clone(),
+                        ),
+                    )),
+                    city: Arc::new(AnyCityRepository::Cached(CachedCityRepository::new(
+                        PgCityRepository::new(db.clone(), release.clone(), as_of),
+                        cache.clone(),
+                    ))),
+                    barangay: Arc::new(AnyBarangayRepository::Cached(
+                        CachedBarangayRepository::new(
+                            PgBarangayRepository::new(db.clone(), release, as_of),
+                            cache,
+                        ),
+                    )),
+                }
+            }
+            Store::InMemory(dataset) => UnitRepositories {
+                region: Arc::new(AnyRegionRepository::InMemory(
+                    InMemoryRegionRepository::new(dataset.clone()),
+                )),
+                province: Arc::new(AnyProvinceRepository::InMemory(
+                    InMemoryProvinceRepository::new(dataset.clone()),
+                )),
+                municipality: Arc::new(AnyMunicipalityRepository::InMemory(
+                    InMemoryMunicipalityRepository::new(dataset.clone()),
+                )),
+                district: Arc::new(AnyDistrictRepository::InMemory(
+                    InMemoryDistrictRepository::new(dataset.clone()),
+                )),
+                city: Arc::new(AnyCityRepository::InMemory(InMemoryCityRepository::new(
+                    dataset.clone(),
+                ))),
+                barangay: Arc::new(AnyBarangayRepository::InMemory(
+                    InMemoryBarangayRepository::new(dataset.clone()),
+                )),
+            },
+        }
+    }
+
+    /// Connection pool usage, for the SQL backends.
+    pub async fn pool_stats(&self) -> Option<PoolStats> {
+        let db = match &self.store {
+            Store::Sql(db) | Store::Cached(db, _) => db,
+            Store::InMemory(_) => return None,
+        };
+
+        match pool_stats(db).await {
+            Ok(stats) => Some(stats),
+            Err(e) => {
+                warn!("failed to read pool stats: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Cache lookups, when the backend is cached and Redis is reachable.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        match &self.store {
+            Store::Cached(_, cache) if cache.is_enabled() => Some(cache.stats()),
+            _ => None,
+        }
+    }
+}
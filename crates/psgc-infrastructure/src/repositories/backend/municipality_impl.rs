@@ -0,0 +1,99 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        municipality::Municipality, stats::GroupCount,
+    },
+    repositories::municipality_repository::MunicipalityRepository,
+};
+
+use crate::repositories::{
+    cached::municipality_impl::CachedMunicipalityRepository,
+    memory::municipality_impl::InMemoryMunicipalityRepository,
+    municipality_impl::PgMunicipalityRepository,
+};
+
+pub enum AnyMunicipalityRepository {
+    Sql(PgMunicipalityRepository),
+    Cached(CachedMunicipalityRepository<PgMunicipalityRepository>),
+    InMemory(InMemoryMunicipalityRepository),
+}
+
+impl MunicipalityRepository for AnyMunicipalityRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Municipality, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Municipality, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_region_code(region_code))
+    }
+
+    async fn list_by_province_code(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_province_code(province_code))
+    }
+
+    async fn list_by_district_code(
+        &self,
+        district_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_district_code(district_code))
+    }
+
+    async fn list_barangays(
+        &self,
+        municipality_code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        dispatch!(self, repository => repository.list_barangays(municipality_code))
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        dispatch!(self, repository => repository.count_by_income_class())
+    }
+
+    async fn create(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(municipality, audit))
+    }
+
+    async fn update(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(municipality, audit))
+    }
+}
@@ -0,0 +1,93 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult,
+        audit::AuditEntry,
+        city::City,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        stats::{GroupCount, ProvinceStats},
+    },
+    repositories::province_repository::ProvinceRepository,
+};
+
+use crate::repositories::{
+    cached::province_impl::CachedProvinceRepository,
+    memory::province_impl::InMemoryProvinceRepository, province_impl::PgProvinceRepository,
+};
+
+pub enum AnyProvinceRepository {
+    Sql(PgProvinceRepository),
+    Cached(CachedProvinceRepository<PgProvinceRepository>),
+    InMemory(InMemoryProvinceRepository),
+}
+
+impl ProvinceRepository for AnyProvinceRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Province, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Province, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Province>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Province>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_region_code(region_code))
+    }
+
+    async fn list_cities(&self, province_code: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_cities(province_code))
+    }
+
+    async fn list_municipalities(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_municipalities(province_code))
+    }
+
+    async fn list_stats(&self) -> Result<Vec<ProvinceStats>, RepositoryError> {
+        dispatch!(self, repository => repository.list_stats())
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<ProvinceStats, RepositoryError> {
+        dispatch!(self, repository => repository.get_stats(code))
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        dispatch!(self, repository => repository.count_by_income_class())
+    }
+
+    async fn create(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(province, audit))
+    }
+
+    async fn update(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(province, audit))
+    }
+}
@@ -0,0 +1,82 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, island_group::IslandGroup,
+        municipality::Municipality, province::Province, region::Region, stats::RegionStats,
+    },
+    repositories::region_repository::RegionRepository,
+};
+
+use crate::repositories::{
+    cached::region_impl::CachedRegionRepository, memory::region_impl::InMemoryRegionRepository,
+    region_impl::PgRegionRepository,
+};
+
+pub enum AnyRegionRepository {
+    Sql(PgRegionRepository),
+    Cached(CachedRegionRepository<PgRegionRepository>),
+    InMemory(InMemoryRegionRepository),
+}
+
+impl RegionRepository for AnyRegionRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Region, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code(code))
+    }
+
+    async fn find_by_code_or_alias(&self, code: &str) -> Result<Region, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_code_or_alias(code))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Region, RepositoryError> {
+        dispatch!(self, repository => repository.find_by_slug(slug))
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Region>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        dispatch!(self, repository => repository.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        dispatch!(self, repository => repository.list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_provinces(&self, code: &str) -> Result<Vec<Province>, RepositoryError> {
+        dispatch!(self, repository => repository.list_provinces(code))
+    }
+
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        dispatch!(self, repository => repository.list_cities(code))
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        dispatch!(self, repository => repository.list_municipalities(code))
+    }
+
+    async fn list_stats(&self) -> Result<Vec<RegionStats>, RepositoryError> {
+        dispatch!(self, repository => repository.list_stats())
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<RegionStats, RepositoryError> {
+        dispatch!(self, repository => repository.get_stats(code))
+    }
+
+    async fn create(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.create(region, audit))
+    }
+
+    async fn update(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        dispatch!(self, repository => repository.update(region, audit))
+    }
+}
@@ -0,0 +1,34 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{release::Release, release_diff::ReleaseUnit},
+    repositories::release_repository::ReleaseRepository,
+};
+
+use crate::repositories::{
+    cached::release_impl::CachedReleaseRepository, memory::release_impl::InMemoryReleaseRepository,
+    release_impl::PgReleaseRepository,
+};
+
+pub enum AnyReleaseRepository {
+    Sql(PgReleaseRepository),
+    Cached(CachedReleaseRepository<PgReleaseRepository>),
+    InMemory(InMemoryReleaseRepository),
+}
+
+impl ReleaseRepository for AnyReleaseRepository {
+    async fn list(&self) -> Result<Vec<Release>, RepositoryError> {
+        dispatch!(self, repository => repository.list())
+    }
+
+    async fn find(&self, id: &str) -> Result<Release, RepositoryError> {
+        dispatch!(self, repository => repository.find(id))
+    }
+
+    async fn latest(&self) -> Result<Release, RepositoryError> {
+        dispatch!(self, repository => repository.latest())
+    }
+
+    async fn units(&self, id: &str) -> Result<Vec<ReleaseUnit>, RepositoryError> {
+        dispatch!(self, repository => repository.units(id))
+    }
+}
@@ -1,82 +1,266 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        stats::GroupCount,
+    },
     repositories::barangay_repository::BarangayRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
 
-use crate::database::{generators::PageExt, models};
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
+/// Reads and writes the rows of one release.
 pub struct PgBarangayRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgBarangayRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
     }
 }
 
 #[allow(unused)]
 impl BarangayRepository for PgBarangayRepository {
+    #[instrument(name = "PgBarangayRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<Barangay, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let barangay = models::barangay::Barangay::select_by_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangay = models::barangay::Barangay::select_by_code(&executor, &self.release, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        with_former_names(&self.db, existed(barangay.into(), self.as_of)?).await
+    }
+
+    #[instrument(name = "PgBarangayRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<Barangay, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangay = models::barangay::Barangay::select_by_slug(&executor, &self.release, slug)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
             .ok_or(RepositoryError::NotFound)?;
 
-        Ok(barangay.into())
+        with_former_names(&self.db, existed(barangay.into(), self.as_of)?).await
     }
 
+    #[instrument(name = "PgBarangayRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::barangay::Barangay::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(records.into_iter().map(|r| r.into()).collect(), self.as_of),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgBarangayRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let barangays = models::barangay::Barangay::list_barangays(
-            &mut executor,
+            &executor,
+            &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(&self.db, barangays.into_domain::<Barangay>()).await
+    }
+
+    #[instrument(name = "PgBarangayRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::barangay::Barangay::list_by_island_group(
+            &executor,
             &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
         )
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(barangays.into_domain::<Barangay>())
+        with_former_names(&self.db, records.into_domain::<Barangay>()).await
     }
 
+    #[instrument(name = "PgBarangayRepository::list_by_city_code", skip(self))]
     async fn list_by_city_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let barangays =
-            models::barangay::Barangay::list_barangays_by_city_code(&mut executor, code)
+            models::barangay::Barangay::list_barangays_by_city_code(&executor, &self.release, code)
                 .await
                 .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(barangays.into_iter().map(|b| b.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                barangays.into_iter().map(|b| b.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgBarangayRepository::list_by_municipality_code", skip(self))]
     async fn list_by_municipality_code(
         &self,
         code: &str,
     ) -> Result<Vec<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let barangays =
-            models::barangay::Barangay::list_barangays_by_municipality_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangays = models::barangay::Barangay::list_barangays_by_municipality_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(barangays.into_iter().map(|b| b.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                barangays.into_iter().map(|b| b.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgBarangayRepository::list_by_district_code", skip(self))]
     async fn list_by_district_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let barangays =
-            models::barangay::Barangay::list_barangays_by_district_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangays = models::barangay::Barangay::list_barangays_by_district_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(
+                barangays.into_iter().map(|b| b.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgBarangayRepository::count_by_urban_rural", skip(self))]
+    async fn count_by_urban_rural(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_group_counts(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            "barangays",
+            "urban_rural",
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
 
-        Ok(barangays.into_iter().map(|b| b.into()).collect())
+    #[instrument(name = "PgBarangayRepository::create", skip_all, fields(code = %barangay.code))]
+    async fn create(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = models::barangay::Barangay {
+            release_id: self.release.clone(),
+            ..barangay.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::barangay::Barangay::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgBarangayRepository::update", skip_all, fields(code = %barangay.code))]
+    async fn update(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row: models::barangay::Barangay = barangay.into();
+        audited(&self.db, audit, async |tx| {
+            let updated = models::barangay::update_barangay(tx, &row).await?;
+            if updated > 0 {
+                models::former_name::insert_new(
+                    tx,
+                    &self.release,
+                    "barangay",
+                    &row.id,
+                    &barangay.former_names,
+                )
+                .await?;
+            }
+            Ok(updated)
+        })
+        .await
     }
 }
@@ -0,0 +1,132 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        stats::GroupCount,
+    },
+    repositories::barangay_repository::BarangayRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`BarangayRepository`] reads in Redis in front of another implementation.
+pub struct CachedBarangayRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: BarangayRepository> CachedBarangayRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: BarangayRepository> BarangayRepository for CachedBarangayRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<Barangay, RepositoryError> {
+        self.cache
+            .entry(
+                format!("barangay:code:{}", code),
+                self.inner.find_by_code(code),
+            )
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Barangay, RepositoryError> {
+        self.cache
+            .entry(
+                format!("barangay:slug:{}", slug),
+                self.inner.find_by_slug(slug),
+            )
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("barangay:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("barangay:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("barangay:island-group:{}:{}:{}", island_group, page, limit),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_city_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("barangay:by-city-code:{}", code),
+                self.inner.list_by_city_code(code),
+            )
+            .await
+    }
+
+    async fn list_by_municipality_code(
+        &self,
+        code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("barangay:by-municipality-code:{}", code),
+                self.inner.list_by_municipality_code(code),
+            )
+            .await
+    }
+
+    async fn list_by_district_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("barangay:by-district-code:{}", code),
+                self.inner.list_by_district_code(code),
+            )
+            .await
+    }
+
+    async fn count_by_urban_rural(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        self.cache
+            .list(
+                "barangay:count-by-urban-rural".to_string(),
+                self.inner.count_by_urban_rural(),
+            )
+            .await
+    }
+
+    async fn create(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.create(barangay, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(&self, barangay: &Barangay, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.update(barangay, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
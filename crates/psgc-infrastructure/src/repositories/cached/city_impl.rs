@@ -0,0 +1,123 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, city::City,
+        island_group::IslandGroup, stats::GroupCount,
+    },
+    repositories::city_repository::CityRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`CityRepository`] reads in Redis in front of another implementation.
+pub struct CachedCityRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: CityRepository> CachedCityRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: CityRepository> CityRepository for CachedCityRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<City, RepositoryError> {
+        self.cache
+            .entry(format!("city:code:{}", code), self.inner.find_by_code(code))
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<City, RepositoryError> {
+        self.cache
+            .entry(format!("city:slug:{}", slug), self.inner.find_by_slug(slug))
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("city:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("city:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("city:island-group:{}:{}:{}", island_group, page, limit),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("city:by-region-code:{}", code),
+                self.inner.list_by_region_code(code),
+            )
+            .await
+    }
+
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("city:by-province-code:{}", code),
+                self.inner.list_by_province_code(code),
+            )
+            .await
+    }
+
+    async fn list_barangays(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("city:barangays:{}", code),
+                self.inner.list_barangays(code),
+            )
+            .await
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        self.cache
+            .list(
+                "city:count-by-income-class".to_string(),
+                self.inner.count_by_income_class(),
+            )
+            .await
+    }
+
+    async fn create(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.create(city, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.update(city, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, district::District,
+        island_group::IslandGroup, municipality::Municipality,
+    },
+    repositories::district_repository::DistrictRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`DistrictRepository`] reads in Redis in front of another implementation.
+pub struct CachedDistrictRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: DistrictRepository> CachedDistrictRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: DistrictRepository> DistrictRepository for CachedDistrictRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<District, RepositoryError> {
+        self.cache
+            .entry(
+                format!("district:code:{}", code),
+                self.inner.find_by_code(code),
+            )
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<District, RepositoryError> {
+        self.cache
+            .entry(
+                format!("district:slug:{}", slug),
+                self.inner.find_by_slug(slug),
+            )
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<District>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("district:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:island-group:{}:{}:{}", island_group, page, limit),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:by-region-code:{}", code),
+                self.inner.list_by_region_code(code),
+            )
+            .await
+    }
+
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:by-province-code:{}", code),
+                self.inner.list_by_province_code(code),
+            )
+            .await
+    }
+
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:cities:{}", code),
+                self.inner.list_cities(code),
+            )
+            .await
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("district:municipalities:{}", code),
+                self.inner.list_municipalities(code),
+            )
+            .await
+    }
+
+    async fn create(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.create(district, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.update(district, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
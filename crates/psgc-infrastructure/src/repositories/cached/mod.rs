@@ -0,0 +1,7 @@
+pub mod barangay_impl;
+pub mod city_impl;
+pub mod district_impl;
+pub mod municipality_impl;
+pub mod province_impl;
+pub mod region_impl;
+pub mod release_impl;
@@ -0,0 +1,161 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        municipality::Municipality, stats::GroupCount,
+    },
+    repositories::municipality_repository::MunicipalityRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`MunicipalityRepository`] reads in Redis in front of another implementation.
+pub struct CachedMunicipalityRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: MunicipalityRepository> CachedMunicipalityRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: MunicipalityRepository> MunicipalityRepository for CachedMunicipalityRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<Municipality, RepositoryError> {
+        self.cache
+            .entry(
+                format!("municipality:code:{}", code),
+                self.inner.find_by_code(code),
+            )
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Municipality, RepositoryError> {
+        self.cache
+            .entry(
+                format!("municipality:slug:{}", slug),
+                self.inner.find_by_slug(slug),
+            )
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("municipality:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("municipality:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!(
+                    "municipality:island-group:{}:{}:{}",
+                    island_group, page, limit
+                ),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("municipality:by-region-code:{}", region_code),
+                self.inner.list_by_region_code(region_code),
+            )
+            .await
+    }
+
+    async fn list_by_province_code(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("municipality:by-province-code:{}", province_code),
+                self.inner.list_by_province_code(province_code),
+            )
+            .await
+    }
+
+    async fn list_by_district_code(
+        &self,
+        district_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("municipality:by-district-code:{}", district_code),
+                self.inner.list_by_district_code(district_code),
+            )
+            .await
+    }
+
+    async fn list_barangays(
+        &self,
+        municipality_code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        self.cache
+            .list(
+                format!("municipality:barangays:{}", municipality_code),
+                self.inner.list_barangays(municipality_code),
+            )
+            .await
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        self.cache
+            .list(
+                "municipality:count-by-income-class".to_string(),
+                self.inner.count_by_income_class(),
+            )
+            .await
+    }
+
+    async fn create(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        self.inner.create(municipality, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        self.inner.update(municipality, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
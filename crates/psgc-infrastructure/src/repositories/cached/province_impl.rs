@@ -0,0 +1,155 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult,
+        audit::AuditEntry,
+        city::City,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        stats::{GroupCount, ProvinceStats},
+    },
+    repositories::province_repository::ProvinceRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`ProvinceRepository`] reads in Redis in front of another implementation.
+pub struct CachedProvinceRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: ProvinceRepository> CachedProvinceRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: ProvinceRepository> ProvinceRepository for CachedProvinceRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<Province, RepositoryError> {
+        self.cache
+            .entry(
+                format!("province:code:{}", code),
+                self.inner.find_by_code(code),
+            )
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Province, RepositoryError> {
+        self.cache
+            .entry(
+                format!("province:slug:{}", slug),
+                self.inner.find_by_slug(slug),
+            )
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Province>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("province:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:island-group:{}:{}:{}", island_group, page, limit),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Province>, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:by-region-code:{}", region_code),
+                self.inner.list_by_region_code(region_code),
+            )
+            .await
+    }
+
+    async fn list_cities(&self, province_code: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:cities:{}", province_code),
+                self.inner.list_cities(province_code),
+            )
+            .await
+    }
+
+    async fn list_municipalities(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:municipalities:{}", province_code),
+                self.inner.list_municipalities(province_code),
+            )
+            .await
+    }
+
+    async fn list_stats(&self) -> Result<Vec<ProvinceStats>, RepositoryError> {
+        self.cache
+            .list("province:list-stats".to_string(), self.inner.list_stats())
+            .await
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<ProvinceStats, RepositoryError> {
+        self.cache
+            .list(
+                format!("province:stats:{}", code),
+                self.inner.get_stats(code),
+            )
+            .await
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        self.cache
+            .list(
+                "province:count-by-income-class".to_string(),
+                self.inner.count_by_income_class(),
+            )
+            .await
+    }
+
+    async fn create(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.create(province, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.update(province, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, island_group::IslandGroup,
+        municipality::Municipality, province::Province, region::Region, stats::RegionStats,
+    },
+    repositories::region_repository::RegionRepository,
+};
+use psgc_shared::text::fold_name;
+
+use crate::cache::RedisCache;
+
+/// Caches [`RegionRepository`] reads in Redis in front of another implementation.
+pub struct CachedRegionRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: RegionRepository> CachedRegionRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: RegionRepository> RegionRepository for CachedRegionRepository<R> {
+    async fn find_by_code(&self, code: &str) -> Result<Region, RepositoryError> {
+        self.cache
+            .entry(
+                format!("region:code:{}", code),
+                self.inner.find_by_code(code),
+            )
+            .await
+    }
+
+    async fn find_by_code_or_alias(&self, code: &str) -> Result<Region, RepositoryError> {
+        self.cache
+            .entry(
+                format!("region:code-or-alias:{}", code),
+                self.inner.find_by_code_or_alias(code),
+            )
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Region, RepositoryError> {
+        self.cache
+            .entry(
+                format!("region:slug:{}", slug),
+                self.inner.find_by_slug(slug),
+            )
+            .await
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Region>, RepositoryError> {
+        self.cache
+            .entry(
+                format!("region:name:{}", fold_name(name)),
+                self.inner.list_by_name(name),
+            )
+            .await
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        self.cache
+            .list(
+                format!("region:all:{}:{}", page, limit),
+                self.inner.list_all(page, limit),
+            )
+            .await
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        self.cache
+            .list(
+                format!("region:island-group:{}:{}:{}", island_group, page, limit),
+                self.inner.list_by_island_group(island_group, page, limit),
+            )
+            .await
+    }
+
+    async fn list_provinces(&self, code: &str) -> Result<Vec<Province>, RepositoryError> {
+        self.cache
+            .list(
+                format!("region:provinces:{}", code),
+                self.inner.list_provinces(code),
+            )
+            .await
+    }
+
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        self.cache
+            .list(
+                format!("region:cities:{}", code),
+                self.inner.list_cities(code),
+            )
+            .await
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        self.cache
+            .list(
+                format!("region:municipalities:{}", code),
+                self.inner.list_municipalities(code),
+            )
+            .await
+    }
+
+    async fn list_stats(&self) -> Result<Vec<RegionStats>, RepositoryError> {
+        self.cache
+            .list("region:list-stats".to_string(), self.inner.list_stats())
+            .await
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<RegionStats, RepositoryError> {
+        self.cache
+            .list(format!("region:stats:{}", code), self.inner.get_stats(code))
+            .await
+    }
+
+    async fn create(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.create(region, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+
+    async fn update(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        self.inner.update(region, audit).await?;
+        self.cache.invalidate_all().await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{release::Release, release_diff::ReleaseUnit},
+    repositories::release_repository::ReleaseRepository,
+};
+
+use crate::cache::RedisCache;
+
+/// Caches [`ReleaseRepository`] reads in Redis in front of another implementation.
+///
+/// Releases are only added by the seeder, which doesn't clear the cache, so a new release
+/// becomes the default once the cached list expires. Units for diffs aren't cached; a whole
+/// release is too large an entry and diffs are rare.
+pub struct CachedReleaseRepository<R> {
+    inner: R,
+    cache: RedisCache,
+}
+
+impl<R: ReleaseRepository> CachedReleaseRepository<R> {
+    pub fn new(inner: R, cache: RedisCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+impl<R: ReleaseRepository> ReleaseRepository for CachedReleaseRepository<R> {
+    async fn list(&self) -> Result<Vec<Release>, RepositoryError> {
+        self.cache
+            .list("release:all".to_string(), self.inner.list())
+            .await
+    }
+
+    async fn find(&self, id: &str) -> Result<Release, RepositoryError> {
+        self.cache
+            .list(format!("release:id:{}", id), self.inner.find(id))
+            .await
+    }
+
+    async fn latest(&self) -> Result<Release, RepositoryError> {
+        self.cache
+            .list("release:latest".to_string(), self.inner.latest())
+            .await
+    }
+
+    async fn units(&self, id: &str) -> Result<Vec<ReleaseUnit>, RepositoryError> {
+        self.inner.units(id).await
+    }
+}
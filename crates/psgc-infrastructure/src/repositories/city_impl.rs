@@ -1,74 +1,253 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay, city::City},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, city::City,
+        island_group::IslandGroup, stats::GroupCount,
+    },
     repositories::city_repository::CityRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
 
-use crate::database::{generators::PageExt, models};
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
+/// Reads and writes the rows of one release.
 pub struct PgCityRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgCityRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
     }
 }
 
 #[allow(unused)]
 impl CityRepository for PgCityRepository {
+    #[instrument(name = "PgCityRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<City, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let city = models::city::City::select_by_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let city = models::city::City::select_by_code(&executor, &self.release, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        with_former_names(&self.db, existed(city.into(), self.as_of)?).await
+    }
+
+    #[instrument(name = "PgCityRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<City, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let city = models::city::City::select_by_slug(&executor, &self.release, slug)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
             .ok_or(RepositoryError::NotFound)?;
 
-        Ok(city.into())
+        with_former_names(&self.db, existed(city.into(), self.as_of)?).await
     }
 
+    #[instrument(name = "PgCityRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<City>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::city::City::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(records.into_iter().map(|r| r.into()).collect(), self.as_of),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgCityRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities(&mut executor, &PageRequest::new(page, limit))
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities = models::city::City::list_cities(
+            &executor,
+            &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(&self.db, cities.into_domain::<City>()).await
+    }
+
+    #[instrument(name = "PgCityRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::city::City::list_by_island_group(
+            &executor,
+            &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_domain::<City>())
+        with_former_names(&self.db, records.into_domain::<City>()).await
     }
 
+    #[instrument(name = "PgCityRepository::list_by_region_code", skip(self))]
     async fn list_by_region_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities_by_region_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities = models::city::City::list_cities_by_region_code(&executor, &self.release, code)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_iter().map(|c| c.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(cities.into_iter().map(|c| c.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgCityRepository::list_by_province_code", skip(self))]
     async fn list_by_province_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities_by_province_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities =
+            models::city::City::list_cities_by_province_code(&executor, &self.release, code)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_iter().map(|c| c.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(cities.into_iter().map(|c| c.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgCityRepository::list_barangays", skip(self))]
     async fn list_barangays(&self, codename: &str) -> Result<Vec<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let barangays =
-            models::barangay::Barangay::list_barangays_by_city_code(&mut executor, &codename)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangays = models::barangay::Barangay::list_barangays_by_city_code(
+            &executor,
+            &self.release,
+            codename,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(
+                barangays.into_iter().map(|b| b.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgCityRepository::count_by_income_class", skip(self))]
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_group_counts(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            "cities",
+            "income_class",
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgCityRepository::create", skip_all, fields(code = %city.code))]
+    async fn create(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = models::city::City {
+            release_id: self.release.clone(),
+            ..city.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::city::City::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
 
-        Ok(barangays.into_iter().map(|b| b.into()).collect())
+    #[instrument(name = "PgCityRepository::update", skip_all, fields(code = %city.code))]
+    async fn update(&self, city: &City, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row: models::city::City = city.into();
+        audited(&self.db, audit, async |tx| {
+            let updated = models::city::update_city(tx, &row).await?;
+            if updated > 0 {
+                models::former_name::insert_new(
+                    tx,
+                    &self.release,
+                    "city",
+                    &row.id,
+                    &city.former_names,
+                )
+                .await?;
+            }
+            Ok(updated)
+        })
+        .await
     }
 }
@@ -0,0 +1,154 @@
+use std::{collections::HashMap, sync::Arc};
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{audit::AuditEntry, crosswalk::CrosswalkEntry, release_diff::ReleaseUnit},
+    repositories::crosswalk_repository::CrosswalkRepository,
+};
+use rbatis::RBatis;
+use tracing::instrument;
+
+use crate::{
+    database::{generators::UuidExt, models},
+    repositories::audit_impl::audited,
+};
+
+/// Like lineage, the crosswalk lives in the database for both SQL drivers and spans every
+/// release.
+pub struct PgCrosswalkRepository {
+    db: Arc<RBatis>,
+}
+
+impl PgCrosswalkRepository {
+    pub fn new(db: Arc<RBatis>) -> Self {
+        Self { db }
+    }
+}
+
+impl CrosswalkRepository for PgCrosswalkRepository {
+    #[instrument(name = "PgCrosswalkRepository::find_by_id", skip(self))]
+    async fn find_by_id(&self, id: uuid::Uuid) -> Result<CrosswalkEntry, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::crosswalk::Crosswalk::select_by_map(
+            &executor,
+            rbs::value! {"id": id.into_db()},
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)?
+            .try_into()
+    }
+
+    #[instrument(name = "PgCrosswalkRepository::list_by_historical_codes", skip(self))]
+    async fn list_by_historical_codes(
+        &self,
+        codes: &[String],
+    ) -> Result<Vec<CrosswalkEntry>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut entries = Vec::new();
+        // Keeps each query under the bound parameter limit of the database
+        for chunk in codes.chunks(500) {
+            let rows = models::crosswalk::select_by_historical_codes(&executor, chunk)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+            for row in rows {
+                entries.push(row.try_into()?);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    #[instrument(name = "PgCrosswalkRepository::find_current_units", skip(self, codes))]
+    async fn find_current_units(
+        &self,
+        release: &str,
+        codes: &[String],
+    ) -> Result<HashMap<String, ReleaseUnit>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut units = HashMap::new();
+        // Each code is bound twice per level
+        for chunk in codes.chunks(50) {
+            let rows = models::release::select_release_units_by_codes(&executor, release, chunk)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+            for row in rows {
+                let matched = [Some(&row.code), row.alternate_code.as_ref()]
+                    .into_iter()
+                    .flatten()
+                    .filter(|code| chunk.contains(code))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let unit = ReleaseUnit::from(row);
+                for code in matched {
+                    units.insert(code, unit.clone());
+                }
+            }
+        }
+
+        Ok(units)
+    }
+
+    #[instrument(name = "PgCrosswalkRepository::create", skip_all, fields(historical = %entry.historical_code, current = %entry.current_code))]
+    async fn create(
+        &self,
+        entry: &CrosswalkEntry,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let existing = models::crosswalk::Crosswalk::select_by_map(
+            &executor,
+            rbs::value! {
+                "historical_code": &entry.historical_code,
+                "current_code": &entry.current_code,
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !existing.is_empty() {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "{} is already mapped to {}",
+                entry.historical_code, entry.current_code
+            )));
+        }
+
+        let row = models::crosswalk::Crosswalk::from(entry);
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::crosswalk::Crosswalk::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgCrosswalkRepository::delete", skip(self, audit))]
+    async fn delete(&self, id: uuid::Uuid, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        audited(&self.db, audit, async |tx| {
+            let deleted =
+                models::crosswalk::Crosswalk::delete_by_map(tx, rbs::value! {"id": id.into_db()})
+                    .await?;
+            Ok(deleted.rows_affected)
+        })
+        .await
+    }
+}
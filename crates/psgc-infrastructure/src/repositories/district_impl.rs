@@ -1,45 +1,108 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
-    models::{PaginateResult, city::City, district::District, municipality::Municipality},
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, district::District,
+        island_group::IslandGroup, municipality::Municipality,
+    },
     repositories::district_repository::DistrictRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
+
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
-use crate::database::{generators::PageExt, models};
-
+/// Reads and writes the rows of one release.
 pub struct PgDistrictRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgDistrictRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
     }
 }
 
 #[allow(unused)]
 impl DistrictRepository for PgDistrictRepository {
+    #[instrument(name = "PgDistrictRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<District, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let district = models::district::District::select_by_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let district = models::district::District::select_by_code(&executor, &self.release, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        existed(district.into(), self.as_of)
+    }
+
+    #[instrument(name = "PgDistrictRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<District, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let district = models::district::District::select_by_slug(&executor, &self.release, slug)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
             .ok_or(RepositoryError::NotFound)?;
 
-        Ok(district.into())
+        existed(district.into(), self.as_of)
+    }
+
+    #[instrument(name = "PgDistrictRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<District>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::district::District::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(existing(
+            records.into_iter().map(|r| r.into()).collect(),
+            self.as_of,
+        ))
     }
 
+    #[instrument(name = "PgDistrictRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<District>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let districts = models::district::District::list_districts(
-            &mut executor,
+            &executor,
             &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
         )
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
@@ -47,45 +110,138 @@ impl DistrictRepository for PgDistrictRepository {
         Ok(districts.into_domain::<District>())
     }
 
+    #[instrument(name = "PgDistrictRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::district::District::list_by_island_group(
+            &executor,
+            &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(records.into_domain::<District>())
+    }
+
+    #[instrument(name = "PgDistrictRepository::list_by_region_code", skip(self))]
     async fn list_by_region_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let districts =
-            models::district::District::list_districts_by_region_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let districts = models::district::District::list_districts_by_region_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(districts.into_iter().map(|d| d.into()).collect())
+        Ok(existing(
+            districts.into_iter().map(|d| d.into()).collect(),
+            self.as_of,
+        ))
     }
 
+    #[instrument(name = "PgDistrictRepository::list_by_province_code", skip(self))]
     async fn list_by_province_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let districts =
-            models::district::District::list_districts_by_province_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let districts = models::district::District::list_districts_by_province_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(districts.into_iter().map(|d| d.into()).collect())
+        Ok(existing(
+            districts.into_iter().map(|d| d.into()).collect(),
+            self.as_of,
+        ))
     }
 
+    #[instrument(name = "PgDistrictRepository::list_cities", skip(self))]
     async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities_by_district_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities =
+            models::city::City::list_cities_by_district_code(&executor, &self.release, code)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_iter().map(|c| c.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(cities.into_iter().map(|c| c.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgDistrictRepository::list_municipalities", skip(self))]
     async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_district_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgDistrictRepository::create", skip_all, fields(code = %district.code))]
+    async fn create(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = models::district::District {
+            release_id: self.release.clone(),
+            ..district.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::district::District::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgDistrictRepository::update", skip_all, fields(code = %district.code))]
+    async fn update(&self, district: &District, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = district.into();
+        audited(&self.db, audit, async |tx| {
+            models::district::update_district(tx, &row).await
+        })
+        .await
     }
 }
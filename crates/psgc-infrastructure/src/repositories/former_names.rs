@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, barangay::Barangay, city::City, municipality::Municipality,
+        province::Province,
+    },
+};
+use rbatis::RBatis;
+
+use crate::database::{
+    generators::{RBatisUuidExt, UuidExt},
+    models,
+};
+
+/// Units that carry the names they had before.
+pub(crate) trait FormerNames {
+    fn units(&mut self) -> Vec<(uuid::Uuid, &mut Vec<String>)>;
+}
+
+macro_rules! former_names {
+    ($($unit:ty),*) => {
+        $(
+            impl FormerNames for $unit {
+                fn units(&mut self) -> Vec<(uuid::Uuid, &mut Vec<String>)> {
+                    vec![(self.id, &mut self.former_names)]
+                }
+            }
+        )*
+    };
+}
+
+former_names!(Province, City, Municipality, Barangay);
+
+impl<T: FormerNames> FormerNames for Vec<T> {
+    fn units(&mut self) -> Vec<(uuid::Uuid, &mut Vec<String>)> {
+        self.iter_mut().flat_map(FormerNames::units).collect()
+    }
+}
+
+impl<T: FormerNames> FormerNames for PaginateResult<T> {
+    fn units(&mut self) -> Vec<(uuid::Uuid, &mut Vec<String>)> {
+        self.records.units()
+    }
+}
+
+/// Fills in the former names of `units` with one query.
+pub(crate) async fn with_former_names<T: FormerNames>(
+    db: &RBatis,
+    mut units: T,
+) -> Result<T, RepositoryError> {
+    let mut slots: HashMap<uuid::Uuid, &mut Vec<String>> = units.units().into_iter().collect();
+    if slots.is_empty() {
+        return Ok(units);
+    }
+
+    let ids: Vec<_> = slots.keys().map(|id| id.into_db()).collect();
+    let executor = db
+        .acquire()
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+    // Keeps each query under the bound parameter limit of the database
+    for chunk in ids.chunks(500) {
+        let rows = models::former_name::select_by_unit_ids(&executor, chunk)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        for row in rows {
+            if let Some(names) = slots.get_mut(&row.unit_id.inner()) {
+                names.push(row.name);
+            }
+        }
+    }
+    drop(slots);
+
+    Ok(units)
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{audit::AuditEntry, lineage::LineageEvent},
+    repositories::lineage_repository::LineageRepository,
+};
+use rbatis::RBatis;
+use tracing::instrument;
+
+use crate::{
+    database::{
+        generators::{NaiveDateExt, UuidExt},
+        models,
+    },
+    repositories::audit_impl::audited,
+};
+
+/// Lineage lives in the database for both SQL drivers and spans every release; the bundled
+/// data has none, so there's no in-memory variant.
+pub struct PgLineageRepository {
+    db: Arc<RBatis>,
+}
+
+impl PgLineageRepository {
+    pub fn new(db: Arc<RBatis>) -> Self {
+        Self { db }
+    }
+
+    async fn list_by(
+        &self,
+        column: &str,
+        codes: &[String],
+    ) -> Result<Vec<LineageEvent>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::lineage::select_by_codes(&executor, column, codes)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(LineageEvent::try_from).collect()
+    }
+}
+
+impl LineageRepository for PgLineageRepository {
+    #[instrument(name = "PgLineageRepository::find_by_id", skip(self))]
+    async fn find_by_id(&self, id: uuid::Uuid) -> Result<LineageEvent, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows =
+            models::lineage::Lineage::select_by_map(&executor, rbs::value! {"id": id.into_db()})
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)?
+            .try_into()
+    }
+
+    #[instrument(name = "PgLineageRepository::list_by_predecessors", skip(self))]
+    async fn list_by_predecessors(
+        &self,
+        codes: &[String],
+    ) -> Result<Vec<LineageEvent>, RepositoryError> {
+        self.list_by("predecessor_code", codes).await
+    }
+
+    #[instrument(name = "PgLineageRepository::list_by_successors", skip(self))]
+    async fn list_by_successors(
+        &self,
+        codes: &[String],
+    ) -> Result<Vec<LineageEvent>, RepositoryError> {
+        self.list_by("successor_code", codes).await
+    }
+
+    #[instrument(name = "PgLineageRepository::create", skip_all, fields(predecessor = %event.predecessor_code, successor = %event.successor_code))]
+    async fn create(
+        &self,
+        event: &LineageEvent,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let existing = models::lineage::Lineage::select_by_map(
+            &executor,
+            rbs::value! {
+                "predecessor_code": &event.predecessor_code,
+                "successor_code": &event.successor_code,
+                "event_type": event.event_type.as_str(),
+                "effective_on": event.effective_on.into_db(),
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if !existing.is_empty() {
+            return Err(RepositoryError::ConstraintViolation(format!(
+                "the {} of {} into {} on {} is already recorded",
+                event.event_type.as_str(),
+                event.predecessor_code,
+                event.successor_code,
+                event.effective_on
+            )));
+        }
+
+        let row = models::lineage::Lineage::from(event);
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::lineage::Lineage::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgLineageRepository::delete", skip(self, audit))]
+    async fn delete(&self, id: uuid::Uuid, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        audited(&self.db, audit, async |tx| {
+            let deleted =
+                models::lineage::Lineage::delete_by_map(tx, rbs::value! {"id": id.into_db()})
+                    .await?;
+            Ok(deleted.rows_affected)
+        })
+        .await
+    }
+}
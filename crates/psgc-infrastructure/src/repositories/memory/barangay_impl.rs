@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        stats::GroupCount,
+    },
+    repositories::barangay_repository::BarangayRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryBarangayRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryBarangayRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl BarangayRepository for InMemoryBarangayRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Barangay, RepositoryError> {
+        self.dataset.barangays.find_by_code(code)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Barangay, RepositoryError> {
+        self.dataset.barangays.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self.dataset.barangays.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        Ok(self.dataset.barangays.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_city_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .children_of(&self.dataset.cities, code))
+    }
+
+    async fn list_by_municipality_code(
+        &self,
+        code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .children_of(&self.dataset.municipalities, code))
+    }
+
+    async fn list_by_district_code(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .children_of(&self.dataset.districts, code))
+    }
+
+    async fn count_by_urban_rural(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        Ok(self.dataset.barangays.count_by(|b| &b.urban_rural))
+    }
+
+    async fn create(
+        &self,
+        _barangay: &Barangay,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(
+        &self,
+        _barangay: &Barangay,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, city::City,
+        island_group::IslandGroup, stats::GroupCount,
+    },
+    repositories::city_repository::CityRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryCityRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryCityRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl CityRepository for InMemoryCityRepository {
+    async fn find_by_code(&self, code: &str) -> Result<City, RepositoryError> {
+        self.dataset.cities.find_by_code(code)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<City, RepositoryError> {
+        self.dataset.cities.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<City>, RepositoryError> {
+        Ok(self.dataset.cities.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        Ok(self.dataset.cities.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<City>, RepositoryError> {
+        Ok(self
+            .dataset
+            .cities
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        Ok(self.dataset.cities.children_of(&self.dataset.regions, code))
+    }
+
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        Ok(self
+            .dataset
+            .cities
+            .children_of(&self.dataset.provinces, code))
+    }
+
+    async fn list_barangays(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .children_of(&self.dataset.cities, code))
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        Ok(self.dataset.cities.count_by(|c| &c.income_class))
+    }
+
+    async fn create(&self, _city: &City, _audit: &AuditEntry) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(&self, _city: &City, _audit: &AuditEntry) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
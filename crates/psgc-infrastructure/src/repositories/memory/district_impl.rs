@@ -0,0 +1,119 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, district::District,
+        island_group::IslandGroup, municipality::Municipality,
+    },
+    repositories::district_repository::DistrictRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryDistrictRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryDistrictRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl DistrictRepository for InMemoryDistrictRepository {
+    async fn find_by_code(&self, code: &str) -> Result<District, RepositoryError> {
+        self.dataset.districts.find_by_code(code)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<District, RepositoryError> {
+        self.dataset.districts.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<District>, RepositoryError> {
+        Ok(self.dataset.districts.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        Ok(self.dataset.districts.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<District>, RepositoryError> {
+        Ok(self
+            .dataset
+            .districts
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        Ok(self
+            .dataset
+            .districts
+            .children_of(&self.dataset.regions, code))
+    }
+
+    /// Districts don't link to provinces directly, so they're found through the
+    /// province's municipalities.
+    async fn list_by_province_code(&self, code: &str) -> Result<Vec<District>, RepositoryError> {
+        let district_ids = self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.provinces, code)
+            .into_iter()
+            .filter_map(|m| m.district_id)
+            .collect::<BTreeSet<_>>();
+
+        Ok(district_ids
+            .iter()
+            .filter_map(|id| self.dataset.districts.get(id).cloned())
+            .collect())
+    }
+
+    /// Cities of every province that has a municipality in the district.
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        let province_ids = self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.districts, code)
+            .into_iter()
+            .filter_map(|m| m.province_id)
+            .collect::<BTreeSet<_>>();
+
+        Ok(province_ids
+            .iter()
+            .flat_map(|id| self.dataset.cities.children(id).cloned())
+            .collect())
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.districts, code))
+    }
+
+    async fn create(
+        &self,
+        _district: &District,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(
+        &self,
+        _district: &District,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
@@ -0,0 +1,559 @@
+pub mod barangay_impl;
+pub mod city_impl;
+pub mod district_impl;
+pub mod municipality_impl;
+pub mod province_impl;
+pub mod region_impl;
+pub mod release_impl;
+
+use std::collections::HashMap;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult,
+        barangay::Barangay,
+        city::City,
+        district::District,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        region::Region,
+        release::Release,
+        stats::{GroupCount, ProvinceStats, RegionStats},
+    },
+};
+use psgc_shared::text::fold_name;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::database::{DatabaseSeedError, generators::RBatisUuidExt, seed_data::SeedData};
+
+/// The whole PSGC dataset held in memory, built from the same seed data as the database.
+/// Only the bundled release is loaded.
+///
+/// Shared by the `InMemory*Repository` implementations; load it once and hand each
+/// repository a clone of the `Arc`.
+pub struct InMemoryDataset {
+    pub(crate) release: Release,
+    pub(crate) regions: Table<Region>,
+    pub(crate) provinces: Table<Province>,
+    pub(crate) districts: Table<District>,
+    pub(crate) cities: Table<City>,
+    pub(crate) municipalities: Table<Municipality>,
+    pub(crate) barangays: Table<Barangay>,
+}
+
+impl InMemoryDataset {
+    pub fn load() -> Result<Self, DatabaseSeedError> {
+        info!("Loading PSGC dataset into memory...");
+
+        let data = SeedData::bundled();
+        let rows = data.build()?;
+
+        let mut former_names: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for former_name in rows.former_names {
+            former_names
+                .entry(former_name.unit_id.inner())
+                .or_default()
+                .push(former_name.name);
+        }
+        let mut former_names_of =
+            |id: &rbatis::rbdc::Uuid| former_names.remove(&id.inner()).unwrap_or_default();
+
+        let dataset = Self {
+            release: Release::builder()
+                .id(data.release)
+                .loaded_at(chrono::Utc::now())
+                .build(),
+            regions: Table::new(rows.regions.into_iter().map(Region::from).collect()),
+            provinces: Table::new(
+                rows.provinces
+                    .into_iter()
+                    .map(|row| Province {
+                        former_names: former_names_of(&row.id),
+                        ..row.into()
+                    })
+                    .collect(),
+            ),
+            districts: Table::new(vec![]),
+            cities: Table::new(
+                rows.cities
+                    .into_iter()
+                    .map(|row| City {
+                        former_names: former_names_of(&row.id),
+                        ..row.into()
+                    })
+                    .collect(),
+            ),
+            municipalities: Table::new(
+                rows.municipalities
+                    .into_iter()
+                    .map(|row| Municipality {
+                        former_names: former_names_of(&row.id),
+                        ..row.into()
+                    })
+                    .collect(),
+            ),
+            barangays: Table::new(
+                rows.barangays
+                    .into_iter()
+                    .map(|row| Barangay {
+                        former_names: former_names_of(&row.id),
+                        ..row.into()
+                    })
+                    .collect(),
+            ),
+        };
+
+        info!(
+            "Loaded release {}: {} regions, {} provinces, {} cities, {} municipalities and {} barangays",
+            dataset.release.id,
+            dataset.regions.len(),
+            dataset.provinces.len(),
+            dataset.cities.len(),
+            dataset.municipalities.len(),
+            dataset.barangays.len()
+        );
+
+        Ok(dataset)
+    }
+
+    /// Barangays hang off either a city or a municipality.
+    fn barangays_under<'a>(
+        &'a self,
+        cities: impl Iterator<Item = &'a City>,
+        municipalities: impl Iterator<Item = &'a Municipality>,
+    ) -> Vec<&'a Barangay> {
+        let parent_ids = cities
+            .map(|c| c.id)
+            .chain(municipalities.map(|m| m.id))
+            .collect::<Vec<_>>();
+
+        parent_ids
+            .iter()
+            .flat_map(|id| self.barangays.children(id))
+            .collect()
+    }
+
+    pub(crate) fn region_stats(&self, region: &Region) -> RegionStats {
+        let cities = self.cities.children(&region.id).collect::<Vec<_>>();
+        let municipalities = self.municipalities.children(&region.id).collect::<Vec<_>>();
+        let barangays =
+            self.barangays_under(cities.iter().copied(), municipalities.iter().copied());
+
+        RegionStats::builder()
+            .code(region.code.clone())
+            .name(region.name.clone())
+            .population(region.population)
+            .province_count(self.provinces.children(&region.id).count() as u64)
+            .city_count(cities.len() as u64)
+            .municipality_count(municipalities.len() as u64)
+            .barangay_count(barangays.len() as u64)
+            .urban_barangay_count(count_urban_rural(&barangays, "U"))
+            .rural_barangay_count(count_urban_rural(&barangays, "R"))
+            .build()
+    }
+
+    pub(crate) fn province_stats(&self, province: &Province) -> ProvinceStats {
+        let cities = self.cities.children(&province.id).collect::<Vec<_>>();
+        let municipalities = self
+            .municipalities
+            .children(&province.id)
+            .collect::<Vec<_>>();
+        let barangays =
+            self.barangays_under(cities.iter().copied(), municipalities.iter().copied());
+
+        ProvinceStats::builder()
+            .code(province.code.clone())
+            .name(province.name.clone())
+            .population(province.population)
+            .city_count(cities.len() as u64)
+            .municipality_count(municipalities.len() as u64)
+            .barangay_count(barangays.len() as u64)
+            .urban_barangay_count(count_urban_rural(&barangays, "U"))
+            .rural_barangay_count(count_urban_rural(&barangays, "R"))
+            .build()
+    }
+}
+
+/// The dataset is rebuilt from the bundled seed data on every start, so it can't take
+/// corrections; those need one of the SQL backends.
+pub(crate) fn read_only() -> RepositoryError {
+    RepositoryError::UnexpectedError("the in-memory backend is read-only".to_string())
+}
+
+fn count_urban_rural(barangays: &[&Barangay], value: &str) -> u64 {
+    barangays.iter().filter(|b| b.urban_rural == value).count() as u64
+}
+
+/// Fields the in-memory indexes are keyed on.
+pub(crate) trait Record: Clone {
+    fn id(&self) -> Uuid;
+    fn code(&self) -> &str;
+    fn slug(&self) -> &str;
+    fn name(&self) -> &str;
+    /// Names the record had before, also matched by name
+    fn former_names(&self) -> &[String] {
+        &[]
+    }
+    /// Ids of every unit this record hangs off, at any level
+    fn parent_ids(&self) -> Vec<Uuid>;
+}
+
+/// Rows of one level sorted by code, with hash indexes on id, code, slug, folded current
+/// and former names, and parent ids. Sorting by code doubles as a prefix index over PSGC
+/// codes.
+pub(crate) struct Table<T> {
+    rows: Vec<T>,
+    by_id: HashMap<Uuid, usize>,
+    by_code: HashMap<String, usize>,
+    by_slug: HashMap<String, usize>,
+    by_name: HashMap<String, Vec<usize>>,
+    by_parent: HashMap<Uuid, Vec<usize>>,
+}
+
+impl<T: Record> Table<T> {
+    pub(crate) fn new(mut rows: Vec<T>) -> Self {
+        rows.sort_by(|a, b| a.code().cmp(b.code()));
+
+        let mut table = Self {
+            rows: vec![],
+            by_id: HashMap::with_capacity(rows.len()),
+            by_code: HashMap::with_capacity(rows.len()),
+            by_slug: HashMap::with_capacity(rows.len()),
+            by_name: HashMap::new(),
+            by_parent: HashMap::new(),
+        };
+
+        for (index, row) in rows.iter().enumerate() {
+            table.by_id.insert(row.id(), index);
+            table.by_code.insert(row.code().to_string(), index);
+            table.by_slug.insert(row.slug().to_string(), index);
+            let mut names = vec![fold_name(row.name())];
+            names.extend(row.former_names().iter().map(|name| fold_name(name)));
+            names.sort();
+            names.dedup();
+            for name in names {
+                table.by_name.entry(name).or_default().push(index);
+            }
+
+            for parent_id in row.parent_ids() {
+                table.by_parent.entry(parent_id).or_default().push(index);
+            }
+        }
+
+        table.rows = rows;
+        table
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.rows.iter()
+    }
+
+    pub(crate) fn get(&self, id: &Uuid) -> Option<&T> {
+        self.by_id.get(id).map(|&index| &self.rows[index])
+    }
+
+    pub(crate) fn get_by_code(&self, code: &str) -> Option<&T> {
+        self.by_code.get(code).map(|&index| &self.rows[index])
+    }
+
+    pub(crate) fn find_by_code(&self, code: &str) -> Result<T, RepositoryError> {
+        self.get_by_code(code)
+            .cloned()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn find_by_slug(&self, slug: &str) -> Result<T, RepositoryError> {
+        self.by_slug
+            .get(slug)
+            .map(|&index| self.rows[index].clone())
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    pub(crate) fn list_by_name(&self, name: &str) -> Vec<T> {
+        self.by_name
+            .get(&fold_name(name))
+            .map(|indexes| indexes.iter().map(|&i| self.rows[i].clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Rows whose PSGC code starts with `prefix`, in code order.
+    pub(crate) fn with_code_prefix(&self, prefix: &str) -> &[T] {
+        let start = self.rows.partition_point(|r| r.code() < prefix);
+        let len = self.rows[start..].partition_point(|r| r.code().starts_with(prefix));
+
+        &self.rows[start..start + len]
+    }
+
+    pub(crate) fn children(&self, parent_id: &Uuid) -> impl Iterator<Item = &T> {
+        self.by_parent
+            .get(parent_id)
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.rows[index])
+    }
+
+    /// Children of the parent with `code` in `parents`, empty when there's no such parent.
+    pub(crate) fn children_of<P: Record>(&self, parents: &Table<P>, code: &str) -> Vec<T> {
+        parents
+            .get_by_code(code)
+            .map(|parent| self.children(&parent.id()).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Island groups are derived from the two-digit region prefix every PSGC code starts with.
+    pub(crate) fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> PaginateResult<T> {
+        let rows = (1..=99)
+            .map(|prefix| format!("{:02}", prefix))
+            .filter(|prefix| IslandGroup::from_region_code(prefix) == Some(island_group))
+            .flat_map(|prefix| self.with_code_prefix(&prefix))
+            .collect::<Vec<_>>();
+
+        paginate(rows, page, limit)
+    }
+
+    pub(crate) fn list_all(&self, page: u64, limit: u64) -> PaginateResult<T> {
+        paginate(self.rows.iter().collect(), page, limit)
+    }
+
+    pub(crate) fn count_by(&self, group: impl Fn(&T) -> &str) -> Vec<GroupCount> {
+        let mut counts = self.rows.iter().fold(HashMap::new(), |mut counts, row| {
+            *counts.entry(group(row).to_string()).or_insert(0u64) += 1;
+            counts
+        });
+
+        let mut groups = counts.drain().collect::<Vec<_>>();
+        groups.sort();
+
+        groups
+            .into_iter()
+            .map(|(group, count)| GroupCount::builder().group(group).count(count).build())
+            .collect()
+    }
+}
+
+/// Mirrors rbatis paging: pages start at 1 and page 0 is treated as the first page.
+fn paginate<T: Clone>(rows: Vec<&T>, page: u64, limit: u64) -> PaginateResult<T> {
+    let page = page.max(1);
+    let total = rows.len() as u64;
+
+    let records = rows
+        .into_iter()
+        .skip(((page - 1) * limit) as usize)
+        .take(limit as usize)
+        .cloned()
+        .collect();
+
+    PaginateResult::builder()
+        .records(records)
+        .total(total)
+        .page_no(page)
+        .page_size(limit)
+        .build()
+}
+
+impl Record for Region {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        vec![]
+    }
+}
+
+impl Record for Province {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn former_names(&self) -> &[String] {
+        &self.former_names
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        vec![self.region_id]
+    }
+}
+
+impl Record for District {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        vec![self.region_id]
+    }
+}
+
+impl Record for City {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn former_names(&self) -> &[String] {
+        &self.former_names
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        self.region_id.into_iter().chain(self.province_id).collect()
+    }
+}
+
+impl Record for Municipality {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn former_names(&self) -> &[String] {
+        &self.former_names
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        self.region_id
+            .into_iter()
+            .chain(self.province_id)
+            .chain(self.district_id)
+            .collect()
+    }
+}
+
+impl Record for Barangay {
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn former_names(&self) -> &[String] {
+        &self.former_names
+    }
+
+    fn parent_ids(&self) -> Vec<Uuid> {
+        self.city_id
+            .into_iter()
+            .chain(self.municipality_id)
+            .chain(self.district_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_seed_data_with_linked_parents() {
+        let dataset = InMemoryDataset::load().unwrap();
+
+        assert!(dataset.regions.len() > 0);
+        assert!(
+            dataset
+                .provinces
+                .iter()
+                .all(|p| dataset.regions.get(&p.region_id).is_some())
+        );
+
+        let ncr = dataset.regions.find_by_code("1300000000").unwrap();
+        assert_eq!(
+            dataset.regions.find_by_slug(&ncr.slug).unwrap().code,
+            ncr.code
+        );
+        assert_eq!(dataset.regions.with_code_prefix("13").len(), 1);
+    }
+
+    #[test]
+    fn finds_units_by_former_name() {
+        let dataset = InMemoryDataset::load().unwrap();
+
+        let renamed = dataset.municipalities.list_by_name("nuling");
+        assert_eq!(renamed.len(), 1);
+        assert_eq!(renamed[0].name, "Sultan Kudarat");
+        assert_eq!(renamed[0].former_names, vec!["Nuling".to_string()]);
+        assert_eq!(
+            dataset.municipalities.list_by_name("Sultan Kudarat")[0].code,
+            renamed[0].code
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        municipality::Municipality, stats::GroupCount,
+    },
+    repositories::municipality_repository::MunicipalityRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryMunicipalityRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryMunicipalityRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl MunicipalityRepository for InMemoryMunicipalityRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Municipality, RepositoryError> {
+        self.dataset.municipalities.find_by_code(code)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Municipality, RepositoryError> {
+        self.dataset.municipalities.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self.dataset.municipalities.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        Ok(self.dataset.municipalities.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.regions, region_code))
+    }
+
+    async fn list_by_province_code(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.provinces, province_code))
+    }
+
+    async fn list_by_district_code(
+        &self,
+        district_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.districts, district_code))
+    }
+
+    async fn list_barangays(
+        &self,
+        municipality_code: &str,
+    ) -> Result<Vec<Barangay>, RepositoryError> {
+        Ok(self
+            .dataset
+            .barangays
+            .children_of(&self.dataset.municipalities, municipality_code))
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        Ok(self.dataset.municipalities.count_by(|m| &m.income_class))
+    }
+
+    async fn create(
+        &self,
+        _municipality: &Municipality,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(
+        &self,
+        _municipality: &Municipality,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
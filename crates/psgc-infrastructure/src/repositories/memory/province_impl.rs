@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult,
+        audit::AuditEntry,
+        city::City,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        stats::{GroupCount, ProvinceStats},
+    },
+    repositories::province_repository::ProvinceRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryProvinceRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryProvinceRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl ProvinceRepository for InMemoryProvinceRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Province, RepositoryError> {
+        self.dataset.provinces.find_by_code(code)
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Province, RepositoryError> {
+        self.dataset.provinces.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Province>, RepositoryError> {
+        Ok(self.dataset.provinces.list_by_name(name))
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        Ok(self.dataset.provinces.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        Ok(self
+            .dataset
+            .provinces
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_by_region_code(
+        &self,
+        region_code: &str,
+    ) -> Result<Vec<Province>, RepositoryError> {
+        Ok(self
+            .dataset
+            .provinces
+            .children_of(&self.dataset.regions, region_code))
+    }
+
+    async fn list_cities(&self, province_code: &str) -> Result<Vec<City>, RepositoryError> {
+        Ok(self
+            .dataset
+            .cities
+            .children_of(&self.dataset.provinces, province_code))
+    }
+
+    async fn list_municipalities(
+        &self,
+        province_code: &str,
+    ) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.provinces, province_code))
+    }
+
+    async fn list_stats(&self) -> Result<Vec<ProvinceStats>, RepositoryError> {
+        Ok(self
+            .dataset
+            .provinces
+            .iter()
+            .map(|p| self.dataset.province_stats(p))
+            .collect())
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<ProvinceStats, RepositoryError> {
+        self.dataset
+            .provinces
+            .get_by_code(code)
+            .map(|p| self.dataset.province_stats(p))
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        Ok(self.dataset.provinces.count_by(|p| &p.income_class))
+    }
+
+    async fn create(
+        &self,
+        _province: &Province,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(
+        &self,
+        _province: &Province,
+        _audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{
+        PaginateResult, audit::AuditEntry, city::City, island_group::IslandGroup,
+        municipality::Municipality, province::Province, region::Region, stats::RegionStats,
+    },
+    repositories::region_repository::RegionRepository,
+};
+
+use crate::repositories::memory::{InMemoryDataset, read_only};
+
+pub struct InMemoryRegionRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryRegionRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+
+    fn find_by_alias(&self, alias: &str) -> Option<Region> {
+        self.dataset
+            .regions
+            .iter()
+            .find(|r| r.is_known_as(alias))
+            .cloned()
+    }
+}
+
+impl RegionRepository for InMemoryRegionRepository {
+    async fn find_by_code(&self, code: &str) -> Result<Region, RepositoryError> {
+        self.dataset.regions.find_by_code(code)
+    }
+
+    async fn find_by_code_or_alias(&self, code: &str) -> Result<Region, RepositoryError> {
+        self.dataset
+            .regions
+            .find_by_code(code)
+            .or_else(|_| self.find_by_alias(code).ok_or(RepositoryError::NotFound))
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Region, RepositoryError> {
+        self.dataset.regions.find_by_slug(slug)
+    }
+
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Region>, RepositoryError> {
+        let regions = self.dataset.regions.list_by_name(name);
+
+        if regions.is_empty() {
+            return Ok(self.find_by_alias(name).into_iter().collect());
+        }
+
+        Ok(regions)
+    }
+
+    async fn list_all(
+        &self,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        Ok(self.dataset.regions.list_all(page, limit))
+    }
+
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        Ok(self
+            .dataset
+            .regions
+            .list_by_island_group(island_group, page, limit))
+    }
+
+    async fn list_provinces(&self, code: &str) -> Result<Vec<Province>, RepositoryError> {
+        Ok(self
+            .dataset
+            .provinces
+            .children_of(&self.dataset.regions, code))
+    }
+
+    async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
+        Ok(self.dataset.cities.children_of(&self.dataset.regions, code))
+    }
+
+    async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        Ok(self
+            .dataset
+            .municipalities
+            .children_of(&self.dataset.regions, code))
+    }
+
+    async fn list_stats(&self) -> Result<Vec<RegionStats>, RepositoryError> {
+        Ok(self
+            .dataset
+            .regions
+            .iter()
+            .map(|r| self.dataset.region_stats(r))
+            .collect())
+    }
+
+    async fn get_stats(&self, code: &str) -> Result<RegionStats, RepositoryError> {
+        self.dataset
+            .regions
+            .get_by_code(code)
+            .map(|r| self.dataset.region_stats(r))
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn create(&self, _region: &Region, _audit: &AuditEntry) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+
+    async fn update(&self, _region: &Region, _audit: &AuditEntry) -> Result<(), RepositoryError> {
+        Err(read_only())
+    }
+}
@@ -0,0 +1,132 @@
+use std::{collections::HashMap, sync::Arc};
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{release::Release, release_diff::ReleaseUnit},
+    repositories::release_repository::ReleaseRepository,
+};
+
+use uuid::Uuid;
+
+use crate::repositories::memory::InMemoryDataset;
+
+pub struct InMemoryReleaseRepository {
+    dataset: Arc<InMemoryDataset>,
+}
+
+impl InMemoryReleaseRepository {
+    pub fn new(dataset: Arc<InMemoryDataset>) -> Self {
+        Self { dataset }
+    }
+}
+
+impl ReleaseRepository for InMemoryReleaseRepository {
+    async fn list(&self) -> Result<Vec<Release>, RepositoryError> {
+        Ok(vec![self.dataset.release.clone()])
+    }
+
+    async fn find(&self, id: &str) -> Result<Release, RepositoryError> {
+        match self.dataset.release.id == id {
+            true => Ok(self.dataset.release.clone()),
+            false => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn latest(&self) -> Result<Release, RepositoryError> {
+        Ok(self.dataset.release.clone())
+    }
+
+    async fn units(&self, id: &str) -> Result<Vec<ReleaseUnit>, RepositoryError> {
+        self.find(id).await?;
+
+        let dataset = self.dataset.as_ref();
+        let mut codes: HashMap<Uuid, String> = HashMap::new();
+        let mut units = Vec::new();
+        let mut push = |level: &str,
+                        id: Uuid,
+                        (code, correspondence_code): (&str, &str),
+                        (name, former_names): (&str, &[String]),
+                        parents: &[Option<Uuid>],
+                        classification: &str| {
+            let code = psgc_code(code, correspondence_code);
+            codes.insert(id, code.clone());
+            let parent_code = parents
+                .iter()
+                .flatten()
+                .find_map(|parent| codes.get(parent).cloned());
+
+            units.push(
+                ReleaseUnit::builder()
+                    .level(level.to_string())
+                    .code(code)
+                    .name(name.to_string())
+                    .former_names(former_names.to_vec())
+                    .maybe_parent_code(parent_code)
+                    .classification(classification.to_string())
+                    .build(),
+            );
+        };
+
+        // Parents before children, so their codes are known
+        for r in dataset.regions.iter() {
+            push(
+                "region",
+                r.id,
+                (&r.code, &r.correspondence_code),
+                (&r.name, &[]),
+                &[],
+                "",
+            );
+        }
+        for p in dataset.provinces.iter() {
+            let parents = [Some(p.region_id)];
+            push(
+                "province",
+                p.id,
+                (&p.code, &p.correspondence_code),
+                (&p.name, &p.former_names),
+                &parents,
+                "",
+            );
+        }
+        for d in dataset.districts.iter() {
+            let parents = [Some(d.region_id)];
+            push(
+                "district",
+                d.id,
+                (&d.code, &d.correspondence_code),
+                (&d.name, &[]),
+                &parents,
+                "",
+            );
+        }
+        for c in dataset.cities.iter() {
+            let parents = [c.province_id, c.region_id];
+            let codes = (c.code.as_str(), c.correspondence_code.as_str());
+            let names = (c.name.as_str(), c.former_names.as_slice());
+            push("city", c.id, codes, names, &parents, &c.city_class);
+        }
+        for m in dataset.municipalities.iter() {
+            let parents = [m.province_id, m.district_id, m.region_id];
+            let codes = (m.code.as_str(), m.correspondence_code.as_str());
+            let names = (m.name.as_str(), m.former_names.as_slice());
+            push("municipality", m.id, codes, names, &parents, "");
+        }
+        for b in dataset.barangays.iter() {
+            let parents = [b.city_id, b.municipality_id];
+            let codes = (b.code.as_str(), b.correspondence_code.as_str());
+            let names = (b.name.as_str(), b.former_names.as_slice());
+            push("barangay", b.id, codes, names, &parents, &b.urban_rural);
+        }
+
+        Ok(units)
+    }
+}
+
+/// Levels differ in which of their two codes has the 10-digit form.
+fn psgc_code(code: &str, correspondence_code: &str) -> String {
+    match code.len() {
+        10 => code.to_string(),
+        _ => correspondence_code.to_string(),
+    }
+}
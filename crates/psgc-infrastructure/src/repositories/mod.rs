@@ -1,6 +1,18 @@
+pub mod api_key_impl;
+pub mod audit_impl;
+pub mod backend;
 pub mod barangay_impl;
+pub mod cached;
 pub mod city_impl;
+pub mod crosswalk_impl;
 pub mod district_impl;
+mod former_names;
+pub mod lineage_impl;
+pub mod memory;
 pub mod municipality_impl;
 pub mod province_impl;
 pub mod region_impl;
+pub mod release_impl;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+mod validity;
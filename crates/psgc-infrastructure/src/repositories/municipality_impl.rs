@@ -1,104 +1,310 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
-    models::{PaginateResult, barangay::Barangay, municipality::Municipality},
+    models::{
+        PaginateResult, audit::AuditEntry, barangay::Barangay, island_group::IslandGroup,
+        municipality::Municipality, stats::GroupCount,
+    },
     repositories::municipality_repository::MunicipalityRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
 
-use crate::database::{generators::PageExt, models};
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
+/// Reads and writes the rows of one release.
 pub struct PgMunicipalityRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgMunicipalityRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
     }
 }
 
 #[allow(unused)]
 impl MunicipalityRepository for PgMunicipalityRepository {
+    #[instrument(name = "PgMunicipalityRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<Municipality, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let municipality = models::municipality::Municipality::select_by_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
             .await
-            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
-            .ok_or(RepositoryError::NotFound)?;
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let municipality =
+            models::municipality::Municipality::select_by_code(&executor, &self.release, code)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+                .ok_or(RepositoryError::NotFound)?;
+
+        with_former_names(&self.db, existed(municipality.into(), self.as_of)?).await
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<Municipality, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let municipality =
+            models::municipality::Municipality::select_by_slug(&executor, &self.release, slug)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+                .ok_or(RepositoryError::NotFound)?;
 
-        Ok(municipality.into())
+        with_former_names(&self.db, existed(municipality.into(), self.as_of)?).await
     }
 
+    #[instrument(name = "PgMunicipalityRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Municipality>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::municipality::Municipality::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(records.into_iter().map(|r| r.into()).collect(), self.as_of),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities = models::municipality::Municipality::list_municipalities(
-            &mut executor,
+            &executor,
             &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
         )
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_domain::<Municipality>())
+        with_former_names(&self.db, municipalities.into_domain::<Municipality>()).await
     }
 
+    #[instrument(name = "PgMunicipalityRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Municipality>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::municipality::Municipality::list_by_island_group(
+            &executor,
+            &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(&self.db, records.into_domain::<Municipality>()).await
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::list_by_region_code", skip(self))]
     async fn list_by_region_code(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_region_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgMunicipalityRepository::list_by_province_code", skip(self))]
     async fn list_by_province_code(
         &self,
         code: &str,
     ) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_province_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgMunicipalityRepository::list_by_district_code", skip(self))]
     async fn list_by_district_code(
         &self,
         code: &str,
     ) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_district_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgMunicipalityRepository::list_barangays", skip(self))]
     async fn list_barangays(&self, code: &str) -> Result<Vec<Barangay>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let barangays =
-            models::barangay::Barangay::list_barangays_by_municipality_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let barangays = models::barangay::Barangay::list_barangays_by_municipality_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(barangays.into_iter().map(|b| b.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                barangays.into_iter().map(|b| b.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::count_by_income_class", skip(self))]
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_group_counts(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            "municipalities",
+            "income_class",
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::create", skip_all, fields(code = %municipality.code))]
+    async fn create(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        let row = models::municipality::Municipality {
+            release_id: self.release.clone(),
+            ..municipality.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::municipality::Municipality::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgMunicipalityRepository::update", skip_all, fields(code = %municipality.code))]
+    async fn update(
+        &self,
+        municipality: &Municipality,
+        audit: &AuditEntry,
+    ) -> Result<(), RepositoryError> {
+        let row: models::municipality::Municipality = municipality.into();
+        audited(&self.db, audit, async |tx| {
+            let updated = models::municipality::update_municipality(tx, &row).await?;
+            if updated > 0 {
+                models::former_name::insert_new(
+                    tx,
+                    &self.release,
+                    "municipality",
+                    &row.id,
+                    &municipality.former_names,
+                )
+                .await?;
+            }
+            Ok(updated)
+        })
+        .await
     }
 }
@@ -1,81 +1,305 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
-    models::{PaginateResult, city::City, municipality::Municipality, province::Province},
+    models::{
+        PaginateResult,
+        audit::AuditEntry,
+        city::City,
+        island_group::IslandGroup,
+        municipality::Municipality,
+        province::Province,
+        stats::{GroupCount, ProvinceStats},
+    },
     repositories::province_repository::ProvinceRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
 
-use crate::database::{generators::PageExt, models};
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
+/// Reads and writes the rows of one release.
 pub struct PgProvinceRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgProvinceRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
     }
 }
 
 #[allow(unused)]
 impl ProvinceRepository for PgProvinceRepository {
+    #[instrument(name = "PgProvinceRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<Province, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let province = models::province::Province::select_by_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let province = models::province::Province::select_by_code(&executor, &self.release, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        with_former_names(&self.db, existed(province.into(), self.as_of)?).await
+    }
+
+    #[instrument(name = "PgProvinceRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<Province, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let province = models::province::Province::select_by_slug(&executor, &self.release, slug)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
             .ok_or(RepositoryError::NotFound)?;
 
-        Ok(province.into())
+        with_former_names(&self.db, existed(province.into(), self.as_of)?).await
+    }
+
+    #[instrument(name = "PgProvinceRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Province>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::province::Province::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(
+            &self.db,
+            existing(records.into_iter().map(|r| r.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgProvinceRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<Province>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let provinces = models::province::Province::list_provinces(
-            &mut executor,
+            &executor,
+            &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        with_former_names(&self.db, provinces.into_domain::<Province>()).await
+    }
+
+    #[instrument(name = "PgProvinceRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Province>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::province::Province::list_by_island_group(
+            &executor,
             &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
         )
         .await
         .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(provinces.into_domain::<Province>())
+        with_former_names(&self.db, records.into_domain::<Province>()).await
     }
 
+    #[instrument(name = "PgProvinceRepository::list_by_region_code", skip(self))]
     async fn list_by_region_code(&self, code: &str) -> Result<Vec<Province>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let provinces =
-            models::province::Province::list_provinces_by_region_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let provinces = models::province::Province::list_provinces_by_region_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(provinces.into_iter().map(|p| p.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                provinces.into_iter().map(|p| p.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgProvinceRepository::list_cities", skip(self))]
     async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities_by_province_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities =
+            models::city::City::list_cities_by_province_code(&executor, &self.release, code)
+                .await
+                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_iter().map(|c| c.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(cities.into_iter().map(|c| c.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgProvinceRepository::list_municipalities", skip(self))]
     async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_province_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgProvinceRepository::list_stats", skip(self))]
+    async fn list_stats(&self) -> Result<Vec<ProvinceStats>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_province_stats(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            None,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgProvinceRepository::get_stats", skip(self))]
+    async fn get_stats(&self, code: &str) -> Result<ProvinceStats, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let row = models::stats::select_province_stats(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            Some(code),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(row.into())
+    }
+
+    #[instrument(name = "PgProvinceRepository::count_by_income_class", skip(self))]
+    async fn count_by_income_class(&self) -> Result<Vec<GroupCount>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_group_counts(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            "provinces",
+            "income_class",
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgProvinceRepository::create", skip_all, fields(code = %province.code))]
+    async fn create(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = models::province::Province {
+            release_id: self.release.clone(),
+            ..province.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::province::Province::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgProvinceRepository::update", skip_all, fields(code = %province.code))]
+    async fn update(&self, province: &Province, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row: models::province::Province = province.into();
+        audited(&self.db, audit, async |tx| {
+            let updated = models::province::update_province(tx, &row).await?;
+            if updated > 0 {
+                models::former_name::insert_new(
+                    tx,
+                    &self.release,
+                    "province",
+                    &row.id,
+                    &province.former_names,
+                )
+                .await?;
+            }
+            Ok(updated)
+        })
+        .await
     }
 }
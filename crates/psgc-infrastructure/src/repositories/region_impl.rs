@@ -1,81 +1,307 @@
 use std::sync::Arc;
 
+use chrono::NaiveDate;
 use psgc_domain::{
     errors::RepositoryError,
     models::{
-        PaginateResult, city::City, municipality::Municipality, province::Province, region::Region,
+        PaginateResult, audit::AuditEntry, city::City, island_group::IslandGroup,
+        municipality::Municipality, province::Province, region::Region, stats::RegionStats,
     },
     repositories::region_repository::RegionRepository,
 };
+use psgc_shared::text::fold_name;
 use rbatis::{PageRequest, RBatis};
+use tracing::instrument;
 
-use crate::database::{generators::PageExt, models};
+use crate::{
+    database::{generators::PageExt, models},
+    repositories::{
+        audit_impl::audited,
+        former_names::with_former_names,
+        validity::{existed, existing, list_scope},
+    },
+};
 
+/// Reads and writes the rows of one release.
 pub struct PgRegionRepository {
     db: Arc<RBatis>,
+    release: String,
+    as_of: Option<NaiveDate>,
 }
 
 impl PgRegionRepository {
-    pub fn new(db: Arc<RBatis>) -> Self {
-        Self { db }
+    pub fn new(db: Arc<RBatis>, release: String, as_of: Option<NaiveDate>) -> Self {
+        Self { db, release, as_of }
+    }
+
+    /// There are fewer than twenty regions, so aliases are matched in memory.
+    async fn find_by_alias(&self, alias: &str) -> Result<Option<Region>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let regions = models::region::Region::select_by_map(
+            &executor,
+            rbs::value! {"release_id": &self.release},
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(regions
+            .into_iter()
+            .map(Region::from)
+            .find(|r| r.is_known_as(alias)))
     }
 }
 
 #[allow(unused)]
 impl RegionRepository for PgRegionRepository {
+    #[instrument(name = "PgRegionRepository::find_by_code", skip(self))]
     async fn find_by_code(&self, code: &str) -> Result<Region, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let region = models::region::Region::select_by_codename(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let region = models::region::Region::select_by_codename(&executor, &self.release, code)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        existed(region.into(), self.as_of)
+    }
+
+    #[instrument(name = "PgRegionRepository::find_by_code_or_alias", skip(self))]
+    async fn find_by_code_or_alias(&self, code: &str) -> Result<Region, RepositoryError> {
+        match self.find_by_code(code).await {
+            Err(RepositoryError::NotFound) => self
+                .find_by_alias(code)
+                .await?
+                .ok_or(RepositoryError::NotFound)
+                .and_then(|region| existed(region, self.as_of)),
+            result => result,
+        }
+    }
+
+    #[instrument(name = "PgRegionRepository::find_by_slug", skip(self))]
+    async fn find_by_slug(&self, slug: &str) -> Result<Region, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let region = models::region::Region::select_by_slug(&executor, &self.release, slug)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
             .ok_or(RepositoryError::NotFound)?;
 
-        Ok(region.into())
+        existed(region.into(), self.as_of)
+    }
+
+    #[instrument(name = "PgRegionRepository::list_by_name", skip(self))]
+    async fn list_by_name(&self, name: &str) -> Result<Vec<Region>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::region::Region::select_by_normalized_name(
+            &executor,
+            &self.release,
+            &fold_name(name),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        if records.is_empty() {
+            return Ok(existing(
+                self.find_by_alias(name).await?.into_iter().collect(),
+                self.as_of,
+            ));
+        }
+
+        Ok(existing(
+            records.into_iter().map(|r| r.into()).collect(),
+            self.as_of,
+        ))
     }
 
+    #[instrument(name = "PgRegionRepository::list_all", skip(self))]
     async fn list_all(
         &self,
         page: u64,
         limit: u64,
     ) -> Result<PaginateResult<Region>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let regions =
-            models::region::Region::list_all(&mut executor, &PageRequest::new(page, limit))
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let regions = models::region::Region::list_all(
+            &executor,
+            &PageRequest::new(page, limit),
+            &list_scope(&self.release, self.as_of),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
         Ok(regions.into_domain::<Region>())
     }
 
+    #[instrument(name = "PgRegionRepository::list_by_island_group", skip(self))]
+    async fn list_by_island_group(
+        &self,
+        island_group: IslandGroup,
+        page: u64,
+        limit: u64,
+    ) -> Result<PaginateResult<Region>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let records = models::region::Region::list_by_island_group(
+            &executor,
+            &PageRequest::new(page, limit),
+            &models::ListScope {
+                island_group: Some(island_group.id().to_string()),
+                ..list_scope(&self.release, self.as_of)
+            },
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(records.into_domain::<Region>())
+    }
+
+    #[instrument(name = "PgRegionRepository::list_provinces", skip(self))]
     async fn list_provinces(&self, code: &str) -> Result<Vec<Province>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let provinces =
-            models::province::Province::list_provinces_by_region_code(&mut executor, code)
-                .await
-                .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let provinces = models::province::Province::list_provinces_by_region_code(
+            &executor,
+            &self.release,
+            code,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(provinces.into_iter().map(|p| p.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                provinces.into_iter().map(|p| p.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
     }
 
+    #[instrument(name = "PgRegionRepository::list_cities", skip(self))]
     async fn list_cities(&self, code: &str) -> Result<Vec<City>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
-        let cities = models::city::City::list_cities_by_region_code(&mut executor, code)
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let cities = models::city::City::list_cities_by_region_code(&executor, &self.release, code)
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(cities.into_iter().map(|c| c.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(cities.into_iter().map(|c| c.into()).collect(), self.as_of),
+        )
+        .await
     }
 
+    #[instrument(name = "PgRegionRepository::list_municipalities", skip(self))]
     async fn list_municipalities(&self, code: &str) -> Result<Vec<Municipality>, RepositoryError> {
-        let mut executor = self.db.acquire().await.unwrap();
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
         let municipalities =
             models::municipality::Municipality::list_municipalities_by_region_code(
-                &mut executor,
+                &executor,
+                &self.release,
                 code,
             )
             .await
             .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
 
-        Ok(municipalities.into_iter().map(|m| m.into()).collect())
+        with_former_names(
+            &self.db,
+            existing(
+                municipalities.into_iter().map(|m| m.into()).collect(),
+                self.as_of,
+            ),
+        )
+        .await
+    }
+
+    #[instrument(name = "PgRegionRepository::list_stats", skip(self))]
+    async fn list_stats(&self) -> Result<Vec<RegionStats>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::stats::select_region_stats(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            None,
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgRegionRepository::get_stats", skip(self))]
+    async fn get_stats(&self, code: &str) -> Result<RegionStats, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let row = models::stats::select_region_stats(
+            &executor,
+            &list_scope(&self.release, self.as_of),
+            Some(code),
+        )
+        .await
+        .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        .into_iter()
+        .next()
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(row.into())
+    }
+
+    #[instrument(name = "PgRegionRepository::create", skip_all, fields(code = %region.code))]
+    async fn create(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = models::region::Region {
+            release_id: self.release.clone(),
+            ..region.into()
+        };
+        audited(&self.db, audit, async |tx| {
+            let inserted = models::region::Region::insert(tx, &row).await?;
+            Ok(inserted.rows_affected)
+        })
+        .await
+    }
+
+    #[instrument(name = "PgRegionRepository::update", skip_all, fields(code = %region.code))]
+    async fn update(&self, region: &Region, audit: &AuditEntry) -> Result<(), RepositoryError> {
+        let row = region.into();
+        audited(&self.db, audit, async |tx| {
+            models::region::update_region(tx, &row).await
+        })
+        .await
     }
 }
@@ -0,0 +1,94 @@
+use std::{collections::HashMap, sync::Arc};
+
+use psgc_domain::{
+    errors::RepositoryError,
+    models::{release::Release, release_diff::ReleaseUnit},
+    repositories::release_repository::ReleaseRepository,
+};
+use rbatis::RBatis;
+use tracing::instrument;
+
+use crate::database::{generators::RBatisUuidExt, models};
+
+pub struct PgReleaseRepository {
+    db: Arc<RBatis>,
+}
+
+impl PgReleaseRepository {
+    pub fn new(db: Arc<RBatis>) -> Self {
+        Self { db }
+    }
+}
+
+impl ReleaseRepository for PgReleaseRepository {
+    #[instrument(name = "PgReleaseRepository::list", skip(self))]
+    async fn list(&self) -> Result<Vec<Release>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let releases = models::release::Release::list_newest_first(&executor)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+
+        Ok(releases.into_iter().map(|r| r.into()).collect())
+    }
+
+    #[instrument(name = "PgReleaseRepository::find", skip(self))]
+    async fn find(&self, id: &str) -> Result<Release, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let release = models::release::Release::select_by_id(&executor, id)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(release.into())
+    }
+
+    #[instrument(name = "PgReleaseRepository::latest", skip(self))]
+    async fn latest(&self) -> Result<Release, RepositoryError> {
+        self.list()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    #[instrument(name = "PgReleaseRepository::units", skip(self))]
+    async fn units(&self, id: &str) -> Result<Vec<ReleaseUnit>, RepositoryError> {
+        let executor = self
+            .db
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let rows = models::release::select_release_units(&executor, id)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?;
+        let mut former_names: HashMap<_, Vec<String>> = HashMap::new();
+        for row in models::former_name::select_by_release(&executor, id)
+            .await
+            .map_err(|e| RepositoryError::DatabaseError(e.to_string()))?
+        {
+            former_names
+                .entry(row.unit_id.inner())
+                .or_default()
+                .push(row.name);
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                let names = former_names.remove(&r.id.inner()).unwrap_or_default();
+                ReleaseUnit {
+                    former_names: names,
+                    ..r.into()
+                }
+            })
+            .collect())
+    }
+}
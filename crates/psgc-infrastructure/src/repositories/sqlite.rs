@@ -0,0 +1,191 @@
+//! SQLite repositories.
+//!
+//! The rbatis repositories only emit SQL that Postgres and SQLite both accept, and the
+//! driver is picked from the `DATABASE_URL` scheme in [`create_db_pool`], so SQLite
+//! reuses them over an SQLite-backed pool.
+//!
+//! [`create_db_pool`]: crate::database::pool::create_db_pool
+
+use crate::repositories::{
+    barangay_impl::PgBarangayRepository, city_impl::PgCityRepository,
+    district_impl::PgDistrictRepository, municipality_impl::PgMunicipalityRepository,
+    province_impl::PgProvinceRepository, region_impl::PgRegionRepository,
+};
+
+pub type SqliteRegionRepository = PgRegionRepository;
+pub type SqliteProvinceRepository = PgProvinceRepository;
+pub type SqliteDistrictRepository = PgDistrictRepository;
+pub type SqliteCityRepository = PgCityRepository;
+pub type SqliteMunicipalityRepository = PgMunicipalityRepository;
+pub type SqliteBarangayRepository = PgBarangayRepository;
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use chrono::Utc;
+    use psgc_domain::{
+        errors::RepositoryError,
+        models::{audit::AuditEntry, island_group::IslandGroup, municipality::Municipality},
+        repositories::{
+            audit_repository::AuditLogRepository, crosswalk_repository::CrosswalkRepository,
+            municipality_repository::MunicipalityRepository, region_repository::RegionRepository,
+            release_repository::ReleaseRepository,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        config::db_config::DatabaseConfig,
+        database::{
+            migrator::run_sqlite_migrations,
+            pool::create_db_pool,
+            seed_data::{BUNDLED_RELEASE, SeedData},
+            seeder::seeder,
+        },
+        repositories::{
+            audit_impl::PgAuditLogRepository, crosswalk_impl::PgCrosswalkRepository,
+            release_impl::PgReleaseRepository,
+        },
+    };
+
+    #[tokio::test]
+    async fn serves_the_repositories_in_memory() {
+        // A named in-memory database is shared by the connections of the process while
+        // one of them stays open
+        let name = format!(
+            "file:psgc-test-{}?mode=memory&cache=shared",
+            uuid::Uuid::now_v7()
+        );
+        let mut conn = rusqlite::Connection::open(&name).unwrap();
+        run_sqlite_migrations(&mut conn).unwrap();
+
+        let config = DatabaseConfig::builder()
+            .db_url(format!("sqlite://{name}"))
+            .build();
+        let db = create_db_pool(&config).unwrap();
+        seeder(db.clone(), &SeedData::bundled()).await.unwrap();
+
+        let municipalities =
+            SqliteMunicipalityRepository::new(db.clone(), BUNDLED_RELEASE.to_string(), None);
+        let mut adams = municipalities.find_by_code("012801000").await.unwrap();
+        assert_eq!(adams.name, "Adams");
+
+        adams.population += 1;
+        adams.former_names.push(adams.name.clone());
+        adams.name = "Adams Town".to_string();
+        let audit = AuditEntry::builder()
+            .id(uuid::Uuid::now_v7())
+            .actor("test".to_string())
+            .entity("municipality".to_string())
+            .entity_id(adams.id)
+            .code(adams.code.clone())
+            .after(serde_json::json!({"population": adams.population}))
+            .created_at(Utc::now())
+            .build();
+        municipalities.update(&adams, &audit).await.unwrap();
+        let updated = municipalities.find_by_code("012801000").await.unwrap();
+        assert_eq!(updated.population, adams.population);
+
+        // Still found by the name it had before the rename
+        let renamed = municipalities.list_by_name("adams").await.unwrap();
+        assert_eq!(renamed.len(), 1);
+        assert_eq!(renamed[0].former_names, vec!["Adams".to_string()]);
+
+        // Pages follow the code, so paging through neither repeats nor skips a unit
+        let first = municipalities.list_all(1, 10).await.unwrap().records;
+        let second = municipalities.list_all(2, 10).await.unwrap().records;
+        let codes: Vec<&str> = first
+            .iter()
+            .chain(&second)
+            .map(|m| m.code.as_str())
+            .collect();
+        assert_eq!(codes.len(), 20);
+        assert!(codes.is_sorted());
+
+        // The audit entry is written in the same transaction as the change
+        let history = PgAuditLogRepository::new(db.clone())
+            .history("municipality", "012801000")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+
+        let region = SqliteRegionRepository::new(db.clone(), BUNDLED_RELEASE.to_string(), None)
+            .find_by_code("0100000000")
+            .await
+            .unwrap();
+        assert_eq!(adams.region_id, Some(region.id));
+
+        let luzon = municipalities
+            .list_by_island_group(IslandGroup::Luzon, 1, 5000)
+            .await
+            .unwrap();
+        assert!(luzon.records.iter().any(|m| m.code == "012801000"));
+
+        // The unions over every level, by either code
+        let units = PgCrosswalkRepository::new(db.clone())
+            .find_current_units(
+                BUNDLED_RELEASE,
+                &["012801000".to_string(), "0100000000".to_string()],
+            )
+            .await
+            .unwrap();
+        assert_eq!(units.len(), 2);
+
+        // The seed run's entry comes first and marks where the release was loaded, then
+        // the update
+        let release = PgReleaseRepository::new(db.clone())
+            .find(BUNDLED_RELEASE)
+            .await
+            .unwrap();
+        assert_eq!(release.position, 1);
+        let audit_log = PgAuditLogRepository::new(db.clone());
+        let changes = audit_log
+            .list_unit_changes(BUNDLED_RELEASE, 0, 10)
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].position, Some(2));
+        assert!(
+            audit_log
+                .list_unit_changes(BUNDLED_RELEASE, 2, 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            audit_log.position_before(chrono::Utc::now()).await.unwrap(),
+            2
+        );
+
+        // Units belong to a loaded release
+        let orphan = db
+            .exec(
+                "INSERT INTO regions (id, code, correspondence_code, name, designation, population, release_id) VALUES (?, '9900000000', '990000000', 'Nowhere', 'R99', 0, '1999Q1')",
+                vec![rbs::value!(uuid::Uuid::now_v7().to_string())],
+            )
+            .await;
+        assert!(orphan.is_err());
+
+        // A second unit with a code already used in the release is turned down as a conflict
+        let twin = Municipality {
+            id: uuid::Uuid::now_v7(),
+            slug: format!("{}-twin", adams.slug),
+            ..adams.clone()
+        };
+        let duplicate = municipalities
+            .create(
+                &twin,
+                &AuditEntry {
+                    id: uuid::Uuid::now_v7(),
+                    entity_id: twin.id,
+                    ..audit.clone()
+                },
+            )
+            .await;
+        assert!(matches!(
+            duplicate,
+            Err(RepositoryError::ConstraintViolation(message)) if message.contains("code")
+        ));
+
+        drop(conn);
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use psgc_domain::{errors::RepositoryError, models::validity::Validity};
+
+use crate::database::{generators::NaiveDateExt, models::ListScope};
+
+/// The day reads are made as of: the one asked for, or today.
+pub(crate) fn as_of_date(as_of: Option<NaiveDate>) -> NaiveDate {
+    as_of.unwrap_or_else(|| chrono::Utc::now().date_naive())
+}
+
+pub(crate) fn list_scope(release: &str, as_of: Option<NaiveDate>) -> ListScope {
+    ListScope {
+        release: release.to_string(),
+        date: as_of_date(as_of).into_db(),
+        island_group: None,
+    }
+}
+
+/// The units that existed on the day reads are made as of.
+pub(crate) fn existing<T: Validity>(units: Vec<T>, as_of: Option<NaiveDate>) -> Vec<T> {
+    let date = as_of_date(as_of);
+
+    units
+        .into_iter()
+        .filter(|unit| unit.is_valid_on(date))
+        .collect()
+}
+
+/// A unit looked up by code or slug. Without `as_of` it's found even once abolished, so
+/// old codes still resolve and show their end date.
+pub(crate) fn existed<T: Validity>(
+    unit: T,
+    as_of: Option<NaiveDate>,
+) -> Result<T, RepositoryError> {
+    match as_of {
+        Some(date) if !unit.is_valid_on(date) => Err(RepositoryError::NotFound),
+        _ => Ok(unit),
+    }
+}
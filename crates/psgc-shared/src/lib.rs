@@ -1,3 +1,7 @@
+pub mod logging;
+pub mod telemetry;
+pub mod text;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -0,0 +1,89 @@
+//! Log output shared by the binaries: JSON lines by default, plain text for local runs.
+
+use std::{env, str::FromStr};
+
+use tracing_log::LogTracer;
+use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt};
+
+use crate::telemetry::{TelemetryConfig, TelemetryGuard, otel_layer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Json,
+    Text,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "text" | "pretty" => Ok(Self::Text),
+            other => Err(format!(
+                "unknown LOG_FORMAT {other:?}, expected json or text"
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    pub format: LogFormat,
+    /// Filter directives used when `RUST_LOG` isn't set, e.g. `info,rbatis=warn`
+    pub default_directives: String,
+    pub telemetry: TelemetryConfig,
+}
+
+impl LoggingConfig {
+    /// Reads `LOG_FORMAT` (`json` or `text`, defaulting to `json`) and the OpenTelemetry
+    /// settings. An unknown format falls back to JSON rather than aborting startup.
+    pub fn from_env(default_directives: impl Into<String>) -> Self {
+        let format = env::var("LOG_FORMAT")
+            .ok()
+            .filter(|format| !format.is_empty())
+            .map(|format| {
+                format.parse().unwrap_or_else(|e| {
+                    eprintln!("{e}; logging as json");
+                    LogFormat::Json
+                })
+            })
+            .unwrap_or(LogFormat::Json);
+
+        Self {
+            format,
+            default_directives: default_directives.into(),
+            telemetry: TelemetryConfig::from_env(),
+        }
+    }
+}
+
+/// Installs the global subscriber. JSON lines carry the fields of every enclosing span,
+/// so anything logged while handling a request includes its `request_id`.
+pub fn setup_tracing(config: &LoggingConfig) -> TelemetryGuard {
+    let filter_layer = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(&config.default_directives));
+
+    let fmt_layer = match config.format {
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(false)
+            .with_span_list(true)
+            .boxed(),
+        LogFormat::Text => tracing_subscriber::fmt::layer().boxed(),
+    }
+    .with_filter(filter_layer);
+
+    let (otel_layer, telemetry) =
+        otel_layer(&config.telemetry).expect("Failed to set up OpenTelemetry export");
+    let subscriber = tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer);
+
+    tracing::subscriber::set_global_default(subscriber)
+        .expect("Failed to set global default subscriber");
+
+    LogTracer::init().expect("Failed to set logger");
+
+    telemetry
+}
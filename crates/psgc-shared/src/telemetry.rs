@@ -0,0 +1,173 @@
+//! Optional OpenTelemetry export for the `tracing` spans.
+//!
+//! Nothing is exported unless `OTEL_EXPORTER_OTLP_ENDPOINT` is set, in which case spans
+//! are batched to `{endpoint}/v1/traces` over OTLP/HTTP.
+
+use std::env;
+
+use opentelemetry::{global, trace::TracerProvider};
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, propagation::TraceContextPropagator, trace::SdkTracerProvider};
+use tracing::Subscriber;
+use tracing_subscriber::{Layer, filter::LevelFilter, registry::LookupSpan};
+
+const DEFAULT_SERVICE_NAME: &str = "psgc-rs";
+
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// Base OTLP/HTTP endpoint of the collector, e.g. `http://localhost:4318`
+    pub otlp_endpoint: Option<String>,
+    pub service_name: String,
+}
+
+impl TelemetryConfig {
+    /// Reads `OTEL_EXPORTER_OTLP_ENDPOINT` and `OTEL_SERVICE_NAME`.
+    pub fn from_env() -> Self {
+        let otlp_endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .ok()
+            .filter(|endpoint| !endpoint.is_empty());
+        let service_name = env::var("OTEL_SERVICE_NAME")
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string());
+
+        Self {
+            otlp_endpoint,
+            service_name,
+        }
+    }
+
+    fn traces_endpoint(&self) -> Option<String> {
+        self.otlp_endpoint
+            .as_ref()
+            .map(|endpoint| format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+    }
+}
+
+/// Flushes buffered spans when dropped; keep it alive for the life of the process.
+#[must_use]
+pub struct TelemetryGuard {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            eprintln!("failed to flush OpenTelemetry spans: {e}");
+        }
+    }
+}
+
+/// Builds the OTLP layer when an endpoint is configured. Also installs the W3C trace
+/// context propagator, so incoming `traceparent` headers continue the caller's trace.
+#[allow(clippy::type_complexity)]
+pub fn otel_layer<S>(
+    config: &TelemetryConfig,
+) -> Result<(Option<Box<dyn Layer<S> + Send + Sync>>, TelemetryGuard), ExporterBuildError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span> + Send + Sync,
+{
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let Some(endpoint) = config.traces_endpoint() else {
+        return Ok((None, TelemetryGuard { provider: None }));
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name.clone())
+                .build(),
+        )
+        .build();
+    global::set_tracer_provider(provider.clone());
+
+    let tracer = provider.tracer(DEFAULT_SERVICE_NAME);
+    let layer = tracing_opentelemetry::layer()
+        .with_tracer(tracer)
+        .with_filter(LevelFilter::INFO)
+        .boxed();
+
+    Ok((
+        Some(layer),
+        TelemetryGuard {
+            provider: Some(provider),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    /// Accepts one request and hands back its request line.
+    fn collector_stand_in() -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0; 4096];
+            let read = stream.read(&mut buffer).unwrap();
+            let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .unwrap();
+            tx.send(request.lines().next().unwrap_or_default().to_string())
+                .unwrap();
+        });
+
+        (endpoint, rx)
+    }
+
+    #[test]
+    fn exports_spans_to_the_configured_endpoint() {
+        let (endpoint, requests) = collector_stand_in();
+        let config = TelemetryConfig {
+            otlp_endpoint: Some(endpoint),
+            service_name: "psgc-test".to_string(),
+        };
+
+        let (layer, guard) = otel_layer(&config).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("GetRegionByCodeUsecase::execute", code = "0100000000")
+                .in_scope(|| {});
+        });
+        drop(guard);
+
+        let request_line = requests.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(request_line, "POST /v1/traces HTTP/1.1");
+    }
+
+    #[test]
+    fn disabled_without_an_endpoint() {
+        let config = TelemetryConfig {
+            otlp_endpoint: None,
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+        };
+
+        let (layer, _guard) = otel_layer::<tracing_subscriber::Registry>(&config).unwrap();
+        assert!(layer.is_none());
+    }
+}
@@ -0,0 +1,75 @@
+use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
+
+/// Folds a place name for case- and accent-insensitive comparison.
+///
+/// `"Peñablanca"` and `"  PENABLANCA "` both fold to `"penablanca"`.
+pub fn fold_name(name: &str) -> String {
+    let stripped = name
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase();
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Builds a URL-safe slug from a place name, e.g. `"City of Las Piñas"` -> `"city-of-las-pinas"`.
+pub fn slugify(name: &str) -> String {
+    let folded = fold_name(name);
+    let mut slug = String::with_capacity(folded.len());
+
+    for c in folded.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Splits a name written as `"Current (Former)"`, as older records give renamed places,
+/// e.g. `"Sultan Kudarat (Nuling)"` -> `("Sultan Kudarat", "Nuling")`.
+pub fn split_parenthetical(name: &str) -> Option<(&str, &str)> {
+    let (outer, inner) = name.trim().strip_suffix(')')?.split_once('(')?;
+    let (outer, inner) = (outer.trim(), inner.trim());
+
+    (!outer.is_empty() && !inner.is_empty() && !inner.contains(['(', ')']))
+        .then_some((outer, inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_case_accents_and_whitespace() {
+        assert_eq!(fold_name("Peñablanca"), "penablanca");
+        assert_eq!(fold_name("  PEÑABLANCA  "), "penablanca");
+        assert_eq!(fold_name("Sto.  Niño"), "sto. nino");
+    }
+
+    #[test]
+    fn slugifies_names() {
+        assert_eq!(slugify("City of Las Piñas"), "city-of-las-pinas");
+        assert_eq!(slugify("Region IV-A"), "region-iv-a");
+        assert_eq!(slugify("Sultan Kudarat (Nuling)"), "sultan-kudarat-nuling");
+        assert_eq!(slugify("NCR"), "ncr");
+    }
+
+    #[test]
+    fn splits_parenthetical_names() {
+        assert_eq!(
+            split_parenthetical("Sultan Kudarat (Nuling)"),
+            Some(("Sultan Kudarat", "Nuling"))
+        );
+        assert_eq!(
+            split_parenthetical(" Samar ( Western Samar ) "),
+            Some(("Samar", "Western Samar"))
+        );
+        assert_eq!(split_parenthetical("Sultan Kudarat"), None);
+        assert_eq!(split_parenthetical("(Nuling)"), None);
+        assert_eq!(split_parenthetical("A (B) (C)"), None);
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use psgc_application::usecases::api_key_usecases::{
+    CreateApiKeyUsecase, ListApiKeysUsecase, RevokeApiKeyUsecase,
+};
+use psgc_infrastructure::{
+    config::db_config::DatabaseConfig, database::pool::create_db_pool,
+    repositories::api_key_impl::PgApiKeyRepository,
+};
+use psgc_shared::logging::{LoggingConfig, setup_tracing};
+
+const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 60;
+
+const USAGE: &str = "\
+Usage:
+  api-keys create <name> [--rate-limit <requests per minute>] [--daily-quota <requests>] [--origins <origin,...>] [--admin]
+  api-keys list
+  api-keys revoke <name>";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let crate_name = env!("CARGO_CRATE_NAME");
+    let _telemetry = setup_tracing(&LoggingConfig::from_env(format!(
+        "warn,{}=info,psgc_infrastructure=warn,rbatis=warn",
+        crate_name
+    )));
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let Some((command, args)) = args.split_first() else {
+        anyhow::bail!("{USAGE}");
+    };
+
+    let config = DatabaseConfig::from_env()?;
+    let repository = Arc::new(PgApiKeyRepository::new(create_db_pool(&config)?));
+
+    match command.as_str() {
+        "create" => {
+            let Some((name, options)) = args.split_first() else {
+                anyhow::bail!("{USAGE}");
+            };
+            let mut rate_limit = DEFAULT_RATE_LIMIT_PER_MINUTE;
+            let mut daily_quota = None;
+            let mut origins = Vec::new();
+            let mut admin = false;
+
+            let mut options = options.iter();
+            while let Some(option) = options.next() {
+                if option == "--admin" {
+                    admin = true;
+                    continue;
+                }
+
+                let value = options
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("{option} needs a value\n{USAGE}"))?;
+
+                match option.as_str() {
+                    "--rate-limit" => rate_limit = value.parse()?,
+                    "--daily-quota" => daily_quota = Some(value.parse()?),
+                    "--origins" => {
+                        origins = value
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|origin| !origin.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    }
+                    _ => anyhow::bail!("unknown option {option}\n{USAGE}"),
+                }
+            }
+
+            let created = CreateApiKeyUsecase::new(repository)
+                .execute(name, rate_limit, daily_quota, origins, admin)
+                .await?;
+
+            println!(
+                "Created {}API key {}",
+                if created.api_key.admin { "admin " } else { "" },
+                created.api_key.name
+            );
+            println!("{}", created.key);
+            println!("Store it now, it can't be shown again.");
+        }
+        "list" => {
+            let api_keys = ListApiKeysUsecase::new(repository).execute().await?;
+
+            println!(
+                "{:<24} {:<14} {:>10} {:>12} {:<10} {:<6} ORIGINS",
+                "NAME", "PREFIX", "PER MINUTE", "DAILY QUOTA", "STATUS", "ADMIN"
+            );
+            for api_key in api_keys {
+                println!(
+                    "{:<24} {:<14} {:>10} {:>12} {:<10} {:<6} {}",
+                    api_key.name,
+                    api_key.prefix,
+                    api_key.rate_limit_per_minute,
+                    api_key
+                        .daily_quota
+                        .map(|quota| quota.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    if api_key.revoked_at.is_some() {
+                        "revoked"
+                    } else {
+                        "active"
+                    },
+                    if api_key.admin { "yes" } else { "no" },
+                    if api_key.allowed_origins.is_empty() {
+                        "*".to_string()
+                    } else {
+                        api_key.allowed_origins.join(",")
+                    },
+                );
+            }
+        }
+        "revoke" => {
+            let Some(name) = args.first() else {
+                anyhow::bail!("{USAGE}");
+            };
+
+            RevokeApiKeyUsecase::new(repository).execute(name).await?;
+            println!("Revoked API key {name}");
+        }
+        _ => anyhow::bail!("unknown command {command}\n{USAGE}"),
+    }
+
+    Ok(())
+}
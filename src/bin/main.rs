@@ -1,36 +1,35 @@
-use std::sync::Arc;
-
 use actix_web::{HttpServer, dev::ServerHandle, rt::signal};
-use psgc_api::router::{APIState, create_api_router};
+use psgc_api::{
+    api_key::ApiKeyAuth,
+    dataset::DatasetCache,
+    metrics::Metrics,
+    rate_limit::RateLimiter,
+    router::{APIState, create_admin_router, create_api_router},
+};
 use psgc_infrastructure::{
-    config::{db_config::DatabaseConfig, http_config::HTTPConfig},
-    database::pool::create_db_pool,
-    repositories::{
-        barangay_impl::PgBarangayRepository, city_impl::PgCityRepository,
-        district_impl::PgDistrictRepository, municipality_impl::PgMunicipalityRepository,
-        province_impl::PgProvinceRepository, region_impl::PgRegionRepository,
-    },
+    config::{backend_config::BackendConfig, http_config::HTTPConfig},
+    repositories::backend::Repositories,
 };
+use psgc_shared::logging::{LoggingConfig, setup_tracing};
 use tracing::info;
-use tracing_log::LogTracer;
-use tracing_subscriber::{Layer, layer::SubscriberExt};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    setup_tracing();
+    let crate_name = env!("CARGO_CRATE_NAME");
+    let _telemetry = setup_tracing(&LoggingConfig::from_env(format!(
+        "info,{}=info,psgc_infrastructure=info,psgc_application=info,psgc_api=info,tokio=trace,runtime=trace,rbatis=info,actix_web=info",
+        crate_name
+    )));
+    info!("[PSGC-RS] {} v{}", crate_name, env!("CARGO_PKG_VERSION"));
 
     let http_config = HTTPConfig::from_env()?;
-    let db_config = DatabaseConfig::from_env()?;
-    let db = create_db_pool(&db_config)?;
+    let backend_config = BackendConfig::from_env()?;
+    let repositories = Repositories::connect(&backend_config).await?;
 
-    let region_repository = Arc::new(PgRegionRepository::new(db.clone()));
-
-    let province_repository = Arc::new(PgProvinceRepository::new(db.clone()));
-    let municipality_repository = Arc::new(PgMunicipalityRepository::new(db.clone()));
-    let district_repository = Arc::new(PgDistrictRepository::new(db.clone()));
-    let city_repository = Arc::new(PgCityRepository::new(db.clone()));
-    let barangay_repository = Arc::new(PgBarangayRepository::new(db));
+    serve(http_config, repositories).await
+}
 
+async fn serve(http_config: HTTPConfig, repositories: Repositories) -> anyhow::Result<()> {
     let http_addr = http_config.get_connection_string();
     let http_addr_clone = http_config.get_connection_string();
 
@@ -39,15 +38,60 @@ async fn main() -> anyhow::Result<()> {
         info!("listening on {}", http_addr);
     });
 
+    let metrics = Metrics::new()?;
+    let rate_limiter = RateLimiter::new(&http_config.rate_limit);
+    let datasets = DatasetCache::default();
+    tokio::spawn(datasets.clone().prebuild(repositories.clone()));
+    let api_key_auth = match http_config.require_api_key {
+        true => {
+            let api_keys = repositories.api_keys.clone().ok_or_else(|| {
+                anyhow::anyhow!("REQUIRE_API_KEY needs the sql or cached backend to store keys")
+            })?;
+            info!("requiring an API key on /api/v1");
+
+            Some(ApiKeyAuth::new(api_keys))
+        }
+        false => None,
+    };
+    let admin_addr = http_config.get_admin_connection_string();
+    let expose_metrics = admin_addr.is_none();
+
+    let admin_server = match admin_addr {
+        Some(admin_addr) => {
+            info!("serving /metrics on {}", admin_addr);
+
+            let allowed_origins = http_config.allowed_origins.clone();
+            let repositories = repositories.clone();
+            let metrics = metrics.clone();
+
+            Some(
+                HttpServer::new(move || {
+                    let api_state = APIState::builder()
+                        .allowed_origins(allowed_origins.clone())
+                        .repositories(repositories.clone())
+                        .metrics(metrics.clone())
+                        .build();
+
+                    create_admin_router(api_state)
+                })
+                .workers(1)
+                .bind(admin_addr)?
+                .shutdown_timeout(5)
+                .run(),
+            )
+        }
+        None => None,
+    };
+
     let server = HttpServer::new(move || {
         let api_state = APIState::builder()
             .allowed_origins(http_config.allowed_origins.clone())
-            .region_repository(region_repository.clone())
-            .province_repository(province_repository.clone())
-            .municipality_repository(municipality_repository.clone())
-            .district_repository(district_repository.clone())
-            .city_repository(city_repository.clone())
-            .barangay_repository(barangay_repository.clone())
+            .repositories(repositories.clone())
+            .metrics(metrics.clone())
+            .rate_limiter(rate_limiter.clone())
+            .maybe_api_key_auth(api_key_auth.clone())
+            .expose_metrics(expose_metrics)
+            .datasets(datasets.clone())
             .build();
 
         let router = create_api_router(api_state);
@@ -59,41 +103,28 @@ async fn main() -> anyhow::Result<()> {
     .shutdown_timeout(5)
     .run();
 
-    let server_handle = server.handle();
+    let mut server_handles = vec![server.handle()];
+    server_handles.extend(
+        admin_server
+            .as_ref()
+            .map(|admin_server| admin_server.handle()),
+    );
 
     tokio::spawn(async move {
-        shutdown_signal(server_handle).await;
+        shutdown_signal(server_handles).await;
     });
 
-    server.await?;
+    match admin_server {
+        Some(admin_server) => {
+            tokio::try_join!(server, admin_server)?;
+        }
+        None => server.await?,
+    }
 
     Ok(())
 }
 
-pub fn setup_tracing() {
-    let crate_name = env!("CARGO_CRATE_NAME");
-    let crate_version = env!("CARGO_PKG_VERSION");
-
-    let filter_layer = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        format!(
-            "RUST_LOG=info,{}=info,psgc_infrastructure=info,psgc_application=info,psgc_api=info,tokio=trace,runtime=trace,rbatis=info,actix_web=info",
-            crate_name
-        )
-        .into()
-    });
-
-    let fmt_layer = tracing_subscriber::fmt::layer().with_filter(filter_layer);
-    let subscriber = tracing_subscriber::registry().with(fmt_layer);
-
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Failed to set global default subscriber");
-
-    LogTracer::init().expect("Failed to set logger");
-
-    info!("[PSGC-RS] {} v{}", crate_name, crate_version);
-}
-
-pub async fn shutdown_signal(handle: ServerHandle) {
+pub async fn shutdown_signal(handles: Vec<ServerHandle>) {
     let ctrl_c = async {
         signal::ctrl_c()
             .await
@@ -123,5 +154,7 @@ pub async fn shutdown_signal(handle: ServerHandle) {
     info!("server shutdown complete");
     info!("goodbye!");
 
-    handle.stop(true).await;
+    for handle in handles {
+        handle.stop(true).await;
+    }
 }
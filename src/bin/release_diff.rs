@@ -0,0 +1,68 @@
+use std::{io::Write, sync::Arc};
+
+use psgc_application::usecases::release_usecases::DiffReleasesUsecase;
+use psgc_infrastructure::{
+    config::db_config::DatabaseConfig, database::pool::create_db_pool,
+    repositories::release_impl::PgReleaseRepository,
+};
+use psgc_shared::logging::{LoggingConfig, setup_tracing};
+
+const USAGE: &str = "\
+Usage:
+  release-diff <from> <to> [--format json|csv] [--output <file>]
+
+Prints every unit added, abolished, renamed, recoded or reclassified between two loaded
+releases, e.g. release-diff 2024Q2 2024Q3 --format csv";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let crate_name = env!("CARGO_CRATE_NAME");
+    let _telemetry = setup_tracing(&LoggingConfig::from_env(format!(
+        "warn,{}=info,psgc_infrastructure=warn,rbatis=warn",
+        crate_name
+    )));
+
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let [from, to, options @ ..] = args.as_slice() else {
+        anyhow::bail!("{USAGE}");
+    };
+
+    let mut format = "json".to_string();
+    let mut output = None;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let value = options
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("{option} needs a value\n{USAGE}"))?;
+
+        match option.as_str() {
+            "--format" => format = value.clone(),
+            "--output" => output = Some(value.clone()),
+            _ => anyhow::bail!("unknown option {option}\n{USAGE}"),
+        }
+    }
+
+    if !matches!(format.as_str(), "json" | "csv") {
+        anyhow::bail!("unknown format {format}; use json or csv\n{USAGE}");
+    }
+
+    let config = DatabaseConfig::from_env()?;
+    let repository = Arc::new(PgReleaseRepository::new(create_db_pool(&config)?));
+
+    let diff = DiffReleasesUsecase::new(repository)
+        .execute(from, to)
+        .await?;
+
+    let rendered = match format.as_str() {
+        "csv" => diff.to_csv(),
+        _ => serde_json::to_string_pretty(&diff)? + "\n",
+    };
+
+    match output {
+        Some(path) => std::fs::write(path, rendered)?,
+        None => std::io::stdout().write_all(rendered.as_bytes())?,
+    }
+
+    Ok(())
+}
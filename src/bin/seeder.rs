@@ -1,44 +1,59 @@
+use std::path::PathBuf;
+
 use psgc_infrastructure::{
     config::db_config::DatabaseConfig,
-    database::{pool::create_db_pool, seeder::seeder},
+    database::{
+        pool::create_db_pool,
+        seed_data::{BUNDLED_RELEASE, SeedData},
+        seeder::seeder,
+    },
 };
+use psgc_shared::logging::{LoggingConfig, setup_tracing};
 use tracing::info;
-use tracing_log::LogTracer;
-use tracing_subscriber::{Layer, layer::SubscriberExt};
+
+const USAGE: &str = "\
+Usage:
+  seeder                                      load the bundled data as release BUNDLED
+  seeder --release <id> --data-dir <dir>      load the PSA JSON files in <dir> as release <id>, e.g. 2023Q4";
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    setup_tracing();
+    let crate_name = env!("CARGO_CRATE_NAME");
+    let _telemetry = setup_tracing(&LoggingConfig::from_env(format!(
+        "info,{}=info,psgc_infrastructure=info,tokio=trace,runtime=trace,rbatis=info",
+        crate_name
+    )));
+    info!("[PSGC-RS] {} v{}", crate_name, env!("CARGO_PKG_VERSION"));
+
+    let usage = USAGE.replace("BUNDLED", BUNDLED_RELEASE);
+    let mut release = None;
+    let mut data_dir = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(option) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("{option} needs a value\n{usage}"))?;
+
+        match option.as_str() {
+            "--release" => release = Some(value),
+            "--data-dir" => data_dir = Some(PathBuf::from(value)),
+            _ => anyhow::bail!("unknown option {option}\n{usage}"),
+        }
+    }
+
+    let data = match (release, data_dir) {
+        (None, None) => SeedData::bundled(),
+        (Some(release), Some(data_dir)) => SeedData::from_dir(&release, &data_dir)?,
+        _ => anyhow::bail!("--release and --data-dir go together\n{usage}"),
+    };
 
     let config = DatabaseConfig::from_env()?;
     let pool = create_db_pool(&config)?;
 
     info!("Initializing PSGC database seed...");
-    seeder(pool).await?;
+    seeder(pool, &data).await?;
     info!("PSGC database seeding completed successfully.");
 
     Ok(())
 }
-
-pub fn setup_tracing() {
-    let crate_name = env!("CARGO_CRATE_NAME");
-    let crate_version = env!("CARGO_PKG_VERSION");
-
-    let filter_layer = tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        format!(
-            "RUST_LOG=info,{}=info,psgc_infrastructure=info,tokio=trace,runtime=trace,rbatis=info",
-            crate_name
-        )
-        .into()
-    });
-
-    let fmt_layer = tracing_subscriber::fmt::layer().with_filter(filter_layer);
-    let subscriber = tracing_subscriber::registry().with(fmt_layer);
-
-    tracing::subscriber::set_global_default(subscriber)
-        .expect("Failed to set global default subscriber");
-
-    LogTracer::init().expect("Failed to set logger");
-
-    info!("[PSGC-RS] {} v{}", crate_name, crate_version);
-}